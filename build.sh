@@ -10,6 +10,9 @@
 #   - For macOS signing and notarization, set the following in .env file or as environment variables:
 #     SIGNING_IDENTITY="Developer ID Application: Your Name (TEAMID)"
 #     INSTALLER_IDENTITY="Developer ID Installer: Your Name (TEAMID)"
+#   - For verified self-updates, set:
+#     RELEASE_SIGNING_KEY=/path/to/release-ed25519.pem   (private key, never committed)
+#     The matching public key is embedded into the binary as CS_CLI_UPDATE_PUBLIC_KEY.
 
 set -euo pipefail
 
@@ -66,6 +69,34 @@ usage() {
 
 # --- Core Logic Functions ---
 
+embed_update_public_key() {
+    if [ -z "${RELEASE_SIGNING_KEY:-}" ]; then
+        log WARN "RELEASE_SIGNING_KEY not set. Builds will refuse to self-update."
+        return
+    fi
+
+    # Raw 32-byte Ed25519 public key is the tail of the DER SubjectPublicKeyInfo
+    CS_CLI_UPDATE_PUBLIC_KEY=$(openssl pkey -in "$RELEASE_SIGNING_KEY" -pubout -outform DER | tail -c 32 | base64)
+    export CS_CLI_UPDATE_PUBLIC_KEY
+    log SUCCESS "Embedding release signing key for self-updates"
+}
+
+sign_release_manifest() {
+    log STEP "Writing release checksums..."
+
+    (cd dist/binaries && shasum -a 256 ${BINARY_NAME}-* > SHA256SUMS)
+    log SUCCESS "Created dist/binaries/SHA256SUMS"
+
+    if [ -z "${RELEASE_SIGNING_KEY:-}" ]; then
+        log WARN "RELEASE_SIGNING_KEY not set. SHA256SUMS will not be signed."
+        return
+    fi
+
+    openssl pkeyutl -sign -rawin -inkey "$RELEASE_SIGNING_KEY" \
+        -in dist/binaries/SHA256SUMS -out dist/binaries/SHA256SUMS.sig
+    log SUCCESS "Signed checksums: dist/binaries/SHA256SUMS.sig"
+}
+
 parse_args() {
     SELECTED_TARGETS="$TARGETS_ALL" # Default to all targets
 
@@ -440,6 +471,8 @@ main() {
     xattr -w com.apple.metadata:com_apple_backup_excludeItem com.apple.backupd dist 2>/dev/null || true
     cargo clean
 
+    embed_update_public_key
+
     for target in $SELECTED_TARGETS; do
         build_target "$target"
     done
@@ -450,6 +483,9 @@ main() {
         sign_file "dist/binaries/${BINARY_NAME}-macos-universal"
     fi
 
+    # Checksums are written after signing so they match the published binaries
+    sign_release_manifest
+
     # Create the app bundle (launcher)
     create_app_bundle
 
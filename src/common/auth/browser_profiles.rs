@@ -0,0 +1,387 @@
+//! Browser and profile discovery for cookie extraction
+//!
+//! rookie's per-browser functions only read each browser's default profile.
+//! This module locates every profile's cookie database so callers can pick a
+//! specific profile or compare sessions across all of them.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::{CsCliError, Result};
+
+/// Browsers supported for cookie extraction
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BrowserKind {
+    Firefox,
+    Chrome,
+    Edge,
+    Arc,
+    Brave,
+    Chromium,
+    Librewolf,
+    Opera,
+    OperaGx,
+    Vivaldi,
+    Zen,
+    Safari,
+    Cachy,
+}
+
+impl BrowserKind {
+    /// All browsers in the order they are scanned
+    pub const ALL: [BrowserKind; 13] = [
+        BrowserKind::Firefox,
+        BrowserKind::Chrome,
+        BrowserKind::Edge,
+        BrowserKind::Arc,
+        BrowserKind::Brave,
+        BrowserKind::Chromium,
+        BrowserKind::Librewolf,
+        BrowserKind::Opera,
+        BrowserKind::OperaGx,
+        BrowserKind::Vivaldi,
+        BrowserKind::Zen,
+        BrowserKind::Safari,
+        BrowserKind::Cachy,
+    ];
+
+    /// Human readable browser name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            BrowserKind::Firefox => "Firefox",
+            BrowserKind::Chrome => "Chrome",
+            BrowserKind::Edge => "Edge",
+            BrowserKind::Arc => "Arc",
+            BrowserKind::Brave => "Brave",
+            BrowserKind::Chromium => "Chromium",
+            BrowserKind::Librewolf => "LibreWolf",
+            BrowserKind::Opera => "Opera",
+            BrowserKind::OperaGx => "Opera GX",
+            BrowserKind::Vivaldi => "Vivaldi",
+            BrowserKind::Zen => "Zen",
+            BrowserKind::Safari => "Safari",
+            BrowserKind::Cachy => "Cachy Browser",
+        }
+    }
+
+    /// Whether the browser stores cookies in Firefox's format
+    pub fn is_firefox_based(&self) -> bool {
+        matches!(
+            self,
+            BrowserKind::Firefox | BrowserKind::Librewolf | BrowserKind::Zen | BrowserKind::Cachy
+        )
+    }
+
+    /// Whether the browser is available on the current platform
+    pub fn is_supported_on_platform(&self) -> bool {
+        match self {
+            BrowserKind::Safari => cfg!(target_os = "macos"),
+            BrowserKind::Cachy => cfg!(target_os = "linux"),
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for BrowserKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl FromStr for BrowserKind {
+    type Err = CsCliError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let normalized = s.trim().to_lowercase().replace([' ', '_'], "-");
+        <BrowserKind as clap::ValueEnum>::from_str(&normalized, true).map_err(|_| {
+            CsCliError::InvalidArguments {
+                message: format!(
+                    "Unknown browser '{s}'. Supported: {}",
+                    BrowserKind::ALL
+                        .iter()
+                        .map(|b| b.display_name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        })
+    }
+}
+
+/// A browser profile with its cookie database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowserProfile {
+    /// Browser the profile belongs to
+    pub browser: BrowserKind,
+    /// Profile directory name (e.g. "Default", "Profile 1", "abcd.default-release")
+    pub name: String,
+    /// Path to the profile's cookie database
+    pub cookies_path: PathBuf,
+    /// Path to the Chromium "Local State" file holding the cookie key (Windows)
+    pub key_path: Option<PathBuf>,
+}
+
+impl BrowserProfile {
+    /// Label used in logs and user-facing messages, e.g. "Chrome (Profile 1)"
+    pub fn label(&self) -> String {
+        format!("{} ({})", self.browser.display_name(), self.name)
+    }
+}
+
+/// Which browser (and optionally which profile) to read cookies from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BrowserSelection {
+    /// Specific browser, or None to scan all browsers
+    pub browser: Option<BrowserKind>,
+    /// Specific profile name within the browser
+    pub profile: Option<String>,
+}
+
+impl BrowserSelection {
+    /// Build a selection from optional browser/profile names
+    pub fn from_names(browser: Option<&str>, profile: Option<&str>) -> Result<Self> {
+        let browser = match browser.map(str::trim) {
+            None | Some("") | Some("auto") => None,
+            Some(name) => Some(name.parse::<BrowserKind>()?),
+        };
+
+        if browser.is_none() && profile.is_some() {
+            return Err(CsCliError::InvalidArguments {
+                message: "--browser-profile requires --browser".to_string(),
+            });
+        }
+
+        Ok(Self {
+            browser,
+            profile: profile.map(|p| p.to_string()),
+        })
+    }
+
+    /// Whether every browser and profile should be scanned
+    pub fn is_auto(&self) -> bool {
+        self.browser.is_none()
+    }
+
+    /// Whether a source label (as stored in GongCookies.browser) satisfies this selection
+    pub fn matches_label(&self, label: &str) -> bool {
+        match self.browser {
+            None => true,
+            Some(browser) => {
+                let name = browser.display_name();
+                match &self.profile {
+                    Some(profile) => label == format!("{name} ({profile})"),
+                    None => label == name || label.starts_with(&format!("{name} (")),
+                }
+            }
+        }
+    }
+}
+
+/// Discover all profiles with a cookie database for a browser
+pub fn discover_profiles(browser: BrowserKind) -> Vec<BrowserProfile> {
+    if !browser.is_supported_on_platform() || browser == BrowserKind::Safari {
+        // Safari has a single cookie store handled by rookie directly
+        return Vec::new();
+    }
+
+    let Some(root) = profile_root(browser) else {
+        return Vec::new();
+    };
+
+    if browser.is_firefox_based() {
+        discover_firefox_profiles(browser, &root)
+    } else {
+        discover_chromium_profiles(browser, &root)
+    }
+}
+
+/// Find a named profile for a browser
+pub fn find_profile(browser: BrowserKind, name: &str) -> Result<BrowserProfile> {
+    let profiles = discover_profiles(browser);
+
+    profiles
+        .iter()
+        .find(|p| p.name == name)
+        .or_else(|| {
+            // Firefox profile directories carry a random prefix ("abcd1234.work"),
+            // so also accept the part after the dot
+            profiles
+                .iter()
+                .find(|p| p.name.split_once('.').is_some_and(|(_, suffix)| suffix == name))
+        })
+        .cloned()
+        .ok_or_else(|| {
+            let available = profiles
+                .iter()
+                .map(|p| p.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            CsCliError::CookieExtraction(format!(
+                "Profile '{name}' not found for {browser}. Available profiles: {}",
+                if available.is_empty() { "none" } else { &available }
+            ))
+        })
+}
+
+/// Chromium profiles live in "Default" and "Profile N" directories
+fn discover_chromium_profiles(browser: BrowserKind, root: &Path) -> Vec<BrowserProfile> {
+    let key_path = Some(root.join("Local State")).filter(|p| p.exists() && cfg!(windows));
+
+    let mut profiles: Vec<BrowserProfile> = read_subdirs(root)
+        .into_iter()
+        .filter(|dir| {
+            dir.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n == "Default" || n.starts_with("Profile "))
+        })
+        .filter_map(|dir| {
+            let cookies_path = [dir.join("Network").join("Cookies"), dir.join("Cookies")]
+                .into_iter()
+                .find(|p| p.exists())?;
+            Some(BrowserProfile {
+                browser,
+                name: dir.file_name()?.to_string_lossy().to_string(),
+                cookies_path,
+                key_path: key_path.clone(),
+            })
+        })
+        .collect();
+
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    profiles
+}
+
+/// Firefox profiles are any subdirectory holding a cookies.sqlite file
+fn discover_firefox_profiles(browser: BrowserKind, root: &Path) -> Vec<BrowserProfile> {
+    let mut profiles: Vec<BrowserProfile> = read_subdirs(root)
+        .into_iter()
+        .filter_map(|dir| {
+            let cookies_path = dir.join("cookies.sqlite");
+            if !cookies_path.exists() {
+                return None;
+            }
+            Some(BrowserProfile {
+                browser,
+                name: dir.file_name()?.to_string_lossy().to_string(),
+                cookies_path,
+                key_path: None,
+            })
+        })
+        .collect();
+
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    profiles
+}
+
+/// List subdirectories, ignoring unreadable entries
+fn read_subdirs(root: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Directory containing a browser's profiles on the current platform
+fn profile_root(browser: BrowserKind) -> Option<PathBuf> {
+    #[cfg(target_os = "macos")]
+    {
+        let support = dirs::home_dir()?.join("Library").join("Application Support");
+        let relative = match browser {
+            BrowserKind::Firefox => "Firefox/Profiles",
+            BrowserKind::Chrome => "Google/Chrome",
+            BrowserKind::Edge => "Microsoft Edge",
+            BrowserKind::Arc => "Arc/User Data",
+            BrowserKind::Brave => "BraveSoftware/Brave-Browser",
+            BrowserKind::Chromium => "Chromium",
+            BrowserKind::Librewolf => "librewolf/Profiles",
+            BrowserKind::Opera => "com.operasoftware.Opera",
+            BrowserKind::OperaGx => "com.operasoftware.OperaGX",
+            BrowserKind::Vivaldi => "Vivaldi",
+            BrowserKind::Zen => "zen/Profiles",
+            BrowserKind::Safari | BrowserKind::Cachy => return None,
+        };
+        Some(support.join(relative)).filter(|p| p.exists())
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let local = dirs::data_local_dir()?;
+        let roaming = dirs::data_dir()?;
+        let path = match browser {
+            BrowserKind::Firefox => roaming.join("Mozilla/Firefox/Profiles"),
+            BrowserKind::Chrome => local.join("Google/Chrome/User Data"),
+            BrowserKind::Edge => local.join("Microsoft/Edge/User Data"),
+            BrowserKind::Arc => local.join("Packages/TheBrowserCompany.Arc_ttt1ap7aakyb4/LocalCache/Local/Arc/User Data"),
+            BrowserKind::Brave => local.join("BraveSoftware/Brave-Browser/User Data"),
+            BrowserKind::Chromium => local.join("Chromium/User Data"),
+            BrowserKind::Librewolf => roaming.join("librewolf/Profiles"),
+            BrowserKind::Opera => roaming.join("Opera Software/Opera Stable"),
+            BrowserKind::OperaGx => roaming.join("Opera Software/Opera GX Stable"),
+            BrowserKind::Vivaldi => local.join("Vivaldi/User Data"),
+            BrowserKind::Zen => roaming.join("zen/Profiles"),
+            BrowserKind::Safari | BrowserKind::Cachy => return None,
+        };
+        Some(path).filter(|p| p.exists())
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let home = dirs::home_dir()?;
+        let config = dirs::config_dir()?;
+        let path = match browser {
+            BrowserKind::Firefox => home.join(".mozilla/firefox"),
+            BrowserKind::Chrome => config.join("google-chrome"),
+            BrowserKind::Edge => config.join("microsoft-edge"),
+            BrowserKind::Arc => return None,
+            BrowserKind::Brave => config.join("BraveSoftware/Brave-Browser"),
+            BrowserKind::Chromium => config.join("chromium"),
+            BrowserKind::Librewolf => home.join(".librewolf"),
+            BrowserKind::Opera => config.join("opera"),
+            BrowserKind::OperaGx => return None,
+            BrowserKind::Vivaldi => config.join("vivaldi"),
+            BrowserKind::Zen => home.join(".zen"),
+            BrowserKind::Cachy => home.join(".cachy"),
+            BrowserKind::Safari => return None,
+        };
+        Some(path).filter(|p| p.exists())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_browser_kind_parsing() {
+        assert_eq!("chrome".parse::<BrowserKind>().unwrap(), BrowserKind::Chrome);
+        assert_eq!("Opera GX".parse::<BrowserKind>().unwrap(), BrowserKind::OperaGx);
+        assert!("netscape".parse::<BrowserKind>().is_err());
+    }
+
+    #[test]
+    fn test_selection_matches_label() {
+        let any = BrowserSelection::from_names(None, None).unwrap();
+        assert!(any.is_auto());
+        assert!(any.matches_label("Firefox"));
+
+        let chrome = BrowserSelection::from_names(Some("chrome"), None).unwrap();
+        assert!(chrome.matches_label("Chrome"));
+        assert!(chrome.matches_label("Chrome (Profile 1)"));
+        assert!(!chrome.matches_label("Chromium"));
+
+        let profile = BrowserSelection::from_names(Some("chrome"), Some("Profile 1")).unwrap();
+        assert!(profile.matches_label("Chrome (Profile 1)"));
+        assert!(!profile.matches_label("Chrome (Default)"));
+    }
+
+    #[test]
+    fn test_profile_requires_browser() {
+        assert!(BrowserSelection::from_names(None, Some("Default")).is_err());
+    }
+}
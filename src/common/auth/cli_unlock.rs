@@ -75,26 +75,23 @@ pub fn unlock_keychain_with_cli_password(password: &str) -> Result<()> {
 }
 
 
-/// Check if keychain is already unlocked (no password needed)
+/// Check if the login keychain is already unlocked (no password needed)
 pub fn is_keychain_unlocked() -> Result<bool> {
     if !cfg!(target_os = "macos") {
         return Ok(true); // Non-macOS doesn't need keychain unlock
     }
 
-    // Check if any keychain is locked
+    let home = std::env::var("HOME")
+        .map_err(|_| CsCliError::Authentication("Could not find home directory".to_string()))?;
+    let login_keychain = format!("{}/Library/Keychains/login.keychain-db", home);
+
+    // show-keychain-info fails without user interaction when the keychain is locked
     let output = Command::new("security")
-        .args(&["list-keychains"])
+        .args(["show-keychain-info", &login_keychain])
         .output()
-        .map_err(|e| CsCliError::Authentication(format!("Failed to list keychains: {}", e)))?;
+        .map_err(|e| CsCliError::Authentication(format!("Failed to check keychain: {}", e)))?;
 
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        // If we can list keychains, generally means they're accessible
-        let is_unlocked = !stdout.is_empty();
-        debug!("Keychain status: {}", if is_unlocked { "accessible" } else { "locked" });
-        Ok(is_unlocked)
-    } else {
-        debug!("Keychain list failed, assuming locked");
-        Ok(false)
-    }
+    let is_unlocked = output.status.success();
+    debug!("Keychain status: {}", if is_unlocked { "unlocked" } else { "locked" });
+    Ok(is_unlocked)
 }
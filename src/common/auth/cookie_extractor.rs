@@ -1,6 +1,8 @@
+use super::browser_profiles::{discover_profiles, find_profile, BrowserKind, BrowserProfile, BrowserSelection};
 use crate::{CsCliError, Result};
 use rookie;
 use std::collections::HashMap;
+use tracing::debug;
 
 /// Multi-browser cookie extractor using the rookie crate
 /// Supports Firefox, Chrome, Safari, Edge, Brave, and more
@@ -20,11 +22,159 @@ pub struct Cookie {
     pub expires: Option<u64>,
 }
 
+/// Valid cookies found in one browser profile
+#[derive(Debug, Clone)]
+pub struct CookieCandidate {
+    /// Where the cookies came from, e.g. "Chrome (Profile 1)"
+    pub source: String,
+    /// Unexpired cookies for the target domains
+    pub cookies: Vec<Cookie>,
+    /// Latest expiry among the cookies (Unix timestamp), None if all are session cookies
+    pub latest_expiry: Option<u64>,
+}
+
+impl CookieCandidate {
+    pub fn new(source: String, cookies: Vec<Cookie>) -> Self {
+        let latest_expiry = cookies.iter().filter_map(|c| c.expires).max();
+        Self {
+            source,
+            cookies,
+            latest_expiry,
+        }
+    }
+}
+
 impl CookieExtractor {
     pub fn new(domains: Vec<String>) -> Self {
         Self { domains }
     }
 
+    /// Extract cookies from a browser's default profile
+    pub fn extract_from_browser(&self, browser: BrowserKind) -> Result<Vec<Cookie>> {
+        match browser {
+            BrowserKind::Firefox => self.extract_firefox_cookies(),
+            BrowserKind::Chrome => self.extract_chrome_cookies(),
+            BrowserKind::Edge => self.extract_edge_cookies(),
+            BrowserKind::Arc => self.extract_arc_cookies(),
+            BrowserKind::Brave => self.extract_brave_cookies(),
+            BrowserKind::Chromium => self.extract_chromium_cookies(),
+            BrowserKind::Librewolf => self.extract_librewolf_cookies(),
+            BrowserKind::Opera => self.extract_opera_cookies(),
+            BrowserKind::OperaGx => self.extract_opera_gx_cookies(),
+            BrowserKind::Vivaldi => self.extract_vivaldi_cookies(),
+            BrowserKind::Zen => self.extract_zen_cookies(),
+            #[cfg(target_os = "macos")]
+            BrowserKind::Safari => self.extract_safari_cookies(),
+            #[cfg(target_os = "linux")]
+            BrowserKind::Cachy => self.extract_cachy_cookies(),
+            #[allow(unreachable_patterns)]
+            other => Err(CsCliError::CookieExtraction(format!(
+                "{other} is not supported on this platform"
+            ))),
+        }
+    }
+
+    /// Extract cookies from a specific browser profile's cookie database
+    pub fn extract_from_profile(&self, profile: &BrowserProfile) -> Result<Vec<Cookie>> {
+        let cookies_path = profile.cookies_path.to_string_lossy().to_string();
+        let key_path = profile
+            .key_path
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string());
+
+        match rookie::any_browser(&cookies_path, Some(self.domains.clone()), key_path.as_deref()) {
+            Ok(cookies) => Ok(cookies
+                .into_iter()
+                .map(|c| Cookie {
+                    name: c.name,
+                    value: c.value,
+                    domain: c.domain,
+                    path: c.path,
+                    secure: c.secure,
+                    http_only: c.http_only,
+                    expires: c.expires,
+                })
+                .collect()),
+            Err(e) => Err(CsCliError::CookieExtraction(format!(
+                "Failed to extract {} cookies: {e}",
+                profile.label()
+            ))),
+        }
+    }
+
+    /// Collect valid cookies from every browser/profile matching the selection
+    ///
+    /// Candidates are sorted so the session that expires latest comes first.
+    /// Browsers whose individual profiles cannot be read fall back to rookie's
+    /// default-profile extraction.
+    pub fn collect_candidates(&self, selection: &BrowserSelection) -> Result<Vec<CookieCandidate>> {
+        // Explicit profile: read exactly that database
+        if let (Some(browser), Some(profile_name)) = (selection.browser, &selection.profile) {
+            let profile = find_profile(browser, profile_name)?;
+            let cookies = self.filter_valid_cookies(self.extract_from_profile(&profile)?);
+            if cookies.is_empty() {
+                return Err(CsCliError::CookieExtraction(format!(
+                    "No valid Gong cookies found in {}",
+                    profile.label()
+                )));
+            }
+            return Ok(vec![CookieCandidate::new(profile.label(), cookies)]);
+        }
+
+        let browsers: Vec<BrowserKind> = match selection.browser {
+            Some(browser) => vec![browser],
+            None => BrowserKind::ALL
+                .into_iter()
+                .filter(|b| b.is_supported_on_platform())
+                .collect(),
+        };
+
+        let mut candidates = Vec::new();
+        for browser in browsers {
+            let mut found_in_profiles = false;
+
+            for profile in discover_profiles(browser) {
+                match self.extract_from_profile(&profile) {
+                    Ok(cookies) => {
+                        found_in_profiles = true;
+                        let valid = self.filter_valid_cookies(cookies);
+                        if !valid.is_empty() {
+                            candidates.push(CookieCandidate::new(profile.label(), valid));
+                        }
+                    }
+                    Err(e) => debug!(profile = %profile.label(), error = %e, "Profile cookie read failed"),
+                }
+            }
+
+            if !found_in_profiles {
+                match self.extract_from_browser(browser) {
+                    Ok(cookies) => {
+                        let valid = self.filter_valid_cookies(cookies);
+                        if !valid.is_empty() {
+                            candidates.push(CookieCandidate::new(browser.display_name().to_string(), valid));
+                        }
+                    }
+                    Err(e) => debug!(browser = %browser, error = %e, "Browser cookie read failed"),
+                }
+            }
+        }
+
+        // Freshest session first; stable sort keeps scan order for ties
+        candidates.sort_by(|a, b| b.latest_expiry.cmp(&a.latest_expiry));
+
+        if candidates.is_empty() {
+            let scope = match selection.browser {
+                Some(browser) => browser.display_name().to_string(),
+                None => "any supported browser".to_string(),
+            };
+            return Err(CsCliError::CookieExtraction(format!(
+                "No Gong cookies found in {scope}"
+            )));
+        }
+
+        Ok(candidates)
+    }
+
     /// Extract Gong cookies from Firefox (all profiles like Python version)
     pub fn extract_firefox_cookies(&self) -> Result<Vec<Cookie>> {
         match rookie::firefox(Some(self.domains.clone())) {
@@ -0,0 +1,362 @@
+//! Cookie import from exported files
+//!
+//! For machines where browser cookie stores can't be read (Linux servers,
+//! containers, locked-down laptops), cookies can be supplied as a Netscape
+//! `cookies.txt`, a browser HAR export, or a raw `Cookie:` header. The input is
+//! read from a file, stdin or an environment variable and the format is detected
+//! from its content.
+
+use super::cookie_extractor::Cookie;
+use crate::{CsCliError, Result};
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Where imported cookies are read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CookieImportSource {
+    /// A file on disk
+    File(PathBuf),
+    /// Standard input
+    Stdin,
+    /// An environment variable holding the cookie data itself
+    Env(String),
+}
+
+impl CookieImportSource {
+    /// Parse a source spec: `-` for stdin, `env:NAME` for an environment variable,
+    /// anything else is a file path
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            return Err(CsCliError::InvalidArguments {
+                message: "Cookie source cannot be empty".to_string(),
+            });
+        }
+
+        if spec == "-" {
+            return Ok(Self::Stdin);
+        }
+
+        if let Some(var) = spec.strip_prefix("env:") {
+            if var.is_empty() {
+                return Err(CsCliError::InvalidArguments {
+                    message: "Cookie source 'env:' needs a variable name".to_string(),
+                });
+            }
+            return Ok(Self::Env(var.to_string()));
+        }
+
+        Ok(Self::File(PathBuf::from(spec)))
+    }
+
+    /// Human-readable label used as the session's browser source
+    pub fn label(&self) -> String {
+        match self {
+            Self::File(path) => format!("Imported ({})", path.display()),
+            Self::Stdin => "Imported (stdin)".to_string(),
+            Self::Env(var) => format!("Imported (${var})"),
+        }
+    }
+
+    /// Read the raw cookie data
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::File(path) => std::fs::read_to_string(path).map_err(|e| {
+                CsCliError::FileIo(format!("Failed to read cookies from {}: {e}", path.display()))
+            }),
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| CsCliError::FileIo(format!("Failed to read cookies from stdin: {e}")))?;
+                Ok(input)
+            }
+            Self::Env(var) => std::env::var(var).map_err(|_| {
+                CsCliError::Configuration(format!("Environment variable {var} is not set"))
+            }),
+        }
+    }
+}
+
+/// Supported cookie export formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CookieFormat {
+    /// Netscape/Mozilla `cookies.txt` (curl, wget, browser extensions)
+    Netscape,
+    /// HTTP Archive JSON exported from browser dev tools
+    Har,
+    /// Raw `Cookie:` request header
+    Header,
+}
+
+impl CookieFormat {
+    /// Detect the format from the content
+    pub fn detect(input: &str) -> Self {
+        let trimmed = input.trim_start();
+        if trimmed.starts_with('{') {
+            return Self::Har;
+        }
+
+        let is_netscape_line = |line: &str| {
+            line.starts_with("# Netscape")
+                || line.starts_with("#HttpOnly_")
+                || line.split('\t').count() >= 7
+        };
+        if trimmed.lines().any(is_netscape_line) {
+            return Self::Netscape;
+        }
+
+        Self::Header
+    }
+}
+
+/// Imports cookies for a set of target domains
+pub struct CookieImporter {
+    /// Domains to keep cookies for (suffix match)
+    domains: Vec<String>,
+}
+
+impl CookieImporter {
+    pub fn new(domains: Vec<String>) -> Self {
+        Self { domains }
+    }
+
+    /// Read and parse cookies from a source
+    pub fn import(&self, source: &CookieImportSource) -> Result<Vec<Cookie>> {
+        let input = source.read()?;
+        let cookies = self.parse(&input)?;
+
+        if cookies.is_empty() {
+            return Err(CsCliError::CookieExtraction(format!(
+                "No Gong cookies found in {}",
+                source.label()
+            )));
+        }
+
+        Ok(cookies)
+    }
+
+    /// Parse cookies in any supported format
+    pub fn parse(&self, input: &str) -> Result<Vec<Cookie>> {
+        let cookies = match CookieFormat::detect(input) {
+            CookieFormat::Netscape => self.parse_netscape(input),
+            CookieFormat::Har => self.parse_har(input)?,
+            CookieFormat::Header => self.parse_header(input),
+        };
+
+        Ok(cookies
+            .into_iter()
+            .filter(|c| self.matches_domain(&c.domain))
+            .collect())
+    }
+
+    /// Parse a Netscape `cookies.txt` file
+    ///
+    /// Columns: domain, include-subdomains, path, secure, expires, name, value.
+    /// Lines prefixed with `#HttpOnly_` are HttpOnly cookies, other `#` lines are comments.
+    pub fn parse_netscape(&self, input: &str) -> Vec<Cookie> {
+        input
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim_end_matches(['\r', '\n']);
+                let (line, http_only) = match line.strip_prefix("#HttpOnly_") {
+                    Some(rest) => (rest, true),
+                    None if line.starts_with('#') || line.trim().is_empty() => return None,
+                    None => (line, false),
+                };
+
+                let fields: Vec<&str> = line.split('\t').collect();
+                if fields.len() < 7 {
+                    return None;
+                }
+
+                let expires = fields[4].parse::<u64>().ok().filter(|&e| e > 0);
+
+                Some(Cookie {
+                    name: fields[5].to_string(),
+                    value: fields[6..].join("\t"),
+                    domain: fields[0].to_string(),
+                    path: fields[2].to_string(),
+                    secure: fields[3].eq_ignore_ascii_case("TRUE"),
+                    http_only,
+                    expires,
+                })
+            })
+            .collect()
+    }
+
+    /// Parse cookies from a HAR export
+    ///
+    /// Uses the request cookies of each entry; entries without a cookie domain
+    /// take the host of the request URL so cell detection still works.
+    pub fn parse_har(&self, input: &str) -> Result<Vec<Cookie>> {
+        let har: serde_json::Value = serde_json::from_str(input)
+            .map_err(|e| CsCliError::CookieExtraction(format!("Invalid HAR file: {e}")))?;
+
+        let entries = har
+            .pointer("/log/entries")
+            .and_then(|e| e.as_array())
+            .ok_or_else(|| CsCliError::CookieExtraction("HAR file has no log entries".to_string()))?;
+
+        let mut cookies: Vec<Cookie> = Vec::new();
+        for entry in entries {
+            let request = match entry.get("request") {
+                Some(request) => request,
+                None => continue,
+            };
+
+            let url = request.get("url").and_then(|u| u.as_str()).unwrap_or("");
+            let host = url_host(url);
+            let secure = url.starts_with("https://");
+
+            let Some(request_cookies) = request.get("cookies").and_then(|c| c.as_array()) else {
+                continue;
+            };
+
+            for cookie in request_cookies {
+                let (Some(name), Some(value)) = (
+                    cookie.get("name").and_then(|n| n.as_str()),
+                    cookie.get("value").and_then(|v| v.as_str()),
+                ) else {
+                    continue;
+                };
+
+                let domain = cookie
+                    .get("domain")
+                    .and_then(|d| d.as_str())
+                    .unwrap_or(host)
+                    .to_string();
+
+                // Later requests carry fresher values for the same cookie
+                cookies.retain(|c| !(c.name == name && c.domain == domain));
+                cookies.push(Cookie {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                    domain,
+                    path: cookie
+                        .get("path")
+                        .and_then(|p| p.as_str())
+                        .unwrap_or("/")
+                        .to_string(),
+                    secure: cookie.get("secure").and_then(|s| s.as_bool()).unwrap_or(secure),
+                    http_only: cookie.get("httpOnly").and_then(|h| h.as_bool()).unwrap_or(false),
+                    expires: None,
+                });
+            }
+        }
+
+        Ok(cookies)
+    }
+
+    /// Parse a raw `Cookie:` header (`name=value; name2=value2`)
+    ///
+    /// The header carries no domain, so cookies are attributed to the first
+    /// target domain; the cell is then decoded from the `cell` cookie.
+    pub fn parse_header(&self, input: &str) -> Vec<Cookie> {
+        let header = input.trim();
+        let header = header
+            .strip_prefix("Cookie:")
+            .or_else(|| header.strip_prefix("cookie:"))
+            .unwrap_or(header);
+
+        let domain = self
+            .domains
+            .first()
+            .cloned()
+            .unwrap_or_default();
+
+        header
+            .split(';')
+            .filter_map(|pair| {
+                let (name, value) = pair.trim().split_once('=')?;
+                let name = name.trim();
+                if name.is_empty() {
+                    return None;
+                }
+
+                Some(Cookie {
+                    name: name.to_string(),
+                    value: value.trim().to_string(),
+                    domain: domain.clone(),
+                    path: "/".to_string(),
+                    secure: true,
+                    http_only: false,
+                    expires: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether a cookie domain belongs to one of the target domains
+    fn matches_domain(&self, domain: &str) -> bool {
+        let domain = domain.trim_start_matches('.').to_lowercase();
+        self.domains.iter().any(|target| {
+            let target = target.trim_start_matches('.').to_lowercase();
+            domain == target || domain.ends_with(&format!(".{target}"))
+        })
+    }
+}
+
+/// Host part of a URL, without scheme, port or path
+fn url_host(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let authority = without_scheme.split(['/', '?', '#']).next().unwrap_or("");
+    authority.split(':').next().unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn importer() -> CookieImporter {
+        CookieImporter::new(vec!["gong.io".to_string()])
+    }
+
+    #[test]
+    fn test_parse_netscape_cookies() {
+        let input = "# Netscape HTTP Cookie File\n\
+            .gong.io\tTRUE\t/\tTRUE\t4102444800\tg-session\tabc\n\
+            #HttpOnly_us-14496.app.gong.io\tFALSE\t/\tTRUE\t0\tcell\tx.y.z\n\
+            .example.com\tTRUE\t/\tFALSE\t0\tother\t1\n";
+
+        let cookies = importer().parse(input).unwrap();
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(cookies[0].name, "g-session");
+        assert_eq!(cookies[0].expires, Some(4102444800));
+        assert!(cookies[1].http_only);
+        assert_eq!(cookies[1].domain, "us-14496.app.gong.io");
+        assert_eq!(cookies[1].expires, None);
+    }
+
+    #[test]
+    fn test_parse_har_uses_request_host() {
+        let input = r#"{"log": {"entries": [
+            {"request": {"url": "https://us-14496.app.gong.io/home", "cookies": [
+                {"name": "g-session", "value": "old"}
+            ]}},
+            {"request": {"url": "https://us-14496.app.gong.io/ajax", "cookies": [
+                {"name": "g-session", "value": "new"}
+            ]}}
+        ]}}"#;
+
+        let cookies = importer().parse(input).unwrap();
+        assert_eq!(cookies.len(), 1);
+        assert_eq!(cookies[0].value, "new");
+        assert_eq!(cookies[0].domain, "us-14496.app.gong.io");
+    }
+
+    #[test]
+    fn test_parse_cookie_header_and_source_spec() {
+        let cookies = importer().parse("Cookie: cell=a.b.c; g-session=abc=def").unwrap();
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(cookies[1].value, "abc=def");
+        assert_eq!(cookies[0].domain, "gong.io");
+
+        assert_eq!(CookieImportSource::parse("-").unwrap(), CookieImportSource::Stdin);
+        assert_eq!(
+            CookieImportSource::parse("env:GONG_COOKIES").unwrap(),
+            CookieImportSource::Env("GONG_COOKIES".to_string())
+        );
+        assert!(CookieImportSource::parse("env:").is_err());
+    }
+}
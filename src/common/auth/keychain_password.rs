@@ -0,0 +1,230 @@
+//! Where the macOS keychain password comes from
+//!
+//! Passing the password with `--keychain-password` leaves it in shell history
+//! and `ps` output, so it can instead be read from stdin, an inherited file
+//! descriptor, an environment variable or a password manager command
+//! (`op read ...`, `pass show ...`). The keychain is only unlocked when it is
+//! actually locked, so most runs never need the password at all.
+
+use super::cli_unlock::{is_keychain_unlocked, unlock_keychain_with_cli_password};
+use crate::{CsCliError, Result};
+use std::io::BufRead;
+use std::process::{Command, Stdio};
+use tracing::{debug, info};
+
+/// Environment variable read by `--keychain-password-from env`
+pub const DEFAULT_KEYCHAIN_PASSWORD_ENV: &str = "GONG_KEYCHAIN_PASSWORD";
+
+/// Source of the macOS keychain password
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum KeychainPasswordSource {
+    /// Ask on the terminal
+    Prompt,
+    /// First line of standard input
+    Stdin,
+    /// First line read from an inherited file descriptor
+    Fd(u32),
+    /// An environment variable holding the password
+    Env(String),
+    /// First line printed by a shell command, e.g. a password manager
+    Command(String),
+    /// A password given directly (`--keychain-password`)
+    Value(String),
+    /// Never unlock; macOS shows its own dialog if it needs access
+    #[default]
+    Skip,
+}
+
+impl KeychainPasswordSource {
+    /// Parse a source spec: `prompt`, `stdin` (or `-`), `fd:N`, `env` or
+    /// `env:NAME`, `cmd:COMMAND`, or `none`
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+        let invalid = |message: String| CsCliError::InvalidArguments { message };
+
+        match spec {
+            "prompt" => return Ok(Self::Prompt),
+            "stdin" | "-" => return Ok(Self::Stdin),
+            "env" => return Ok(Self::Env(DEFAULT_KEYCHAIN_PASSWORD_ENV.to_string())),
+            "none" => return Ok(Self::Skip),
+            _ => {}
+        }
+
+        if let Some(fd) = spec.strip_prefix("fd:") {
+            return fd.parse().map(Self::Fd).map_err(|_| {
+                invalid(format!(
+                    "Keychain password source '{spec}' needs a file descriptor number"
+                ))
+            });
+        }
+        if let Some(var) = spec.strip_prefix("env:") {
+            if var.is_empty() {
+                return Err(invalid(
+                    "Keychain password source 'env:' needs a variable name".to_string(),
+                ));
+            }
+            return Ok(Self::Env(var.to_string()));
+        }
+        if let Some(command) = spec.strip_prefix("cmd:") {
+            if command.trim().is_empty() {
+                return Err(invalid(
+                    "Keychain password source 'cmd:' needs a command".to_string(),
+                ));
+            }
+            return Ok(Self::Command(command.trim().to_string()));
+        }
+
+        Err(invalid(format!(
+            "Unknown keychain password source '{spec}' (expected prompt, stdin, fd:N, env:NAME, cmd:COMMAND or none)"
+        )))
+    }
+
+    /// Human-readable description that never includes the password
+    pub fn label(&self) -> String {
+        match self {
+            Self::Prompt => "terminal prompt".to_string(),
+            Self::Stdin => "stdin".to_string(),
+            Self::Fd(fd) => format!("file descriptor {fd}"),
+            Self::Env(var) => format!("${var}"),
+            Self::Command(command) => format!("command `{command}`"),
+            Self::Value(_) => "--keychain-password".to_string(),
+            Self::Skip => "none".to_string(),
+        }
+    }
+
+    /// Read the password; `None` for [`KeychainPasswordSource::Skip`]
+    pub fn read_password(&self) -> Result<Option<String>> {
+        let password = match self {
+            Self::Skip => return Ok(None),
+            Self::Prompt => rpassword::prompt_password("Enter your Macbook password: ")
+                .map_err(|e| CsCliError::Authentication(format!("Failed to read password: {e}")))?,
+            Self::Stdin => first_line(std::io::stdin().lock(), "stdin")?,
+            Self::Fd(fd) => {
+                let path = format!("/dev/fd/{fd}");
+                let file = std::fs::File::open(&path).map_err(|e| {
+                    CsCliError::FileIo(format!("Failed to open file descriptor {fd}: {e}"))
+                })?;
+                first_line(
+                    std::io::BufReader::new(file),
+                    &format!("file descriptor {fd}"),
+                )?
+            }
+            Self::Env(var) => std::env::var(var).map_err(|_| {
+                CsCliError::Configuration(format!("Environment variable {var} is not set"))
+            })?,
+            Self::Command(command) => run_password_command(command)?,
+            Self::Value(password) => password.clone(),
+        };
+
+        if password.is_empty() {
+            return Err(CsCliError::Authentication(format!(
+                "Keychain password from {} is empty",
+                self.label()
+            )));
+        }
+        Ok(Some(password))
+    }
+}
+
+fn first_line(mut reader: impl BufRead, origin: &str) -> Result<String> {
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|e| {
+        CsCliError::FileIo(format!(
+            "Failed to read keychain password from {origin}: {e}"
+        ))
+    })?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn run_password_command(command: &str) -> Result<String> {
+    let output = Command::new("sh")
+        .args(["-c", command])
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| CsCliError::Authentication(format!("Failed to run `{command}`: {e}")))?;
+
+    if !output.status.success() {
+        return Err(CsCliError::Authentication(format!(
+            "Keychain password command `{command}` failed ({})",
+            output.status
+        )));
+    }
+
+    first_line(output.stdout.as_slice(), &format!("`{command}`"))
+}
+
+/// Unlock the macOS keychain with a password from `source`
+///
+/// Does nothing off macOS, when the keychain is already unlocked, or when the
+/// source is [`KeychainPasswordSource::Skip`].
+pub fn unlock_keychain_from(source: &KeychainPasswordSource) -> Result<()> {
+    if !cfg!(target_os = "macos") {
+        return Ok(());
+    }
+
+    if is_keychain_unlocked().unwrap_or(false) {
+        debug!("Keychain already unlocked, no password needed");
+        return Ok(());
+    }
+
+    match source.read_password()? {
+        Some(password) => {
+            unlock_keychain_with_cli_password(&password)?;
+            info!(source = %source.label(), "Keychain unlocked for session");
+        }
+        None => debug!("No keychain password source; macOS may ask for access"),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sources() {
+        assert_eq!(
+            KeychainPasswordSource::parse("-").unwrap(),
+            KeychainPasswordSource::Stdin
+        );
+        assert_eq!(
+            KeychainPasswordSource::parse("fd:3").unwrap(),
+            KeychainPasswordSource::Fd(3)
+        );
+        assert_eq!(
+            KeychainPasswordSource::parse("env").unwrap(),
+            KeychainPasswordSource::Env(DEFAULT_KEYCHAIN_PASSWORD_ENV.to_string())
+        );
+        assert_eq!(
+            KeychainPasswordSource::parse("cmd: pass show mac/login").unwrap(),
+            KeychainPasswordSource::Command("pass show mac/login".to_string())
+        );
+        assert_eq!(
+            KeychainPasswordSource::parse("none").unwrap(),
+            KeychainPasswordSource::Skip
+        );
+        assert!(KeychainPasswordSource::parse("fd:x").is_err());
+        assert!(KeychainPasswordSource::parse("keychain").is_err());
+    }
+
+    #[test]
+    fn test_read_password_from_command_and_env() {
+        let source = KeychainPasswordSource::Command("printf 'hunter2\\nsecond line'".to_string());
+        assert_eq!(source.read_password().unwrap(), Some("hunter2".to_string()));
+
+        assert!(KeychainPasswordSource::Command("exit 1".to_string())
+            .read_password()
+            .is_err());
+        assert!(
+            KeychainPasswordSource::Env("CS_CLI_TEST_UNSET_PASSWORD".to_string())
+                .read_password()
+                .is_err()
+        );
+        assert_eq!(KeychainPasswordSource::Skip.read_password().unwrap(), None);
+        assert!(!KeychainPasswordSource::Value("hunter2".to_string())
+            .label()
+            .contains("hunter2"));
+    }
+}
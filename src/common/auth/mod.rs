@@ -4,11 +4,17 @@
 //! session management, and browser-based authentication patterns.
 
 pub mod browser_auth;
+pub mod browser_profiles;
 pub mod cli_unlock;
 pub mod cookie_extractor;
+pub mod cookie_import;
+pub mod keychain_password;
 pub mod session_manager;
 
 pub use browser_auth::*;
+pub use browser_profiles::*;
 pub use cli_unlock::*;
 pub use cookie_extractor::*;
+pub use cookie_import::*;
+pub use keychain_password::*;
 pub use session_manager::*;
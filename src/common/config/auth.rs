@@ -12,6 +12,34 @@ pub struct AuthSettings {
     pub retry_attempts: u32,
     pub retry_backoff_base: f64,
     pub retry_backoff_seconds: f64,
+    /// Persist the authenticated session to an encrypted local file between runs
+    #[serde(default = "default_persist_session")]
+    pub persist_session: bool,
+    /// Maximum age of a persisted session before browser extraction is forced
+    #[serde(default = "default_session_max_age_hours")]
+    pub session_max_age_hours: u64,
+    /// Browser to read cookies from ("auto" or unset scans every browser and profile)
+    #[serde(default)]
+    pub browser: Option<String>,
+    /// Browser profile to read cookies from (requires `browser`)
+    #[serde(default)]
+    pub browser_profile: Option<String>,
+    /// Import cookies instead of reading a browser: a file path, "-" for stdin,
+    /// or "env:NAME" (Netscape cookies.txt, HAR or raw Cookie header)
+    #[serde(default)]
+    pub cookie_import: Option<String>,
+    /// Where the macOS keychain password comes from: "prompt", "stdin", "fd:N",
+    /// "env:NAME", "cmd:COMMAND" or "none"
+    #[serde(default)]
+    pub keychain_password_from: Option<String>,
+}
+
+fn default_persist_session() -> bool {
+    true
+}
+
+fn default_session_max_age_hours() -> u64 {
+    72
 }
 
 impl Default for AuthSettings {
@@ -22,6 +50,12 @@ impl Default for AuthSettings {
             retry_attempts: 3,
             retry_backoff_base: 2.0,
             retry_backoff_seconds: 1.0,
+            persist_session: default_persist_session(),
+            session_max_age_hours: default_session_max_age_hours(),
+            browser: None,
+            browser_profile: None,
+            cookie_import: None,
+            keychain_password_from: None,
         }
     }
 }
@@ -0,0 +1,281 @@
+//! Layered configuration files
+//!
+//! Settings are merged from, lowest to highest priority:
+//! 1. Built-in defaults
+//! 2. User config file (`$XDG_CONFIG_HOME/cs-cli/config.toml`, usually `~/.config/cs-cli/config.toml`)
+//! 3. Project config file (`cs-cli.toml` in the current directory or the nearest parent)
+//! 4. `CS_CLI_` environment variables, `__` separating sections (`CS_CLI_HTTP__POOL_SIZE=10`)
+//! 5. Command-line flags (applied by the caller)
+
+use figment::providers::{Env, Format, Serialized, Toml};
+use figment::Figment;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{CsCliError, Result};
+
+/// Project config file name
+pub const PROJECT_CONFIG_FILE: &str = "cs-cli.toml";
+
+/// Prefix for configuration environment variables
+pub const CONFIG_ENV_PREFIX: &str = "CS_CLI_";
+
+/// User config file location, honouring `XDG_CONFIG_HOME`
+pub fn user_config_path() -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))?;
+
+    Some(config_home.join("cs-cli").join("config.toml"))
+}
+
+/// Nearest `cs-cli.toml` in `start` or one of its parents
+pub fn find_project_config(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(PROJECT_CONFIG_FILE))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Config files taking part in a load
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigSources {
+    /// User config file (may not exist yet)
+    pub user: Option<PathBuf>,
+    /// Project config file, if one was found
+    pub project: Option<PathBuf>,
+}
+
+impl ConfigSources {
+    /// Locate the user and project config files
+    pub fn discover() -> Self {
+        let project = std::env::current_dir()
+            .ok()
+            .and_then(|dir| find_project_config(&dir));
+
+        Self {
+            user: user_config_path(),
+            project,
+        }
+    }
+
+    /// Existing config files, lowest priority first
+    pub fn files(&self) -> Vec<&Path> {
+        [self.user.as_deref(), self.project.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|path| path.is_file())
+            .collect()
+    }
+
+    /// Merge defaults, config files and `CS_CLI_` environment variables
+    pub fn figment<T: Serialize>(&self, defaults: &T) -> Figment {
+        let mut figment = Figment::from(Serialized::defaults(defaults));
+        for path in self.files() {
+            figment = figment.merge(Toml::file(path));
+        }
+        figment.merge(Env::prefixed(CONFIG_ENV_PREFIX).split("__"))
+    }
+}
+
+/// Parse a config file into JSON for key checks and lookups
+pub fn read_config_file(path: &Path) -> Result<Value> {
+    let contents = fs::read_to_string(path).map_err(|e| {
+        CsCliError::FileIo(format!(
+            "Failed to read config file {}: {e}",
+            path.display()
+        ))
+    })?;
+
+    let table: toml::Table = toml::from_str(&contents).map_err(|e| {
+        CsCliError::Configuration(format!("Invalid TOML in {}: {e}", path.display()))
+    })?;
+
+    serde_json::to_value(table).map_err(|e| {
+        CsCliError::Configuration(format!("Invalid config file {}: {e}", path.display()))
+    })
+}
+
+/// Dotted keys present in `value` but not in `schema`
+///
+/// The schema is the serialized default configuration, so every known setting
+/// appears in it (unset optional settings as `null`). Tables that are empty by
+/// default, such as `output.csv.account_ids`, are free-form and take any key.
+pub fn unknown_keys(value: &Value, schema: &Value) -> Vec<String> {
+    fn walk(value: &Value, schema: &Value, prefix: &str, unknown: &mut Vec<String>) {
+        let (Value::Object(entries), Value::Object(known)) = (value, schema) else {
+            return;
+        };
+
+        for (key, entry) in entries {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+
+            match known.get(key) {
+                Some(Value::Object(map)) if map.is_empty() => {}
+                Some(known_entry) => walk(entry, known_entry, &path, unknown),
+                None => unknown.push(path),
+            }
+        }
+    }
+
+    let mut unknown = Vec::new();
+    walk(value, schema, "", &mut unknown);
+    unknown
+}
+
+/// Look up a dotted key such as `auth.browser`
+pub fn get_key<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.')
+        .try_fold(value, |current, part| current.as_object()?.get(part))
+}
+
+/// Set a dotted key in a TOML config file, creating the file if needed
+///
+/// The raw value is converted to the type of the key in `schema`; `null`
+/// settings accept any string. An empty value removes the key, restoring the
+/// default from lower layers.
+pub fn set_key_in_file(path: &Path, key: &str, raw_value: &str, schema: &Value) -> Result<()> {
+    let expected = get_key(schema, key).ok_or_else(|| CsCliError::InvalidArguments {
+        message: format!("Unknown setting '{key}' (see `cs-cli config show`)"),
+    })?;
+    if expected.is_object() {
+        return Err(CsCliError::InvalidArguments {
+            message: format!("'{key}' is a section; set one of its keys instead"),
+        });
+    }
+
+    let mut table: toml::Table = if path.is_file() {
+        let contents = fs::read_to_string(path).map_err(|e| {
+            CsCliError::FileIo(format!(
+                "Failed to read config file {}: {e}",
+                path.display()
+            ))
+        })?;
+        toml::from_str(&contents).map_err(|e| {
+            CsCliError::Configuration(format!("Invalid TOML in {}: {e}", path.display()))
+        })?
+    } else {
+        toml::Table::new()
+    };
+
+    let parts: Vec<&str> = key.split('.').collect();
+    let (leaf, sections) = parts.split_last().expect("split always yields one part");
+
+    let mut current = &mut table;
+    for section in sections {
+        current = current
+            .entry(section.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| {
+                CsCliError::Configuration(format!(
+                    "'{section}' in {} is not a table",
+                    path.display()
+                ))
+            })?;
+    }
+
+    if raw_value.trim().is_empty() {
+        current.remove(*leaf);
+    } else {
+        current.insert(leaf.to_string(), parse_value(key, raw_value, expected)?);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            CsCliError::FileIo(format!("Failed to create {}: {e}", parent.display()))
+        })?;
+    }
+
+    let contents = toml::to_string_pretty(&table)
+        .map_err(|e| CsCliError::Configuration(format!("Failed to serialize config: {e}")))?;
+    fs::write(path, contents).map_err(|e| {
+        CsCliError::FileIo(format!(
+            "Failed to write config file {}: {e}",
+            path.display()
+        ))
+    })
+}
+
+/// Convert a command-line value to the TOML type expected for `key`
+fn parse_value(key: &str, raw: &str, expected: &Value) -> Result<toml::Value> {
+    let raw = raw.trim();
+    let invalid = |kind: &str| CsCliError::InvalidArguments {
+        message: format!("'{key}' expects {kind}, got '{raw}'"),
+    };
+
+    match expected {
+        Value::Bool(_) => match raw.to_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => Ok(toml::Value::Boolean(true)),
+            "false" | "0" | "no" | "off" => Ok(toml::Value::Boolean(false)),
+            _ => Err(invalid("true or false")),
+        },
+        Value::Number(n) if n.is_f64() => raw
+            .parse::<f64>()
+            .map(toml::Value::Float)
+            .map_err(|_| invalid("a number")),
+        Value::Number(_) => raw
+            .parse::<i64>()
+            .ok()
+            .filter(|n| *n >= 0)
+            .map(toml::Value::Integer)
+            .ok_or_else(|| invalid("a whole number")),
+        _ => Ok(toml::Value::String(raw.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unknown_keys_and_lookup() {
+        let schema = json!({"http": {"pool_size": 50}, "auth": {"browser": null}, "ids": {}});
+        let value = json!({
+            "http": {"pool_sise": 10},
+            "auth": {"browser": "chrome"},
+            "extra": 1,
+            "ids": {"Acme": "001"}
+        });
+
+        assert_eq!(
+            unknown_keys(&value, &schema),
+            vec!["extra", "http.pool_sise"]
+        );
+        assert_eq!(get_key(&value, "auth.browser"), Some(&json!("chrome")));
+        assert_eq!(get_key(&value, "auth.missing"), None);
+    }
+
+    #[test]
+    fn test_set_key_in_file_checks_types() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-config-test-{}", std::process::id()));
+        let path = dir.join("config.toml");
+        let schema =
+            json!({"http": {"pool_size": 50, "timeout_seconds": 30.0}, "auth": {"browser": null}});
+
+        set_key_in_file(&path, "http.pool_size", "10", &schema).unwrap();
+        set_key_in_file(&path, "auth.browser", "firefox", &schema).unwrap();
+        assert!(set_key_in_file(&path, "http.pool_size", "many", &schema).is_err());
+        assert!(set_key_in_file(&path, "http.nope", "1", &schema).is_err());
+
+        let written = read_config_file(&path).unwrap();
+        assert_eq!(get_key(&written, "http.pool_size"), Some(&json!(10)));
+        assert_eq!(get_key(&written, "auth.browser"), Some(&json!("firefox")));
+
+        set_key_in_file(&path, "auth.browser", "", &schema).unwrap();
+        assert_eq!(
+            get_key(&read_config_file(&path).unwrap(), "auth.browser"),
+            None
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
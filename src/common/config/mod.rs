@@ -4,8 +4,12 @@
 
 pub mod auth;
 pub mod http;
+pub mod layered;
 pub mod loader;
+pub mod update;
 
 pub use auth::*;
 pub use http::*;
+pub use layered::*;
 pub use loader::*;
+pub use update::*;
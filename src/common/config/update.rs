@@ -0,0 +1,41 @@
+//! Self-update configuration settings
+
+use serde::{Deserialize, Serialize};
+
+/// Which releases to follow
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// Published releases only (default)
+    #[default]
+    Stable,
+    /// Pre-releases too, e.g. `1.3.0-beta.1`
+    Beta,
+}
+
+/// What happens when a newer release is found at startup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateCheck {
+    /// Offer to install it on a terminal, otherwise print a notice (default)
+    #[default]
+    Auto,
+    /// Only print a notice
+    Notify,
+    /// Never check at startup (`cs-cli update` still works)
+    Off,
+}
+
+/// Self-update policy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateSettings {
+    /// Release channel
+    #[serde(default)]
+    pub channel: UpdateChannel,
+    /// Only consider versions matching this requirement, e.g. "1.x" or "~1.4"
+    #[serde(default)]
+    pub pin: Option<String>,
+    /// Startup check behavior
+    #[serde(default)]
+    pub check: UpdateCheck,
+}
@@ -1,8 +1,90 @@
 // Error types for the application
-// To be implemented in Phase 1
 
 use std::fmt;
 
+/// Boxed underlying error kept for the source chain
+pub type ErrorSource = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// A failed request to an HTTP endpoint, with enough context to act on it
+#[derive(Debug)]
+pub struct HttpFailure {
+    /// HTTP method ("GET", "POST")
+    pub method: String,
+    /// Endpoint URL without query string (query parameters can carry IDs)
+    pub endpoint: String,
+    /// HTTP status, None if no response was received
+    pub status: Option<u16>,
+    /// How many times the request was retried before giving up
+    pub retries: u32,
+    /// What went wrong, e.g. a truncated response body
+    pub message: String,
+    /// Underlying transport or parse error
+    pub source: Option<ErrorSource>,
+}
+
+impl fmt::Display for HttpFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.endpoint)?;
+        match self.status {
+            Some(status) => write!(f, " returned {status}")?,
+            None => write!(f, " failed")?,
+        }
+        if self.retries > 0 {
+            write!(f, " after {} retries", self.retries)?;
+        }
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Broad failure categories, each mapped to a stable process exit code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// Unexpected internal error (exit 1)
+    Internal,
+    /// Bad command-line arguments or input files (exit 2)
+    Usage,
+    /// The request succeeded but found nothing to extract (exit 3)
+    NoData,
+    /// Gong session missing, expired or rejected (exit 4)
+    Authentication,
+    /// Gong answered with a server error (exit 5)
+    GongUnavailable,
+    /// No response: DNS, connection, TLS or timeout (exit 6)
+    Network,
+    /// Gong kept answering 429 Too Many Requests (exit 7)
+    RateLimited,
+    /// Gong answered with an unexpected response (exit 8)
+    Api,
+    /// Invalid configuration (exit 9)
+    Configuration,
+    /// Local file read/write failure (exit 10)
+    FileIo,
+}
+
+impl ErrorCategory {
+    /// Process exit code for this category
+    ///
+    /// These codes are part of the CLI contract; scripts rely on them, so
+    /// existing values must never change.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorCategory::Internal => 1,
+            ErrorCategory::Usage => 2,
+            ErrorCategory::NoData => 3,
+            ErrorCategory::Authentication => 4,
+            ErrorCategory::GongUnavailable => 5,
+            ErrorCategory::Network => 6,
+            ErrorCategory::RateLimited => 7,
+            ErrorCategory::Api => 8,
+            ErrorCategory::Configuration => 9,
+            ErrorCategory::FileIo => 10,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum CsCliError {
     Authentication(String),
@@ -13,9 +95,107 @@ pub enum CsCliError {
     CookieExtraction(String),
     InvalidArguments { message: String },
     UpdateError(String),
+    /// Extraction ran but found nothing
+    NoData(String),
+    /// Endpoint failure with HTTP context
+    Http(Box<HttpFailure>),
     Generic(String),
 }
 
+impl CsCliError {
+    /// Endpoint failure; the query string is dropped from `url`
+    pub fn http(method: &str, url: &str, status: Option<u16>, message: impl Into<String>) -> Self {
+        let endpoint = url.split('?').next().unwrap_or(url).to_string();
+        CsCliError::Http(Box::new(HttpFailure {
+            method: method.to_uppercase(),
+            endpoint,
+            status,
+            retries: 0,
+            message: message.into(),
+            source: None,
+        }))
+    }
+
+    /// Endpoint failure from a non-success status and (possibly long) response body
+    pub fn http_status(method: &str, url: &str, status: u16, body: &str) -> Self {
+        let body = body.trim();
+        let truncated = match body.char_indices().nth(500) {
+            Some((idx, _)) => format!("{}...", &body[..idx]),
+            None => body.to_string(),
+        };
+        Self::http(method, url, Some(status), truncated)
+    }
+
+    /// Record how many retries were attempted (HTTP errors only)
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        if let CsCliError::Http(failure) = &mut self {
+            failure.retries = retries;
+        }
+        self
+    }
+
+    /// Attach the underlying error (HTTP errors only)
+    pub fn with_source<E>(mut self, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        if let CsCliError::Http(failure) = &mut self {
+            failure.source = Some(Box::new(source));
+        }
+        self
+    }
+
+    /// HTTP status of an endpoint failure
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            CsCliError::Http(failure) => failure.status,
+            _ => None,
+        }
+    }
+
+    /// Failure category, used for exit codes and user-facing hints
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            CsCliError::Authentication(_) | CsCliError::CookieExtraction(_) => {
+                ErrorCategory::Authentication
+            }
+            CsCliError::ApiRequest(_) => ErrorCategory::Api,
+            CsCliError::FileIo(_) => ErrorCategory::FileIo,
+            CsCliError::Configuration(_) => ErrorCategory::Configuration,
+            CsCliError::NetworkTimeout(_) => ErrorCategory::Network,
+            CsCliError::InvalidArguments { .. } => ErrorCategory::Usage,
+            CsCliError::NoData(_) => ErrorCategory::NoData,
+            CsCliError::UpdateError(_) | CsCliError::Generic(_) => ErrorCategory::Internal,
+            CsCliError::Http(failure) => match failure.status {
+                None => ErrorCategory::Network,
+                Some(401) | Some(403) => ErrorCategory::Authentication,
+                Some(429) => ErrorCategory::RateLimited,
+                Some(status) if status >= 500 => ErrorCategory::GongUnavailable,
+                Some(_) => ErrorCategory::Api,
+            },
+        }
+    }
+
+    /// Process exit code for this error
+    pub fn exit_code(&self) -> i32 {
+        self.category().exit_code()
+    }
+
+    /// Short suggestion shown under the error message
+    pub fn hint(&self) -> Option<&'static str> {
+        match self.category() {
+            ErrorCategory::Authentication => {
+                Some("Log into Gong in your browser, then run again (cs-cli doctor can help)")
+            }
+            ErrorCategory::GongUnavailable => Some("Gong is having problems; try again in a few minutes"),
+            ErrorCategory::Network => Some("Check your internet connection, VPN or proxy"),
+            ErrorCategory::RateLimited => Some("Gong is rate limiting requests; wait a minute and try again"),
+            ErrorCategory::NoData => Some("Try a longer time period or check the customer name"),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for CsCliError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -27,11 +207,56 @@ impl fmt::Display for CsCliError {
             CsCliError::CookieExtraction(msg) => write!(f, "Cookie extraction error: {msg}"),
             CsCliError::InvalidArguments { message } => write!(f, "Invalid arguments: {message}"),
             CsCliError::UpdateError(msg) => write!(f, "Update error: {msg}"),
+            CsCliError::NoData(msg) => write!(f, "No data: {msg}"),
+            CsCliError::Http(failure) => write!(f, "HTTP error: {failure}"),
             CsCliError::Generic(msg) => write!(f, "Error: {msg}"),
         }
     }
 }
 
-impl std::error::Error for CsCliError {}
+impl std::error::Error for CsCliError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsCliError::Http(failure) => failure
+                .source
+                .as_ref()
+                .map(|s| s.as_ref() as &(dyn std::error::Error + 'static)),
+            _ => None,
+        }
+    }
+}
 
 pub type Result<T> = std::result::Result<T, CsCliError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_error_categories_and_exit_codes() {
+        let expired = CsCliError::http_status("GET", "https://x.app.gong.io/ajax?id=1", 401, "");
+        assert_eq!(expired.category(), ErrorCategory::Authentication);
+        assert_eq!(expired.exit_code(), 4);
+
+        let down = CsCliError::http_status("POST", "https://x.app.gong.io/ajax", 503, "").with_retries(2);
+        assert_eq!(down.exit_code(), 5);
+        assert_eq!(down.to_string(), "HTTP error: POST https://x.app.gong.io/ajax returned 503 after 2 retries");
+
+        let offline = CsCliError::http("GET", "https://x.app.gong.io/ajax", None, "connection refused");
+        assert_eq!(offline.category(), ErrorCategory::Network);
+
+        assert_eq!(CsCliError::NoData("nothing".to_string()).exit_code(), 3);
+    }
+
+    #[test]
+    fn test_http_error_keeps_source_chain() {
+        use std::error::Error;
+
+        let parse_error = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        let err = CsCliError::http("GET", "https://x.app.gong.io/ajax", Some(200), "invalid JSON")
+            .with_source(parse_error);
+
+        assert!(err.source().is_some());
+        assert!(!err.to_string().contains('?'));
+    }
+}
@@ -0,0 +1,10 @@
+//! Machine-readable progress events
+//!
+//! With `--events=json`, cs-cli writes one JSON object per line (NDJSON) to
+//! stdout for every step of an extraction, and all human-readable output moves
+//! to stderr. Dashboards and launcher wrappers can then follow a run without
+//! scraping terminal text.
+
+pub mod stream;
+
+pub use stream::*;
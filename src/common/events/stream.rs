@@ -0,0 +1,270 @@
+//! NDJSON event stream and human output routing
+
+use serde::Serialize;
+use std::fmt::Display;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::CsCliError;
+
+/// Output mode for progress information
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventFormat {
+    /// Human-readable progress on stdout (default)
+    #[default]
+    Human,
+    /// NDJSON events on stdout, human-readable progress on stderr
+    Json,
+}
+
+/// Extraction stage reported by `stage_started` / `stage_finished` events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    /// Authentication and client setup
+    Setup,
+    /// Resolving a customer name to Gong accounts
+    ResolveCustomer,
+    /// Listing calls (and emails) for the date range
+    ListCommunications,
+    /// Fetching call transcripts
+    FetchTranscripts,
+    /// Fetching email bodies
+    FetchEmailBodies,
+    /// Writing output files
+    WriteFiles,
+}
+
+/// A single progress event
+///
+/// Serialized with an `event` tag, e.g.
+/// `{"event":"file_written","ts":"...","path":"/Users/me/Desktop/ct_postman/SUMMARY.md"}`.
+/// Fields are only ever added, never renamed, so consumers can ignore unknown keys.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    StageStarted {
+        stage: Stage,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        customer: Option<String>,
+    },
+    StageFinished {
+        stage: Stage,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        customer: Option<String>,
+        /// Number of items the stage produced
+        #[serde(skip_serializing_if = "Option::is_none")]
+        count: Option<usize>,
+    },
+    AccountResolved {
+        /// Name as given on the command line
+        query: String,
+        /// Name as resolved by Gong
+        customer: String,
+        account_ids: Vec<String>,
+    },
+    CallFetched {
+        call_id: String,
+        title: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        customer: Option<String>,
+        has_transcript: bool,
+    },
+    FileWritten {
+        path: PathBuf,
+    },
+    Warning {
+        message: String,
+    },
+    Error {
+        message: String,
+        exit_code: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hint: Option<String>,
+    },
+    Summary {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        customer: Option<String>,
+        calls: usize,
+        emails: usize,
+        files: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output_dir: Option<PathBuf>,
+    },
+}
+
+impl Event {
+    /// Error event for a failed run
+    pub fn from_error(error: &CsCliError) -> Self {
+        Event::Error {
+            message: error.to_string(),
+            exit_code: error.exit_code(),
+            hint: error.hint().map(String::from),
+        }
+    }
+}
+
+/// Event wrapper adding the timestamp to every line
+#[derive(Serialize)]
+struct EventLine<'a> {
+    ts: String,
+    #[serde(flatten)]
+    event: &'a Event,
+}
+
+/// Process-wide output settings
+#[derive(Debug, Clone, Copy)]
+struct OutputSettings {
+    format: EventFormat,
+    /// `--quiet`: no human-readable progress at all
+    quiet: bool,
+    /// False when `NO_COLOR` is set: plain text, no spinners or bars
+    color: bool,
+}
+
+static OUTPUT: OnceLock<OutputSettings> = OnceLock::new();
+
+/// Select the output mode for this process (first call wins)
+pub fn init_events(format: EventFormat, quiet: bool) {
+    // https://no-color.org: any non-empty value disables color
+    let color = !matches!(std::env::var_os("NO_COLOR"), Some(value) if !value.is_empty());
+    let _ = OUTPUT.set(OutputSettings {
+        format,
+        quiet,
+        color,
+    });
+}
+
+/// Whether NDJSON events are written to stdout
+pub fn json_events() -> bool {
+    OUTPUT.get().is_some_and(|o| o.format == EventFormat::Json)
+}
+
+/// Whether human-readable progress is suppressed (`--quiet`)
+pub fn quiet() -> bool {
+    OUTPUT.get().is_some_and(|o| o.quiet)
+}
+
+/// Whether colors, spinners and progress bars may be drawn
+pub fn fancy_output() -> bool {
+    match OUTPUT.get() {
+        Some(output) => output.color && !output.quiet,
+        None => true,
+    }
+}
+
+/// Write an event to stdout when `--events=json` is active
+pub fn emit(event: Event) {
+    if !json_events() {
+        return;
+    }
+
+    let line = EventLine {
+        ts: jiff::Timestamp::now().to_string(),
+        event: &event,
+    };
+
+    if let Ok(json) = serde_json::to_string(&line) {
+        // Lock so concurrent batch customers never interleave lines;
+        // a closed pipe just means nobody is listening any more
+        let mut stdout = std::io::stdout().lock();
+        let _ = writeln!(stdout, "{json}");
+        let _ = stdout.flush();
+    }
+}
+
+/// Print human-readable progress
+///
+/// Goes to stdout normally and to stderr when stdout carries the event stream.
+/// Nothing is printed under `--quiet`; colors are stripped under `NO_COLOR`.
+pub fn status(message: impl Display) {
+    if quiet() {
+        return;
+    }
+
+    let message = message.to_string();
+    let message = if fancy_output() {
+        message
+    } else {
+        strip_ansi(&message)
+    };
+
+    if json_events() {
+        eprintln!("{message}");
+    } else {
+        println!("{message}");
+    }
+}
+
+/// Remove ANSI escape sequences (colors, bold) from text
+pub fn strip_ansi(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // CSI sequences end with a letter, e.g. ESC[38;2;255;142;100m
+            if chars.next() == Some('[') {
+                for end in chars.by_ref() {
+                    if end.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+        } else {
+            plain.push(c);
+        }
+    }
+
+    plain
+}
+
+/// Print a warning for humans and emit it as a `warning` event
+pub fn warning(message: impl Display, styled: impl Display) {
+    status(styled);
+    emit(Event::Warning {
+        message: message.to_string(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_serialization_is_tagged() {
+        let event = Event::StageFinished {
+            stage: Stage::FetchTranscripts,
+            customer: Some("Postman".to_string()),
+            count: Some(3),
+        };
+        let json = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(json["event"], "stage_finished");
+        assert_eq!(json["stage"], "fetch_transcripts");
+        assert_eq!(json["customer"], "Postman");
+        assert_eq!(json["count"], 3);
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\u{1b}[1m\u{1b}[38;2;255;108;55mDone!\u{1b}[39m\u{1b}[0m"), "Done!");
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+
+    #[test]
+    fn test_error_event_carries_exit_code() {
+        let error = CsCliError::NoData("nothing found".to_string());
+        let line = EventLine {
+            ts: "2026-01-01T00:00:00Z".to_string(),
+            event: &Event::from_error(&error),
+        };
+        let json = serde_json::to_value(&line).unwrap();
+
+        assert_eq!(json["event"], "error");
+        assert_eq!(json["exit_code"], 3);
+        assert_eq!(json["ts"], "2026-01-01T00:00:00Z");
+        assert!(json.get("hint").is_some());
+    }
+}
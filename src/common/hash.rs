@@ -0,0 +1,11 @@
+//! Content hashing shared by release checks and output manifests
+
+use sha2::{Digest, Sha256};
+
+/// Lowercase hex SHA-256 of `data`
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
@@ -1,6 +1,8 @@
 pub mod auth;
 pub mod config;
 pub mod error;
+pub mod events;
+pub mod hash;
 pub mod http;
 pub mod models;
 
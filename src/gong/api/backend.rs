@@ -0,0 +1,184 @@
+//! Backend abstraction for customer extraction
+//!
+//! Customer extraction can run against two backends:
+//! - [`WebSessionBackend`]: internal `/ajax/...` endpoints using the browser session
+//! - [`PublicApiBackend`](super::public_api::PublicApiBackend): Gong's documented
+//!   REST API using an access key and secret, for automated jobs without a browser
+
+use async_trait::async_trait;
+use jiff::Zoned;
+use std::sync::Arc;
+use tracing::warn;
+
+use super::client::HttpClientPool;
+use super::customer::GongCustomerSearchClient;
+use super::email::EmailEnhancer;
+use super::library::CallDetailsFetcher;
+use super::timeline::TimelineExtractor;
+use crate::gong::auth::GongAuthenticator;
+use crate::gong::config::AppConfig;
+use crate::gong::models::{Call, Email, EmailFilterDecision};
+use crate::Result;
+
+/// Customer as resolved by the backend
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedCustomer {
+    /// Canonical customer name
+    pub name: String,
+    /// Gong (or CRM) account IDs belonging to the customer
+    pub account_ids: Vec<String>,
+}
+
+/// Calls and emails for one account
+#[derive(Debug, Clone, Default)]
+pub struct AccountCommunications {
+    pub calls: Vec<Call>,
+    pub emails: Vec<Email>,
+    /// Why each email was kept or dropped by the email filters
+    pub filter_decisions: Vec<EmailFilterDecision>,
+}
+
+/// Transcript and title details for a single call
+#[derive(Debug, Clone, Default)]
+pub struct CallTranscript {
+    /// Formatted transcript (`**Speaker:** text` paragraphs)
+    pub transcript: String,
+    /// Title generated by Gong, if any
+    pub generated_title: Option<String>,
+}
+
+/// Source of customer communications
+#[async_trait]
+pub trait GongBackend: Send + Sync {
+    /// Short backend name for logs and messages
+    fn name(&self) -> &'static str;
+
+    /// Whether the backend can return emails
+    fn supports_emails(&self) -> bool;
+
+    /// Resolve a (partial) customer name to its accounts
+    async fn resolve_customer(
+        &self,
+        name: &str,
+        start: &Zoned,
+        end: &Zoned,
+    ) -> Result<ResolvedCustomer>;
+
+    /// Calls and emails for one account within the date range
+    async fn account_communications(
+        &self,
+        account_id: &str,
+        start: &Zoned,
+        end: &Zoned,
+    ) -> Result<AccountCommunications>;
+
+    /// Transcript for a call, None if the call has none
+    async fn call_transcript(&self, call: &Call) -> Result<Option<CallTranscript>>;
+
+    /// Fill in email bodies
+    async fn enhance_emails(&self, emails: Vec<Email>) -> Result<Vec<Email>>;
+}
+
+/// Backend using Gong's internal web endpoints with the browser session
+pub struct WebSessionBackend {
+    customer_search_client: GongCustomerSearchClient,
+    timeline_extractor: TimelineExtractor,
+    details_fetcher: CallDetailsFetcher,
+    email_enhancer: EmailEnhancer,
+}
+
+impl WebSessionBackend {
+    /// Create the backend from an authenticated session
+    pub fn new(
+        http: Arc<HttpClientPool>,
+        auth: Arc<GongAuthenticator>,
+        config: &AppConfig,
+    ) -> Result<Self> {
+        Ok(Self {
+            customer_search_client: GongCustomerSearchClient::new(
+                http.clone(),
+                auth.clone(),
+                Some(config.clone()),
+            )?,
+            timeline_extractor: TimelineExtractor::new(
+                http.clone(),
+                auth.clone(),
+                Some(config.clone()),
+                None,
+            )?,
+            details_fetcher: CallDetailsFetcher::new(http.clone(), auth.clone(), Some(config.clone())),
+            email_enhancer: EmailEnhancer::new(http, auth, Some(config.clone()), None),
+        })
+    }
+}
+
+#[async_trait]
+impl GongBackend for WebSessionBackend {
+    fn name(&self) -> &'static str {
+        "web"
+    }
+
+    fn supports_emails(&self) -> bool {
+        true
+    }
+
+    async fn resolve_customer(
+        &self,
+        name: &str,
+        _start: &Zoned,
+        _end: &Zoned,
+    ) -> Result<ResolvedCustomer> {
+        let response = self
+            .customer_search_client
+            .get_customer_calls(name, 10, 0, false)
+            .await?;
+
+        Ok(ResolvedCustomer {
+            name: response
+                .companies
+                .first()
+                .cloned()
+                .unwrap_or_else(|| name.to_string()),
+            account_ids: response.account_ids,
+        })
+    }
+
+    async fn account_communications(
+        &self,
+        account_id: &str,
+        start: &Zoned,
+        end: &Zoned,
+    ) -> Result<AccountCommunications> {
+        let timeline = self
+            .timeline_extractor
+            .extract_account_timeline(account_id, start.clone(), Some(end.clone()))
+            .await?;
+
+        Ok(AccountCommunications {
+            calls: timeline.calls,
+            emails: timeline.emails,
+            filter_decisions: timeline.filter_decisions,
+        })
+    }
+
+    async fn call_transcript(&self, call: &Call) -> Result<Option<CallTranscript>> {
+        Ok(self
+            .details_fetcher
+            .get_call_details(&call.id)
+            .await?
+            .map(|details| CallTranscript {
+                transcript: details.transcript,
+                generated_title: Some(details.generated_title).filter(|t| !t.is_empty()),
+            }))
+    }
+
+    async fn enhance_emails(&self, emails: Vec<Email>) -> Result<Vec<Email>> {
+        self.email_enhancer
+            .enhance_emails_with_progress(emails, true)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Email body enhancement failed");
+                crate::CsCliError::Generic(format!("Failed to enhance emails: {e}"))
+            })
+    }
+}
@@ -63,6 +63,11 @@ impl GongHttpClient {
         Ok(())
     }
 
+    /// Remove all cookies (used when switching between candidate browser sessions)
+    pub async fn clear_cookies(&self) {
+        self.cookies.lock().await.clear();
+    }
+
     /// Update default headers
     pub async fn update_headers(&self, headers: HashMap<String, String>) -> Result<()> {
         let mut client_headers = self.headers.lock().await;
@@ -85,6 +90,17 @@ impl GongHttpClient {
         self.request_with_retry("GET", url, None).await
     }
 
+    /// Perform a single GET request with concurrency control, without retries
+    pub async fn get_once(&self, url: &str) -> Result<Response> {
+        let _permit = self
+            .semaphore
+            .acquire()
+            .await
+            .map_err(|e| CsCliError::ApiRequest(format!("Failed to acquire semaphore: {e}")))?;
+
+        self.make_request("GET", url, None).await
+    }
+
     /// Perform POST request with concurrency control and retry logic
     pub async fn post(&self, url: &str, body: Option<String>) -> Result<Response> {
         let _permit = self
@@ -113,6 +129,15 @@ impl GongHttpClient {
 
                     // Handle rate limiting (429)
                     if status == 429 {
+                        if attempt == MAX_RETRIES - 1 {
+                            return Err(CsCliError::http(
+                                method,
+                                url,
+                                Some(status),
+                                "rate limited by Gong",
+                            )
+                            .with_retries(attempt));
+                        }
                         let sleep_duration = self.calculate_retry_delay(&response, attempt).await;
                         warn!(
                             "Rate limited: url={}, attempt={}, retry_after={:.1}s",
@@ -139,6 +164,9 @@ impl GongHttpClient {
                                 "Server error - not retrying: url={}, status={}, attempt={}",
                                 url, status, attempt
                             );
+                            let body = response.text().await.unwrap_or_default();
+                            return Err(CsCliError::http_status(method, url, status, &body)
+                                .with_retries(attempt));
                         }
                     }
 
@@ -163,7 +191,8 @@ impl GongHttpClient {
         }
 
         Err(last_error
-            .unwrap_or_else(|| CsCliError::ApiRequest("Request failed after retries".to_string())))
+            .map(|e| e.with_retries(MAX_RETRIES - 1))
+            .unwrap_or_else(|| CsCliError::http(method, url, None, "request failed after retries")))
     }
 
     /// Make the actual HTTP request using impit API correctly
@@ -207,19 +236,20 @@ impl GongHttpClient {
 
         // Use impit's documented API: get(url, body, options) and post(url, body, options)
         match method.to_uppercase().as_str() {
-            "GET" => {
-                self.client
-                    .get(url.to_string(), None, request_options)
-                    .await
-                    .map_err(|e| CsCliError::ApiRequest(format!("GET request failed: {e}")))
-            }
+            "GET" => self
+                .client
+                .get(url.to_string(), None, request_options)
+                .await
+                .map_err(|e| CsCliError::http(method, url, None, "request failed").with_source(e)),
             "POST" => {
                 // Convert body to Vec<u8> for impit
                 let body_bytes = body.map(|b| b.as_bytes().to_vec());
                 self.client
                     .post(url.to_string(), body_bytes, request_options)
                     .await
-                    .map_err(|e| CsCliError::ApiRequest(format!("POST request failed: {e}")))
+                    .map_err(|e| {
+                        CsCliError::http(method, url, None, "request failed").with_source(e)
+                    })
             }
             _ => Err(CsCliError::ApiRequest(format!(
                 "Unsupported HTTP method: {method}"
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
+use crate::common::events::status;
 use crate::gong::api::client::HttpClientPool;
 use crate::gong::auth::GongAuthenticator;
 use crate::gong::config::AppConfig;
@@ -277,7 +278,7 @@ impl GongCustomerSearchClient {
                 response_text = %truncated_text,
                 "Customer search failed"
             );
-            Ok(Vec::new())
+            Err(CsCliError::http_status("GET", &url, status_code, &response_text))
         }
     }
 
@@ -372,37 +373,37 @@ impl GongCustomerSearchClient {
         if company_names.len() == 1 {
             // Only one match, use it automatically
             let selected = &company_names[0];
-            println!(
+            status(format!(
                 "\n{} {}",
                 style("Found customer:").green().bold(),
                 style(selected).white().bold()
-            );
+            ));
             return Ok(Some(selected.clone()));
         }
 
         // Multiple matches - show selection
-        println!(
+        status(format!(
             "\n{} {} {} {}",
             style("I found").yellow(),
             style(company_names.len()).yellow().bold(),
             style("companies matching").yellow(),
             style(format!("'{customer_name}'")).yellow().bold()
-        );
-        println!("{}", style("Which one are you looking for?").dim());
+        ));
+        status(style("Which one are you looking for?").dim());
 
         // Prepare options for selection
         let display_count = std::cmp::min(company_names.len(), 10);
         let mut options: Vec<String> = company_names[..display_count].to_vec();
 
         if company_names.len() > 10 {
-            println!(
+            status(format!(
                 "\n{}",
                 style(format!(
                     "Showing first 10 of {} matches",
                     company_names.len()
                 ))
                 .dim()
-            );
+            ));
         }
 
         // Add special options
@@ -419,10 +420,10 @@ impl GongCustomerSearchClient {
 
         if selection == options.len() - 1 {
             // User selected "Cancel and exit"
-            println!(
+            status(format!(
                 "\n{}",
                 style("Cancelled - no files will be extracted.").yellow()
-            );
+            ));
             Ok(None)
         } else if selection == options.len() - 2 {
             // User selected "None of these - search again"
@@ -430,11 +431,11 @@ impl GongCustomerSearchClient {
         } else if selection < display_count {
             // User selected a company
             let selected = &company_names[selection];
-            println!(
+            status(format!(
                 "\n{} {}\n",
                 style(" Selected:").green().bold(),
                 style(selected).white().bold()
-            );
+            ));
             Ok(Some(selected.clone()))
         } else {
             Err(CsCliError::Generic("Invalid selection".to_string()))
@@ -464,11 +465,11 @@ impl GongCustomerSearchClient {
 
         if company_names.is_empty() {
             warn!(customer_name = %customer_name, "Could not resolve customer name");
-            println!(
+            status(format!(
                 "{} {}",
                 style("No customers found matching").red().bold(),
                 style(format!("'{customer_name}'")).red().bold()
-            );
+            ));
             return Ok(CustomerCallsResult {
                 calls: Vec::new(),
                 has_more: false,
@@ -497,7 +498,7 @@ impl GongCustomerSearchClient {
                 }
                 Some(selected) if selected == "SEARCH_AGAIN" => {
                     // User wants to search for a different customer
-                    println!("\n{}", style("Let's try a different search.").cyan());
+                    status(format!("\n{}", style("Let's try a different search.").cyan()));
                     let new_customer: String = Input::with_theme(&ColorfulTheme::default())
                         .with_prompt("Enter the customer name")
                         .interact_text()
@@ -549,7 +550,7 @@ impl GongCustomerSearchClient {
 
         // Show progress to user
         if calls_offset == 0 {
-            println!("{}", style("Downloading calls and emails...").dim());
+            status(style("Downloading calls and emails...").dim());
         }
 
         info!(
@@ -651,13 +652,7 @@ impl GongCustomerSearchClient {
                 "Customer calls API request failed"
             );
 
-            Ok(CustomerCallsResult {
-                calls: Vec::new(),
-                has_more: false,
-                total_count: 0,
-                companies: Vec::new(),
-                account_ids: Vec::new(),
-            })
+            Err(CsCliError::http_status("POST", &url, status_code, &response_text))
         }
     }
 
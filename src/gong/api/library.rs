@@ -212,7 +212,7 @@ impl GongLibraryClient {
                 "Call stream API request failed"
             );
 
-            Ok(LibraryCallsResult { calls: Vec::new() })
+            Err(CsCliError::http_status("GET", &url, status_code, &response_text))
         }
     }
 
@@ -495,6 +495,10 @@ impl CallDetailsFetcher {
 
             let call_details = self.extract_call_details(&data, call_id)?;
             Ok(Some(call_details))
+        } else if response.status().as_u16() == 404 {
+            // Call no longer exists or has no transcript
+            warn!(call_id = %call_id, "Call details not found");
+            Ok(None)
         } else {
             let status_code = response.status().as_u16();
             warn!(
@@ -502,7 +506,8 @@ impl CallDetailsFetcher {
                 status_code = status_code,
                 "Failed to fetch call details"
             );
-            Ok(None)
+            let response_text = response.text().await.unwrap_or_default();
+            Err(CsCliError::http_status("GET", &url, status_code, &response_text))
         }
     }
 
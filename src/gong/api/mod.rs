@@ -1,11 +1,15 @@
+pub mod backend;
 pub mod client;
 pub mod customer;
 pub mod email;
 pub mod library;
+pub mod public_api;
 pub mod timeline;
 
+pub use backend::*;
 pub use client::*;
 pub use customer::*;
 pub use email::*;
 pub use library::*;
+pub use public_api::*;
 pub use timeline::*;
@@ -0,0 +1,657 @@
+//! Gong public REST API backend
+//!
+//! Uses the documented v2 API (`/v2/calls/extensive`, `/v2/calls/transcript`,
+//! `/v2/users`) with an access key and secret instead of a browser session, so
+//! scheduled jobs can extract calls without a logged-in browser. Customers are
+//! resolved through the CRM account objects Gong attaches to each call. The
+//! public API does not expose email content, so this backend returns calls only.
+
+use async_trait::async_trait;
+use jiff::Zoned;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OnceCell};
+use tracing::{debug, info, warn};
+
+use super::backend::{AccountCommunications, CallTranscript, GongBackend, ResolvedCustomer};
+use crate::gong::config::{BackendSettings, HttpSettings};
+use crate::gong::models::{Call, CallDirection, CallParticipant, Email};
+use crate::{CsCliError, Result};
+
+/// Maximum attempts when the API answers 429 Too Many Requests
+const MAX_RATE_LIMIT_ATTEMPTS: u32 = 3;
+
+/// Backend using Gong's public REST API
+pub struct PublicApiBackend {
+    client: reqwest::Client,
+    base_url: String,
+    access_key: String,
+    access_key_secret: String,
+    /// Calls listed per date range (Unix seconds), shared across customers
+    calls_cache: Mutex<HashMap<(i64, i64), Arc<Vec<Value>>>>,
+    /// Speaker ID to name for each listed call
+    speakers: Mutex<HashMap<String, HashMap<String, String>>>,
+    /// Gong user ID to full name
+    users: OnceCell<HashMap<String, String>>,
+}
+
+impl PublicApiBackend {
+    /// Create the backend from configured credentials
+    pub fn new(settings: &BackendSettings, http: &HttpSettings) -> Result<Self> {
+        let (access_key, access_key_secret) =
+            match (&settings.access_key, &settings.access_key_secret) {
+                (Some(key), Some(secret)) => (key.clone(), secret.clone()),
+                _ => {
+                    return Err(CsCliError::Configuration(
+                        "The api backend needs GONG_ACCESS_KEY and GONG_ACCESS_KEY_SECRET"
+                            .to_string(),
+                    ))
+                }
+            };
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs_f64(http.timeout_seconds))
+            .user_agent(concat!("cs-cli/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| CsCliError::ApiRequest(format!("Failed to create API client: {e}")))?;
+
+        Ok(Self {
+            client,
+            base_url: settings.api_base_url.trim_end_matches('/').to_string(),
+            access_key,
+            access_key_secret,
+            calls_cache: Mutex::new(HashMap::new()),
+            speakers: Mutex::new(HashMap::new()),
+            users: OnceCell::new(),
+        })
+    }
+
+    /// Send a request, retrying on rate limits, and parse the JSON body
+    async fn send(&self, path: &str, body: Option<&Value>, query: &[(&str, String)]) -> Result<Value> {
+        let url = format!("{}{path}", self.base_url);
+
+        for attempt in 1..=MAX_RATE_LIMIT_ATTEMPTS {
+            let request = match body {
+                Some(body) => self.client.post(&url).json(body),
+                None => self.client.get(&url),
+            };
+
+            let method = if body.is_some() { "POST" } else { "GET" };
+            let response = request
+                .query(query)
+                .basic_auth(&self.access_key, Some(&self.access_key_secret))
+                .send()
+                .await
+                .map_err(|e| {
+                    CsCliError::http(method, &url, None, "request failed")
+                        .with_retries(attempt - 1)
+                        .with_source(e)
+                })?;
+
+            let status = response.status();
+
+            if status.as_u16() == 429 && attempt < MAX_RATE_LIMIT_ATTEMPTS {
+                let wait = response
+                    .headers()
+                    .get("retry-after")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(attempt as u64 * 2);
+                warn!(path = %path, wait_seconds = wait, "Gong API rate limit hit, retrying");
+                tokio::time::sleep(Duration::from_secs(wait)).await;
+                continue;
+            }
+
+            // Gong answers 404 when a filter matches no records
+            if status.as_u16() == 404 {
+                return Ok(json!({}));
+            }
+
+            let text = response.text().await.map_err(|e| {
+                CsCliError::http(method, &url, Some(status.as_u16()), "failed to read response")
+                    .with_source(e)
+            })?;
+
+            if status.as_u16() == 401 || status.as_u16() == 403 {
+                return Err(CsCliError::http(
+                    method,
+                    &url,
+                    Some(status.as_u16()),
+                    "Gong API rejected the access key",
+                ));
+            }
+
+            if !status.is_success() {
+                return Err(CsCliError::http_status(method, &url, status.as_u16(), &text)
+                    .with_retries(attempt - 1));
+            }
+
+            return serde_json::from_str(&text).map_err(|e| {
+                CsCliError::http(method, &url, Some(status.as_u16()), "invalid JSON").with_source(e)
+            });
+        }
+
+        Err(CsCliError::http(
+            if body.is_some() { "POST" } else { "GET" },
+            &url,
+            Some(429),
+            "kept returning 429 Too Many Requests",
+        )
+        .with_retries(MAX_RATE_LIMIT_ATTEMPTS - 1))
+    }
+
+    /// Cursor of the next page, if any
+    fn next_cursor(page: &Value) -> Option<String> {
+        page.pointer("/records/cursor")
+            .and_then(|c| c.as_str())
+            .filter(|c| !c.is_empty())
+            .map(String::from)
+    }
+
+    /// List all calls in the date range with parties, brief and CRM context
+    async fn list_calls(&self, start: &Zoned, end: &Zoned) -> Result<Arc<Vec<Value>>> {
+        let key = (start.timestamp().as_second(), end.timestamp().as_second());
+
+        // Hold the lock while fetching so concurrent customers share one listing
+        let mut cache = self.calls_cache.lock().await;
+        if let Some(calls) = cache.get(&key) {
+            return Ok(calls.clone());
+        }
+
+        let mut calls = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut body = json!({
+                "filter": {
+                    "fromDateTime": start.timestamp().to_string(),
+                    "toDateTime": end.timestamp().to_string(),
+                },
+                "contentSelector": {
+                    "context": "Extended",
+                    "exposedFields": {
+                        "parties": true,
+                        "content": { "brief": true },
+                    },
+                },
+            });
+            if let Some(cursor) = &cursor {
+                body["cursor"] = json!(cursor);
+            }
+
+            let page = self.send("/v2/calls/extensive", Some(&body), &[]).await?;
+            if let Some(page_calls) = page.get("calls").and_then(|c| c.as_array()) {
+                calls.extend(page_calls.iter().cloned());
+            }
+
+            cursor = Self::next_cursor(&page);
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        info!(calls = calls.len(), "Listed calls from Gong public API");
+
+        let calls = Arc::new(calls);
+        cache.insert(key, calls.clone());
+        Ok(calls)
+    }
+
+    /// Gong user ID to full name, fetched once
+    async fn users(&self) -> Result<&HashMap<String, String>> {
+        self.users
+            .get_or_try_init(|| async {
+                let mut users = HashMap::new();
+                let mut cursor: Option<String> = None;
+
+                loop {
+                    let query: Vec<(&str, String)> =
+                        cursor.iter().map(|c| ("cursor", c.clone())).collect();
+                    let page = self.send("/v2/users", None, &query).await?;
+
+                    for user in page.get("users").and_then(|u| u.as_array()).into_iter().flatten() {
+                        if let Some(id) = user.get("id").and_then(value_as_string) {
+                            users.insert(id, full_name(user));
+                        }
+                    }
+
+                    cursor = Self::next_cursor(&page);
+                    if cursor.is_none() {
+                        break;
+                    }
+                }
+
+                debug!(users = users.len(), "Loaded Gong users");
+                Ok(users)
+            })
+            .await
+    }
+
+    /// Convert a public API call record into a Call for the given account
+    fn parse_call(
+        &self,
+        record: &Value,
+        account_id: &str,
+        users: &HashMap<String, String>,
+    ) -> Option<Call> {
+        let meta = record.get("metaData")?;
+        let id = meta.get("id").and_then(value_as_string)?;
+
+        let scheduled_start = ["started", "scheduled"]
+            .iter()
+            .filter_map(|field| meta.get(*field).and_then(|v| v.as_str()))
+            .find_map(|s| s.parse::<jiff::Timestamp>().ok())
+            .map(|ts| ts.to_zoned(jiff::tz::TimeZone::system()))?;
+
+        let direction = match meta.get("scope").and_then(|v| v.as_str()) {
+            Some("Internal") => CallDirection::Internal,
+            _ => match meta.get("direction").and_then(|v| v.as_str()) {
+                Some("Inbound") => CallDirection::Inbound,
+                Some("Outbound") | Some("Conference") => CallDirection::Outbound,
+                _ => CallDirection::Unknown,
+            },
+        };
+
+        let mut call = Call::new(
+            id,
+            account_id.to_string(),
+            meta.get("title")
+                .and_then(|v| v.as_str())
+                .filter(|t| !t.is_empty())
+                .unwrap_or("Call")
+                .to_string(),
+            direction,
+            meta.get("duration").and_then(|v| v.as_f64()).unwrap_or(0.0) as i32,
+            scheduled_start,
+        );
+
+        call.recording_url = meta.get("url").and_then(|v| v.as_str()).map(String::from);
+        call.call_brief = record
+            .pointer("/content/brief")
+            .and_then(|v| v.as_str())
+            .map(String::from);
+        call.host_id = meta.get("primaryUserId").and_then(value_as_string);
+        call.host_name = call.host_id.as_ref().and_then(|id| users.get(id)).cloned();
+        call.customer_name = account_objects(record)
+            .into_iter()
+            .find(|(object_id, _)| object_id == account_id)
+            .map(|(_, name)| name);
+
+        call.participants = record
+            .get("parties")
+            .and_then(|p| p.as_array())
+            .into_iter()
+            .flatten()
+            .map(|party| {
+                let user_name = party
+                    .get("userId")
+                    .and_then(value_as_string)
+                    .and_then(|id| users.get(&id).cloned());
+
+                CallParticipant {
+                    id: party.get("id").and_then(value_as_string),
+                    name: party
+                        .get("name")
+                        .and_then(|v| v.as_str())
+                        .filter(|n| !n.is_empty())
+                        .map(String::from)
+                        .or(user_name)
+                        .unwrap_or_else(|| "Unknown".to_string()),
+                    email: party.get("emailAddress").and_then(|v| v.as_str()).map(String::from),
+                    phone: None,
+                    title: party.get("title").and_then(|v| v.as_str()).map(String::from),
+                    company: None,
+                    is_internal: party.get("affiliation").and_then(|v| v.as_str()) == Some("Internal"),
+                    speaking_time: None,
+                    talk_ratio: None,
+                }
+            })
+            .collect();
+
+        Some(call)
+    }
+
+    /// Remember speaker names so transcripts can be attributed
+    async fn record_speakers(&self, record: &Value, call_id: &str, users: &HashMap<String, String>) {
+        let speakers: HashMap<String, String> = record
+            .get("parties")
+            .and_then(|p| p.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|party| {
+                let speaker_id = party.get("speakerId").and_then(value_as_string)?;
+                let name = party
+                    .get("name")
+                    .and_then(|v| v.as_str())
+                    .filter(|n| !n.is_empty())
+                    .map(String::from)
+                    .or_else(|| {
+                        party
+                            .get("userId")
+                            .and_then(value_as_string)
+                            .and_then(|id| users.get(&id).cloned())
+                    })?;
+                Some((speaker_id, name))
+            })
+            .collect();
+
+        self.speakers.lock().await.insert(call_id.to_string(), speakers);
+    }
+}
+
+#[async_trait]
+impl GongBackend for PublicApiBackend {
+    fn name(&self) -> &'static str {
+        "api"
+    }
+
+    fn supports_emails(&self) -> bool {
+        false
+    }
+
+    async fn resolve_customer(
+        &self,
+        name: &str,
+        start: &Zoned,
+        end: &Zoned,
+    ) -> Result<ResolvedCustomer> {
+        let calls = self.list_calls(start, end).await?;
+        let query = name.trim().to_lowercase();
+
+        // Count calls per matching account name to pick the best match; sorted
+        // so ties resolve the same way on every run
+        let mut matches: BTreeMap<String, (usize, Vec<String>)> = BTreeMap::new();
+        for record in calls.iter() {
+            for (object_id, account_name) in account_objects(record) {
+                if !account_name.to_lowercase().contains(&query) {
+                    continue;
+                }
+                let entry = matches.entry(account_name).or_default();
+                entry.0 += 1;
+                if !entry.1.contains(&object_id) {
+                    entry.1.push(object_id);
+                }
+            }
+        }
+
+        let best = matches
+            .iter()
+            .find(|(account_name, _)| account_name.to_lowercase() == query)
+            .or_else(|| {
+                matches
+                    .iter()
+                    .max_by(|a, b| a.1 .0.cmp(&b.1 .0).then_with(|| b.0.cmp(a.0)))
+            })
+            .map(|(account_name, (_, ids))| (account_name.clone(), ids.clone()));
+
+        Ok(match best {
+            Some((name, account_ids)) => ResolvedCustomer { name, account_ids },
+            None => ResolvedCustomer {
+                name: name.to_string(),
+                account_ids: Vec::new(),
+            },
+        })
+    }
+
+    async fn account_communications(
+        &self,
+        account_id: &str,
+        start: &Zoned,
+        end: &Zoned,
+    ) -> Result<AccountCommunications> {
+        let records = self.list_calls(start, end).await?;
+        let users = self.users().await?;
+
+        let mut calls = Vec::new();
+        for record in records.iter() {
+            if !account_objects(record).iter().any(|(id, _)| id == account_id) {
+                continue;
+            }
+            if let Some(call) = self.parse_call(record, account_id, users) {
+                self.record_speakers(record, &call.id, users).await;
+                calls.push(call);
+            }
+        }
+
+        Ok(AccountCommunications {
+            calls,
+            ..Default::default()
+        })
+    }
+
+    async fn call_transcript(&self, call: &Call) -> Result<Option<CallTranscript>> {
+        let body = json!({ "filter": { "callIds": [call.id] } });
+        let response = self.send("/v2/calls/transcript", Some(&body), &[]).await?;
+
+        let Some(monologues) = response
+            .get("callTranscripts")
+            .and_then(|t| t.as_array())
+            .into_iter()
+            .flatten()
+            .find(|t| t.get("callId").and_then(value_as_string).as_ref() == Some(&call.id))
+            .and_then(|t| t.get("transcript"))
+            .and_then(|t| t.as_array())
+        else {
+            return Ok(None);
+        };
+
+        let speakers = self.speakers.lock().await.get(&call.id).cloned().unwrap_or_default();
+        let transcript = format_transcript(monologues, &speakers);
+
+        Ok(Some(CallTranscript {
+            transcript,
+            generated_title: None,
+        }))
+    }
+
+    async fn enhance_emails(&self, emails: Vec<Email>) -> Result<Vec<Email>> {
+        // The public API has no email content endpoint
+        Ok(emails)
+    }
+}
+
+/// CRM account objects (ID, name) attached to a call
+fn account_objects(record: &Value) -> Vec<(String, String)> {
+    record
+        .get("context")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .flat_map(|system| system.get("objects").and_then(|o| o.as_array()).into_iter().flatten())
+        .filter(|object| object.get("objectType").and_then(|t| t.as_str()) == Some("Account"))
+        .filter_map(|object| {
+            let id = object.get("objectId").and_then(value_as_string)?;
+            let name = object
+                .get("fields")
+                .and_then(|f| f.as_array())
+                .into_iter()
+                .flatten()
+                .find(|field| field.get("name").and_then(|n| n.as_str()) == Some("Name"))
+                .and_then(|field| field.get("value"))
+                .and_then(|v| v.as_str())?;
+            Some((id, name.to_string()))
+        })
+        .collect()
+}
+
+/// Format transcript monologues as `**Speaker:** text` paragraphs
+fn format_transcript(monologues: &[Value], speakers: &HashMap<String, String>) -> String {
+    monologues
+        .iter()
+        .filter_map(|monologue| {
+            let text = monologue
+                .get("sentences")
+                .and_then(|s| s.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|sentence| sentence.get("text").and_then(|t| t.as_str()))
+                .collect::<Vec<_>>()
+                .join(" ");
+            if text.trim().is_empty() {
+                return None;
+            }
+
+            let speaker = monologue
+                .get("speakerId")
+                .and_then(value_as_string)
+                .and_then(|id| speakers.get(&id).cloned())
+                .unwrap_or_else(|| "Unknown Speaker".to_string());
+
+            Some(format!("**{speaker}:** {text}"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Full name of a Gong user record
+fn full_name(user: &Value) -> String {
+    let first = user.get("firstName").and_then(|v| v.as_str()).unwrap_or("");
+    let last = user.get("lastName").and_then(|v| v.as_str()).unwrap_or("");
+    format!("{first} {last}").trim().to_string()
+}
+
+/// IDs come back as strings or numbers depending on the endpoint
+fn value_as_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn call_record() -> Value {
+        json!({
+            "metaData": {
+                "id": "7782342274025937895",
+                "title": "Quarterly review",
+                "started": "2025-01-15T16:00:00Z",
+                "duration": 1800,
+                "direction": "Conference",
+                "scope": "External",
+                "url": "https://app.gong.io/call?id=7782342274025937895",
+                "primaryUserId": "234599484848423"
+            },
+            "parties": [
+                {"id": "1", "speakerId": "s1", "userId": "234599484848423", "affiliation": "Internal"},
+                {"id": "2", "speakerId": "s2", "name": "Dana Customer", "affiliation": "External"}
+            ],
+            "context": [{
+                "system": "Salesforce",
+                "objects": [{
+                    "objectType": "Account",
+                    "objectId": "0013600000ABC",
+                    "fields": [{"name": "Name", "value": "Acme Corp"}]
+                }]
+            }],
+            "content": {"brief": "Renewal discussion"}
+        })
+    }
+
+    async fn mock_server() -> MockServer {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v2/calls/extensive"))
+            .and(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "records": {"totalRecords": 1},
+                "calls": [call_record()]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/users"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "records": {},
+                "users": [{"id": "234599484848423", "firstName": "Sam", "lastName": "Rep"}]
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v2/calls/transcript"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "callTranscripts": [{
+                    "callId": "7782342274025937895",
+                    "transcript": [
+                        {"speakerId": "s1", "sentences": [{"text": "Thanks for joining."}]},
+                        {"speakerId": "s2", "sentences": [{"text": "Happy to."}, {"text": "Let's start."}]}
+                    ]
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    fn backend(base_url: &str) -> PublicApiBackend {
+        let settings = BackendSettings {
+            api_base_url: base_url.to_string(),
+            access_key: Some("key".to_string()),
+            access_key_secret: Some("secret".to_string()),
+            ..BackendSettings::default()
+        };
+        PublicApiBackend::new(&settings, &HttpSettings::default()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_public_api_customer_calls_and_transcript() {
+        let server = mock_server().await;
+        let backend = backend(&server.uri());
+
+        let end = Zoned::now();
+        let start = end.checked_sub(jiff::Span::new().days(90)).unwrap();
+
+        let customer = backend.resolve_customer("acme", &start, &end).await.unwrap();
+        assert_eq!(customer.name, "Acme Corp");
+        assert_eq!(customer.account_ids, vec!["0013600000ABC".to_string()]);
+
+        // Second listing for the same range is served from the cache (expect(1))
+        let comms = backend
+            .account_communications("0013600000ABC", &start, &end)
+            .await
+            .unwrap();
+        assert_eq!(comms.calls.len(), 1);
+
+        let call = &comms.calls[0];
+        assert_eq!(call.host_name.as_deref(), Some("Sam Rep"));
+        assert_eq!(call.customer_name.as_deref(), Some("Acme Corp"));
+        assert_eq!(call.participants[0].name, "Sam Rep");
+        assert!(call.participants[0].is_internal);
+
+        let transcript = backend.call_transcript(call).await.unwrap().unwrap();
+        assert_eq!(
+            transcript.transcript,
+            "**Sam Rep:** Thanks for joining.\n\n**Dana Customer:** Happy to. Let's start."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_public_api_rejected_credentials() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v2/calls/extensive"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let backend = backend(&server.uri());
+        let end = Zoned::now();
+        let start = end.checked_sub(jiff::Span::new().days(7)).unwrap();
+
+        let err = backend.resolve_customer("acme", &start, &end).await.unwrap_err();
+        assert_eq!(err.category(), crate::common::error::ErrorCategory::Authentication);
+        assert_eq!(err.status(), Some(401));
+    }
+}
@@ -9,12 +9,18 @@ use crate::gong::api::client::HttpClientPool;
 use crate::gong::auth::GongAuthenticator;
 use crate::gong::config::AppConfig;
 use crate::gong::models::{
-    Call, CallDirection, CallParticipant, Email, EmailDirection, EmailRecipient, ExtractionRange,
+    Call, CallDirection, CallParticipant, Email, EmailDirection, EmailFilterDecision,
+    EmailRecipient, ExtractionRange, AUTOMATED_EMAIL_FILTER, DUPLICATE_EMAIL_FILTER,
 };
 use crate::{CsCliError, Result};
 
-/// Type alias for complex email filtering result
-type EmailFilterResult = (Vec<HashMap<String, Value>>, HashMap<String, usize>);
+/// Type alias for complex email filtering result: kept emails, counts and
+/// the (email ID, reason) of each dropped email
+type EmailFilterResult = (
+    Vec<HashMap<String, Value>>,
+    HashMap<String, usize>,
+    Vec<(String, String)>,
+);
 
 /// Template markers for BDR/SPAM/Automation filtering
 ///
@@ -94,6 +100,8 @@ pub struct TimelineResult {
     pub calls: Vec<Call>,
     /// Extracted emails (after filtering)
     pub emails: Vec<Email>,
+    /// What the filters decided about every email, kept or dropped
+    pub filter_decisions: Vec<EmailFilterDecision>,
     /// Filtering statistics
     pub stats: FilteringStats,
 }
@@ -147,7 +155,7 @@ impl TimelineExtractor {
     /// # Returns
     /// Tuple of (calls, emails) with filtering applied
     pub async fn extract_account_timeline(
-        &mut self,
+        &self,
         account_id: &str,
         start_date: Zoned,
         end_date: Option<Zoned>,
@@ -178,18 +186,15 @@ impl TimelineExtractor {
         // Aggregate results
         let mut all_calls = Vec::new();
         let mut all_emails = Vec::new();
+        let mut filter_decisions = Vec::new();
 
         for result in chunk_results {
-            match result {
-                Ok((calls, emails)) => {
-                    all_calls.extend(calls);
-                    all_emails.extend(emails);
-                }
-                Err(e) => {
-                    error!(error = %e, "Chunk failed");
-                    continue;
-                }
-            }
+            // A failed chunk would silently drop part of the timeline, so fail the account
+            let (calls, emails, decisions) =
+                result.inspect_err(|e| error!(error = %e, "Chunk failed"))?;
+            all_calls.extend(calls);
+            all_emails.extend(emails);
+            filter_decisions.extend(decisions);
         }
 
         // Sort by date
@@ -206,6 +211,7 @@ impl TimelineExtractor {
         Ok(TimelineResult {
             calls: all_calls,
             emails: all_emails,
+            filter_decisions,
             stats: self.filtered_stats.clone(),
         })
     }
@@ -217,12 +223,12 @@ impl TimelineExtractor {
     /// * `chunk` - Date range chunk to fetch
     ///
     /// # Returns
-    /// Tuple of (calls, emails) for this chunk
+    /// Tuple of (calls, emails, email filter decisions) for this chunk
     pub async fn fetch_chunk(
         &self,
         account_id: &str,
         chunk: &ExtractionRange,
-    ) -> Result<(Vec<Call>, Vec<Email>)> {
+    ) -> Result<(Vec<Call>, Vec<Email>, Vec<EmailFilterDecision>)> {
         let base_url = self.auth.get_base_url()?;
 
         // Get API parameters from chunk
@@ -264,7 +270,8 @@ impl TimelineExtractor {
                 status = status_code,
                 "Chunk fetch failed"
             );
-            return Ok((Vec::new(), Vec::new()));
+            let response_text = response.text().await.unwrap_or_default();
+            return Err(CsCliError::http_status("GET", &endpoint, status_code, &response_text));
         }
 
         let response_text = response
@@ -334,15 +341,34 @@ impl TimelineExtractor {
             }
         }
 
-        // Process emails with advanced filtering
-        let emails = if !email_activities.is_empty() {
-            let raw_emails = self.process_emails_with_filtering(email_activities)?;
-            raw_emails.into_iter().filter(|e| !e.is_automated).collect()
-        } else {
-            Vec::new()
-        };
+        // Process emails with advanced filtering, recording why each was kept or dropped
+        let mut emails = Vec::new();
+        let mut decisions = Vec::new();
+        if !email_activities.is_empty() {
+            let (raw_emails, dropped) = self.process_emails_with_filtering(email_activities)?;
+            decisions.extend(dropped);
+            for email in raw_emails {
+                if email.is_automated {
+                    let reason = match email.similarity_score {
+                        Some(score) => format!(
+                            "{:.0}% similar to another email from the same sender",
+                            score * 100.0
+                        ),
+                        None => "automated, out-of-office or BDR template content".to_string(),
+                    };
+                    decisions.push(EmailFilterDecision::dropped(
+                        &email,
+                        AUTOMATED_EMAIL_FILTER,
+                        reason,
+                    ));
+                } else {
+                    decisions.push(EmailFilterDecision::kept(&email));
+                    emails.push(email);
+                }
+            }
+        }
 
-        Ok((calls, emails))
+        Ok((calls, emails, decisions))
     }
 
     /// Call filtering logic - match behavior of direct customer search
@@ -353,12 +379,15 @@ impl TimelineExtractor {
 
     /// Process emails with advanced BDR/SPAM/Automation filtering
     /// This is the CORE filtering logic that removes noise
+    ///
+    /// Returns the emails that survive duplicate and mass email filtering
+    /// (automated ones are only flagged) and a decision for each dropped one.
     pub fn process_emails_with_filtering(
         &self,
         email_activities: Vec<(Value, String)>,
-    ) -> Result<Vec<Email>> {
+    ) -> Result<(Vec<Email>, Vec<EmailFilterDecision>)> {
         if email_activities.is_empty() {
-            return Ok(Vec::new());
+            return Ok((Vec::new(), Vec::new()));
         }
 
         // Group activities by sender for context-aware processing
@@ -414,7 +443,8 @@ impl TimelineExtractor {
                 .collect();
 
             // Apply advanced synopsis filtering
-            let (filtered_dicts, _filter_stats) = self.filter_emails_by_synopsis(email_dicts)?;
+            let (filtered_dicts, _filter_stats, dropped) =
+                self.filter_emails_by_synopsis(email_dicts)?;
             let filtered_ids: HashSet<String> = filtered_dicts
                 .iter()
                 .filter_map(|dict| {
@@ -424,10 +454,20 @@ impl TimelineExtractor {
                 })
                 .collect();
 
-            let filtered_emails: Vec<Email> = all_emails
-                .into_iter()
-                .filter(|e| filtered_ids.contains(&e.id))
-                .collect();
+            let dropped: HashMap<String, String> = dropped.into_iter().collect();
+            let mut decisions = Vec::new();
+            let mut filtered_emails = Vec::new();
+            for email in all_emails {
+                if filtered_ids.contains(&email.id) {
+                    filtered_emails.push(email);
+                } else if let Some(reason) = dropped.get(&email.id) {
+                    decisions.push(EmailFilterDecision::dropped(
+                        &email,
+                        DUPLICATE_EMAIL_FILTER,
+                        reason.clone(),
+                    ));
+                }
+            }
 
             info!(
                 original = filtered_emails.len() + _filter_stats.get("total").unwrap_or(&0),
@@ -436,10 +476,10 @@ impl TimelineExtractor {
                 "Email filtering applied"
             );
 
-            return Ok(filtered_emails);
+            return Ok((filtered_emails, decisions));
         }
 
-        Ok(all_emails)
+        Ok((all_emails, Vec::new()))
     }
 
     /// Extract sender email from activity data
@@ -483,6 +523,7 @@ impl TimelineExtractor {
             // Check similarity against other emails from same sender
             let mut final_is_automated = is_automated;
             let mut _final_is_template = is_template;
+            let mut similarity = None;
 
             if !is_automated && sender_email_data.len() > 1 {
                 for (j, other_email_data) in sender_email_data.iter().enumerate() {
@@ -501,6 +542,7 @@ impl TimelineExtractor {
                             // High similarity threshold
                             final_is_automated = true;
                             _final_is_template = true;
+                            similarity = Some(max_similarity);
                             break;
                         }
                     }
@@ -511,6 +553,7 @@ impl TimelineExtractor {
             let mut email = email_data.clone();
             email.is_automated = final_is_automated;
             email.is_template = _final_is_template;
+            email.similarity_score = similarity.or(email.similarity_score);
 
             processed_emails.push(email);
         }
@@ -706,10 +749,15 @@ impl TimelineExtractor {
         emails: Vec<HashMap<String, Value>>,
     ) -> Result<EmailFilterResult> {
         if emails.is_empty() {
-            return Ok((Vec::new(), HashMap::from([("total".to_string(), 0)])));
+            return Ok((
+                Vec::new(),
+                HashMap::from([("total".to_string(), 0)]),
+                Vec::new(),
+            ));
         }
 
         let mut filtered_emails = Vec::new();
+        let mut dropped = Vec::new();
         let mut similarity_filtered = 0;
         let mut template_filtered = 0;
 
@@ -754,6 +802,11 @@ impl TimelineExtractor {
             if is_high_template_sender {
                 // Keep only the best representative for template senders
                 let representative = self.select_blast_representative(&sender_emails);
+                let reason = format!(
+                    "mass email: {template_count} of {} emails from this sender are templates",
+                    sender_emails.len()
+                );
+                dropped.extend(dropped_ids(&sender_emails, &representative, &reason));
                 filtered_emails.push(representative);
                 template_filtered += sender_emails.len() - 1;
                 continue;
@@ -766,6 +819,11 @@ impl TimelineExtractor {
                 if group.len() > 1 && self.is_blast_pattern(&group)? {
                     // Keep one representative for similar content blasts
                     let representative = self.select_blast_representative(&group);
+                    let reason = format!(
+                        "duplicate: one of {} near-identical emails from this sender",
+                        group.len()
+                    );
+                    dropped.extend(dropped_ids(&group, &representative, &reason));
                     filtered_emails.push(representative);
                     similarity_filtered += group.len() - 1;
                 } else {
@@ -782,7 +840,7 @@ impl TimelineExtractor {
         filter_stats.insert("template_mass".to_string(), template_filtered);
         filter_stats.insert("total".to_string(), total_filtered);
 
-        Ok((filtered_emails, filter_stats))
+        Ok((filtered_emails, filter_stats, dropped))
     }
 
     /// Group emails by content similarity to detect blast patterns
@@ -1135,36 +1193,7 @@ impl TimelineExtractor {
         // Parse recipients
         let mut recipients = Vec::new();
         if let Some(to_list) = extended_data.get("to").and_then(|t| t.as_array()) {
-            for recipient_data in to_list {
-                if let Some(recipient_obj) = recipient_data.as_object() {
-                    let recipient = EmailRecipient {
-                        email: recipient_obj
-                            .get("email")
-                            .and_then(|e| e.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        name: recipient_obj
-                            .get("name")
-                            .and_then(|n| n.as_str())
-                            .map(|s| s.to_string()),
-                        recipient_type: "to".to_string(),
-                        is_internal: recipient_obj
-                            .get("email")
-                            .and_then(|e| e.as_str())
-                            .map(|e| e.to_lowercase().contains("postman.com"))
-                            .unwrap_or(false),
-                        title: recipient_obj
-                            .get("title")
-                            .and_then(|t| t.as_str())
-                            .map(|s| s.to_string()),
-                        company: recipient_obj
-                            .get("company")
-                            .and_then(|c| c.as_str())
-                            .map(|s| s.to_string()),
-                    };
-                    recipients.push(recipient);
-                }
-            }
+            recipients.extend(to_list.iter().filter_map(|r| parse_recipient(r, "to")));
         } else {
             // Fallback to participantsEmailList
             if let Some(participants_emails) = activity_obj
@@ -1194,6 +1223,11 @@ impl TimelineExtractor {
                 }
             }
         }
+        for kind in ["cc", "bcc"] {
+            if let Some(list) = extended_data.get(kind).and_then(|l| l.as_array()) {
+                recipients.extend(list.iter().filter_map(|r| parse_recipient(r, kind)));
+            }
+        }
 
         // Determine direction
         let direction = if sender_is_internal {
@@ -1248,8 +1282,55 @@ impl TimelineExtractor {
         email.sender.is_internal = sender_is_internal;
         email.snippet = snippet;
         email.recipients = recipients;
+        email.thread_id = extended_data
+            .get("threadId")
+            .or_else(|| activity_obj.get("threadId"))
+            .and_then(|id| id.as_str())
+            .map(|id| id.to_string());
+        email.in_thread = email.thread_id.is_some();
 
         Ok(Some(email))
     }
 }
 
+/// Parse a `to`/`cc`/`bcc` entry from an email activity
+fn parse_recipient(value: &Value, recipient_type: &str) -> Option<EmailRecipient> {
+    let obj = value.as_object()?;
+    let email = obj
+        .get("email")
+        .and_then(|e| e.as_str())
+        .unwrap_or("")
+        .to_string();
+    Some(EmailRecipient {
+        is_internal: email.to_lowercase().contains("postman.com"),
+        email,
+        name: obj
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map(|s| s.to_string()),
+        recipient_type: recipient_type.to_string(),
+        title: obj
+            .get("title")
+            .and_then(|t| t.as_str())
+            .map(|s| s.to_string()),
+        company: obj
+            .get("company")
+            .and_then(|c| c.as_str())
+            .map(|s| s.to_string()),
+    })
+}
+
+/// IDs of the emails in a group other than its representative, with `reason`
+fn dropped_ids(
+    group: &[HashMap<String, Value>],
+    representative: &HashMap<String, Value>,
+    reason: &str,
+) -> Vec<(String, String)> {
+    let kept = representative.get("id").and_then(|id| id.as_str());
+    group
+        .iter()
+        .filter_map(|email| email.get("id").and_then(|id| id.as_str()))
+        .filter(|id| Some(*id) != kept)
+        .map(|id| (id.to_string(), reason.to_string()))
+        .collect()
+}
@@ -1,16 +1,19 @@
-use super::CSRFManager;
-use crate::common::auth::{Cookie, CookieExtractor};
+use super::{CSRFManager, PersistedSession, SessionStore};
+use crate::common::auth::{
+    BrowserSelection, Cookie, CookieCandidate, CookieExtractor, CookieImportSource, CookieImporter,
+};
 use crate::gong::api::client::GongHttpClient;
 use crate::common::config::{AuthSettings, HttpSettings};
 use crate::{CsCliError, Result};
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{debug, error, info, warn};
 
 /// Gong authentication cookies with metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GongCookies {
     /// Gong cell identifier (e.g., "us-14496")
     pub cell: String,
@@ -25,6 +28,11 @@ pub struct GongCookies {
     pub browser: String,
 }
 
+/// Cookie domains read for Gong: the exact domain and all subdomains (*.gong.io)
+pub fn gong_cookie_domains() -> Vec<String> {
+    vec!["gong.io".to_string(), ".gong.io".to_string()]
+}
+
 /// Main authentication manager for Gong API with multi-browser support
 ///
 /// Orchestrates the complete authentication flow:
@@ -43,6 +51,9 @@ pub struct GongAuthenticator {
     /// Multi-browser cookie extractor using rookie
     cookie_extractor: CookieExtractor,
 
+    /// Parser for cookies.txt, HAR and raw header imports
+    cookie_importer: CookieImporter,
+
     /// CSRF token manager with async safety
     csrf_manager: CSRFManager,
 
@@ -64,10 +75,8 @@ impl GongAuthenticator {
         let http_client = Arc::new(GongHttpClient::new(http_config).await?);
 
         // Set up cookie extractor for Gong domains (*.gong.io)
-        let domains = vec![
-            "gong.io".to_string(),  // Exact domain
-            ".gong.io".to_string(), // All subdomains (*.gong.io)
-        ];
+        let domains = gong_cookie_domains();
+        let cookie_importer = CookieImporter::new(domains.clone());
         let cookie_extractor = CookieExtractor::new(domains);
         let csrf_manager = CSRFManager::new(config.clone(), http_client.clone());
 
@@ -75,6 +84,7 @@ impl GongAuthenticator {
             http_client,
             config,
             cookie_extractor,
+            cookie_importer,
             csrf_manager,
             gong_cookies: None,
             base_url: None,
@@ -96,64 +106,80 @@ impl GongAuthenticator {
     pub async fn authenticate(&mut self) -> Result<bool> {
         info!("Starting Gong authentication with multi-browser support");
 
-        // Extract cookies from available browsers (rookie handles fallback)
-        let (cookies, browser_source) = self.cookie_extractor.extract_gong_cookies_with_source()?;
+        let selection = self.browser_selection()?;
 
-        if cookies.is_empty() {
-            error!("No Gong cookies found in any supported browser");
-            info!("Make sure you're logged into Gong in any supported browser: Firefox, Chrome, Edge, Arc, Brave, Chromium, LibreWolf, Opera, Opera GX, Vivaldi, Zen, Safari, or Cachy");
-            return Ok(false);
+        // Collect sessions from every matching browser/profile, freshest first
+        let candidates = self.cookie_extractor.collect_candidates(&selection)?;
+
+        info!(
+            candidates = candidates.len(),
+            sources = %candidates.iter().map(|c| c.source.as_str()).collect::<Vec<_>>().join(", "),
+            auto = selection.is_auto(),
+            "Found browser sessions with Gong cookies"
+        );
+
+        // Use the first candidate Gong actually accepts
+        let mut chosen = None;
+        for candidate in candidates {
+            match self.try_cookie_candidate(&candidate).await {
+                Ok(true) => {
+                    chosen = Some(candidate);
+                    break;
+                }
+                Ok(false) => {
+                    info!(source = %candidate.source, "Gong rejected browser session, trying next");
+                }
+                Err(e) => {
+                    warn!(source = %candidate.source, error = %e, "Could not use browser session");
+                }
+            }
         }
 
+        let Some(candidate) = chosen else {
+            self.gong_cookies = None;
+            self.base_url = None;
+            error!("No browser session was accepted by Gong");
+            info!("Make sure you're logged into Gong in any supported browser: Firefox, Chrome, Edge, Arc, Brave, Chromium, LibreWolf, Opera, Opera GX, Vivaldi, Zen, Safari, or Cachy");
+            return Ok(false);
+        };
 
         info!(
-            cookies_found = cookies.len(),
-            browser_used = %browser_source,
-            browsers_supported = "Firefox, Chrome, Edge, Arc, Brave, Chromium, LibreWolf, Opera, Opera GX, Vivaldi, Zen, Safari, Cachy",
-            "Successfully extracted browser cookies"
+            cookies_found = candidate.cookies.len(),
+            browser_used = %candidate.source,
+            latest_expiry = ?candidate.latest_expiry,
+            "Selected browser session"
         );
 
-        // Find cell identifier from cookies
-        let cell = self.extract_cell_from_cookies(&cookies)?;
-
-        // Build session cookies map
-        let mut session_cookies = HashMap::new();
-        for cookie in &cookies {
-            session_cookies.insert(cookie.name.clone(), cookie.value.clone());
-        }
+        self.complete_authentication(&candidate).await
+    }
 
-        // Store authentication state (browser source already detected)
-        self.gong_cookies = Some(GongCookies {
-            cell: cell.clone(),
-            session_cookies,
-            extracted_at: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs_f64(),
-            browser: browser_source.clone(),
-        });
+    /// Authenticate with cookies imported from a cookies.txt, HAR or raw header
+    ///
+    /// Used where browser cookie stores can't be read. Cell detection, CSRF and
+    /// workspace extraction work exactly as for browser cookies.
+    ///
+    /// # Returns
+    /// true if Gong accepted the imported cookies
+    pub async fn authenticate_with_import(&mut self, source: &CookieImportSource) -> Result<bool> {
+        info!(source = %source.label(), "Starting Gong authentication with imported cookies");
 
-        self.base_url = Some(format!("https://{cell}.app.gong.io"));
+        let cookies = self.cookie_importer.import(source)?;
+        let candidate = CookieCandidate::new(source.label(), cookies);
 
-        // Set cookies for multiple domains to ensure cell-specific authentication
-        // This matches the Python implementation's domain strategy
-        if let Some(gong_cookies) = &self.gong_cookies {
-            let domains_to_set = vec![
-                ".gong.io".to_string(),
-                ".app.gong.io".to_string(),
-                format!(".{}.app.gong.io", gong_cookies.cell),
-            ];
-
-            // Set cookies for each domain (matching Python behavior)
-            for _domain in &domains_to_set {
-                // Note: Since our HTTP client doesn't support domain-specific cookies,
-                // we set them globally. The original domain info is preserved in cookie metadata.
-                self.http_client
-                    .set_cookies(gong_cookies.session_cookies.clone())
-                    .await?;
-            }
+        if !self.try_cookie_candidate(&candidate).await? {
+            self.gong_cookies = None;
+            self.base_url = None;
+            error!(source = %candidate.source, "Gong rejected the imported cookies");
+            return Ok(false);
         }
 
+        self.complete_authentication(&candidate).await
+    }
+
+    /// Fetch the initial CSRF token and workspace ID for an accepted session
+    async fn complete_authentication(&mut self, candidate: &CookieCandidate) -> Result<bool> {
+        let cell = self.get_cell().unwrap_or_default().to_string();
+
         // Get initial CSRF token (retry based on config)
         match self.csrf_manager.get_csrf_token(&cell, false).await? {
             Some(_) => {
@@ -183,22 +209,229 @@ impl GongAuthenticator {
 
         info!(
             cell = %cell,
-            browser = %browser_source,
-            cookies_count = cookies.len(),
+            browser = %candidate.source,
+            cookies_count = candidate.cookies.len(),
             "Authentication flow completed successfully"
         );
 
         Ok(true)
     }
 
+    /// Browser/profile selection from configuration
+    fn browser_selection(&self) -> Result<BrowserSelection> {
+        BrowserSelection::from_names(
+            self.config.browser.as_deref(),
+            self.config.browser_profile.as_deref(),
+        )
+    }
+
+    /// Install a candidate browser session and probe it against Gong
+    ///
+    /// # Returns
+    /// true if the session was accepted; the authenticator keeps its state
+    pub async fn try_cookie_candidate(&mut self, candidate: &CookieCandidate) -> Result<bool> {
+        let cell = self.extract_cell_from_cookies(&candidate.cookies)?;
+
+        let session_cookies: HashMap<String, String> = candidate
+            .cookies
+            .iter()
+            .map(|cookie| (cookie.name.clone(), cookie.value.clone()))
+            .collect();
+
+        self.gong_cookies = Some(GongCookies {
+            cell: cell.clone(),
+            session_cookies: session_cookies.clone(),
+            extracted_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs_f64(),
+            browser: candidate.source.clone(),
+        });
+        self.base_url = Some(format!("https://{cell}.app.gong.io"));
+
+        // Drop cookies from any previously tried candidate
+        self.http_client.clear_cookies().await;
+        self.http_client.set_cookies(session_cookies).await?;
+        self.csrf_manager.invalidate_token().await;
+
+        self.validate_session().await
+    }
+
+    /// Restore a previously persisted session instead of scanning browsers
+    ///
+    /// The session is only reused when it is younger than the configured maximum
+    /// age and passes a live probe against Gong. Invalid sessions are removed from
+    /// the store so the next run goes straight to browser extraction.
+    ///
+    /// # Returns
+    /// true if a valid persisted session was restored
+    pub async fn restore_session(&mut self, store: &SessionStore) -> Result<bool> {
+        let session = match store.load()? {
+            Some(session) => session,
+            None => {
+                debug!("No persisted session available");
+                return Ok(false);
+            }
+        };
+
+        // An explicit --browser/--browser-profile must not be overridden by a cached session
+        if !self.browser_selection()?.matches_label(&session.cookies.browser) {
+            info!(
+                saved_browser = %session.cookies.browser,
+                "Persisted session came from a different browser than requested"
+            );
+            return Ok(false);
+        }
+
+        let age_hours = session.age_hours();
+        if age_hours > self.config.session_max_age_hours as f64 {
+            info!(
+                age_hours = age_hours,
+                max_age_hours = self.config.session_max_age_hours,
+                "Persisted session is too old, re-extracting browser cookies"
+            );
+            store.clear()?;
+            return Ok(false);
+        }
+
+        let cell = session.cookies.cell.clone();
+        self.http_client
+            .set_cookies(session.cookies.session_cookies.clone())
+            .await?;
+        self.base_url = Some(format!("https://{cell}.app.gong.io"));
+        self.workspace_id = session.workspace_id.clone();
+        self.gong_cookies = Some(session.cookies);
+
+        if let Some(token) = session.csrf_token {
+            self.csrf_manager
+                .restore_token(token, session.csrf_expires_at)
+                .await;
+        }
+
+        if !self.validate_session().await? {
+            info!("Persisted session was rejected by Gong, falling back to browser cookies");
+            self.gong_cookies = None;
+            self.base_url = None;
+            self.workspace_id = None;
+            self.csrf_manager.invalidate_token().await;
+            store.clear()?;
+            return Ok(false);
+        }
+
+        // Older sessions may predate a successful workspace scrape
+        if self.workspace_id.is_none() {
+            if let Ok(Some(workspace_id)) = self.extract_workspace_id().await {
+                self.workspace_id = Some(workspace_id);
+            }
+        }
+
+        info!(
+            cell = %cell,
+            age_hours = age_hours,
+            "Restored persisted Gong session"
+        );
+
+        Ok(true)
+    }
+
+    /// Cheap liveness probe for the current session cookies
+    ///
+    /// Fetches a CSRF token once (no retries). A successful response proves the
+    /// cookies are still accepted and refreshes the cached CSRF token.
+    ///
+    /// # Returns
+    /// true if Gong accepted the session
+    pub async fn validate_session(&self) -> Result<bool> {
+        let cell = match self.get_cell() {
+            Some(cell) => cell.to_string(),
+            None => return Ok(false),
+        };
+        let url = format!("https://{cell}.app.gong.io/ajax/common/rtkn");
+
+        let mut headers = self.get_read_headers()?;
+        headers.insert(
+            "Accept".to_string(),
+            "application/json, text/javascript, */*; q=0.01".to_string(),
+        );
+        self.http_client.update_headers(headers).await?;
+
+        let response = match self.http_client.get_once(&url).await {
+            Ok(response) => response,
+            Err(e) => {
+                warn!(error = %e, "Session probe request failed");
+                return Ok(false);
+            }
+        };
+
+        if !response.status().is_success() {
+            debug!(
+                status = response.status().as_u16(),
+                "Session probe rejected"
+            );
+            return Ok(false);
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        let token = serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|data| data.get("token").and_then(|t| t.as_str()).map(String::from));
+
+        match token {
+            Some(token) => {
+                let expires_at = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs_f64()
+                    + self.csrf_manager.token_ttl_seconds();
+                self.csrf_manager.restore_token(token, expires_at).await;
+                Ok(true)
+            }
+            None => {
+                // Expired sessions are redirected to the login page instead of JSON
+                debug!("Session probe returned no CSRF token");
+                Ok(false)
+            }
+        }
+    }
+
+    /// Save the current session so the next run can skip browser extraction
+    pub async fn persist_session(&self, store: &SessionStore) -> Result<()> {
+        let cookies = self.gong_cookies.clone().ok_or_else(|| {
+            CsCliError::Authentication("Not authenticated - nothing to persist".to_string())
+        })?;
+
+        let (csrf_token, csrf_expires_at) = match self.csrf_manager.get_token_snapshot().await {
+            Some((token, expires_at)) => (Some(token), expires_at),
+            None => (None, 0.0),
+        };
+
+        store.save(&PersistedSession::new(
+            cookies,
+            self.workspace_id.clone(),
+            csrf_token,
+            csrf_expires_at,
+        ))?;
+
+        info!(path = %store.session_path().display(), "Persisted Gong session");
+        Ok(())
+    }
+
     /// Extract Gong cell identifier from cookies using multiple strategies
     fn extract_cell_from_cookies(&self, cookies: &[Cookie]) -> Result<String> {
+        self.detect_cell(cookies).map(|(cell, _)| cell)
+    }
+
+    /// Determine the Gong cell and the strategy that found it
+    ///
+    /// # Returns
+    /// (cell, strategy) where strategy is "jwt_decode" or "domain_parsing"
+    pub fn detect_cell(&self, cookies: &[Cookie]) -> Result<(String, &'static str)> {
         // Strategy 1: Decode from JWT "cell" cookie
         for cookie in cookies {
             if cookie.name == "cell" {
                 if let Ok(cell) = self.decode_cell_cookie(&cookie.value) {
                     debug!(cell = %cell, strategy = "jwt_decode", "Extracted cell from JWT cookie");
-                    return Ok(cell);
+                    return Ok((cell, "jwt_decode"));
                 }
             }
         }
@@ -207,7 +440,7 @@ impl GongAuthenticator {
         let domains: Vec<String> = cookies.iter().map(|c| c.domain.clone()).collect();
         if let Ok(cell) = self.extract_cell_from_domains(&domains) {
             debug!(cell = %cell, strategy = "domain_parsing", "Extracted cell from domain names");
-            return Ok(cell);
+            return Ok((cell, "domain_parsing"));
         }
 
         Err(CsCliError::Authentication(
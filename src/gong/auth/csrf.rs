@@ -232,6 +232,30 @@ impl CSRFManager {
         state.csrf_token.clone()
     }
 
+    /// Get current token with its expiry (Unix timestamp) for session persistence
+    pub async fn get_token_snapshot(&self) -> Option<(String, f64)> {
+        let state = self.token_state.lock().await;
+        state
+            .csrf_token
+            .clone()
+            .map(|token| (token, state.token_expires_at))
+    }
+
+    /// Seed the token cache from a persisted session or a fresh probe
+    pub async fn restore_token(&self, token: String, expires_at: f64) {
+        let mut state = self.token_state.lock().await;
+        state.csrf_token = Some(token);
+        state.token_expires_at = expires_at;
+        state.refresh_attempts = 0;
+
+        debug!(expires_at = expires_at, "CSRF token restored");
+    }
+
+    /// Token lifetime from configuration, in seconds
+    pub fn token_ttl_seconds(&self) -> f64 {
+        (self.config.csrf_token_ttl_minutes * 60) as f64
+    }
+
     /// Check if token is currently valid (for health checks)
     pub async fn is_token_currently_valid(&self) -> bool {
         let state = self.token_state.lock().await;
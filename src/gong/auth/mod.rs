@@ -1,7 +1,9 @@
 pub mod authenticator;
 pub mod cookies;
 pub mod csrf;
+pub mod session_store;
 
 pub use authenticator::*;
 pub use cookies::*;
 pub use csrf::*;
+pub use session_store::*;
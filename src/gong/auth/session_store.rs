@@ -0,0 +1,311 @@
+//! Encrypted on-disk persistence for authenticated Gong sessions
+//!
+//! Browser cookie extraction is the slowest part of startup (and prompts for the
+//! keychain on macOS), so a successfully authenticated session is saved between
+//! runs. The session file is encrypted with AES-256-GCM using a random key kept
+//! in a separate owner-only key file, so the session file on its own (backups,
+//! synced folders, accidental copies) does not leak Gong cookies.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{debug, info};
+
+use super::GongCookies;
+use crate::{CsCliError, Result};
+
+/// Magic prefix identifying the session file format
+const SESSION_MAGIC: &[u8; 4] = b"CSS1";
+
+/// Length of the AES-GCM nonce in bytes
+const NONCE_LEN: usize = 12;
+
+/// Length of the AES-256 key in bytes
+const KEY_LEN: usize = 32;
+
+/// Bumped whenever the persisted session layout changes
+const SESSION_VERSION: u32 = 1;
+
+/// Authenticated session state saved between runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedSession {
+    /// Layout version of this record
+    pub version: u32,
+    /// Browser cookies and cell
+    pub cookies: GongCookies,
+    /// Gong workspace ID scraped from the home page
+    pub workspace_id: Option<String>,
+    /// Last known CSRF token
+    pub csrf_token: Option<String>,
+    /// When the CSRF token expires (Unix timestamp)
+    pub csrf_expires_at: f64,
+    /// When this record was written (Unix timestamp)
+    pub saved_at: f64,
+}
+
+impl PersistedSession {
+    /// Create a new record for the current layout version
+    pub fn new(
+        cookies: GongCookies,
+        workspace_id: Option<String>,
+        csrf_token: Option<String>,
+        csrf_expires_at: f64,
+    ) -> Self {
+        Self {
+            version: SESSION_VERSION,
+            cookies,
+            workspace_id,
+            csrf_token,
+            csrf_expires_at,
+            saved_at: now_secs(),
+        }
+    }
+
+    /// Age of the underlying browser cookies in hours
+    pub fn age_hours(&self) -> f64 {
+        (now_secs() - self.cookies.extracted_at).max(0.0) / 3600.0
+    }
+}
+
+/// Reads and writes the encrypted session file
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+    /// Encrypted session file
+    session_path: PathBuf,
+    /// Owner-only file holding the encryption key
+    key_path: PathBuf,
+}
+
+impl SessionStore {
+    /// Create a store rooted in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            session_path: dir.join("session.enc"),
+            key_path: dir.join("session.key"),
+        }
+    }
+
+    /// Create a store in the per-user data directory (e.g. `~/.local/share/cs-cli`)
+    pub fn default_location() -> Result<Self> {
+        let dir = dirs::data_local_dir()
+            .or_else(dirs::home_dir)
+            .ok_or_else(|| {
+                CsCliError::Configuration("Could not determine data directory".to_string())
+            })?
+            .join("cs-cli");
+
+        Ok(Self::new(&dir))
+    }
+
+    /// Path of the encrypted session file
+    pub fn session_path(&self) -> &Path {
+        &self.session_path
+    }
+
+    /// Load and decrypt the persisted session, if one exists
+    ///
+    /// Unreadable, tampered or outdated files are treated as absent and removed.
+    pub fn load(&self) -> Result<Option<PersistedSession>> {
+        if !self.session_path.exists() || !self.key_path.exists() {
+            return Ok(None);
+        }
+
+        let data = fs::read(&self.session_path)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to read session file: {e}")))?;
+
+        let session = match self.decrypt(&data) {
+            Ok(plaintext) => serde_json::from_slice::<PersistedSession>(&plaintext)
+                .ok()
+                .filter(|s| s.version == SESSION_VERSION),
+            Err(e) => {
+                debug!(error = %e, "Persisted session could not be decrypted");
+                None
+            }
+        };
+
+        if session.is_none() {
+            info!("Discarding unusable persisted session");
+            self.clear()?;
+        }
+
+        Ok(session)
+    }
+
+    /// Encrypt and save a session
+    pub fn save(&self, session: &PersistedSession) -> Result<()> {
+        let plaintext = serde_json::to_vec(session)
+            .map_err(|e| CsCliError::Generic(format!("Failed to serialize session: {e}")))?;
+
+        let key = self.load_or_create_key()?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|e| CsCliError::Generic(format!("Failed to encrypt session: {e}")))?;
+
+        let mut data = Vec::with_capacity(SESSION_MAGIC.len() + NONCE_LEN + ciphertext.len());
+        data.extend_from_slice(SESSION_MAGIC);
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&ciphertext);
+
+        write_private(&self.session_path, &data)?;
+        debug!(path = %self.session_path.display(), "Persisted session saved");
+        Ok(())
+    }
+
+    /// Remove the persisted session (the key is kept for reuse)
+    pub fn clear(&self) -> Result<()> {
+        if self.session_path.exists() {
+            fs::remove_file(&self.session_path)
+                .map_err(|e| CsCliError::FileIo(format!("Failed to remove session file: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// Decrypt a session file payload
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let header_len = SESSION_MAGIC.len() + NONCE_LEN;
+        if data.len() <= header_len || &data[..SESSION_MAGIC.len()] != SESSION_MAGIC {
+            return Err(CsCliError::Authentication(
+                "Session file has an unknown format".to_string(),
+            ));
+        }
+
+        let key = self.read_key()?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+        let nonce = Nonce::from_slice(&data[SESSION_MAGIC.len()..header_len]);
+
+        cipher
+            .decrypt(nonce, &data[header_len..])
+            .map_err(|e| CsCliError::Authentication(format!("Failed to decrypt session: {e}")))
+    }
+
+    /// Read the existing encryption key
+    fn read_key(&self) -> Result<Vec<u8>> {
+        let key = fs::read(&self.key_path)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to read session key: {e}")))?;
+
+        if key.len() != KEY_LEN {
+            return Err(CsCliError::Authentication(
+                "Session key has an invalid length".to_string(),
+            ));
+        }
+
+        Ok(key)
+    }
+
+    /// Read the encryption key, generating one on first use
+    fn load_or_create_key(&self) -> Result<Vec<u8>> {
+        if let Ok(key) = self.read_key() {
+            return Ok(key);
+        }
+
+        let key = Aes256Gcm::generate_key(OsRng);
+        write_private(&self.key_path, &key)?;
+        Ok(key.to_vec())
+    }
+}
+
+/// Write a file readable only by the current user
+fn write_private(path: &Path, data: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create session directory: {e}")))?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    // Create the file private so the key is never readable by others, even briefly
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to create {}: {e}", path.display())))?;
+
+    // `mode` only applies to new files; tighten one left by an older version
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))
+            .map_err(|e| CsCliError::FileIo(format!("Failed to restrict permissions: {e}")))?;
+    }
+
+    file.write_all(data)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))
+}
+
+/// Current Unix time in seconds
+fn now_secs() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn sample_session() -> PersistedSession {
+        let mut session_cookies = HashMap::new();
+        session_cookies.insert("g-session".to_string(), "secret".to_string());
+
+        PersistedSession::new(
+            GongCookies {
+                cell: "us-14496".to_string(),
+                session_cookies,
+                extracted_at: now_secs(),
+                browser: "Chrome".to_string(),
+            },
+            Some("123".to_string()),
+            Some("token".to_string()),
+            now_secs() + 1800.0,
+        )
+    }
+
+    fn temp_store(name: &str) -> (SessionStore, PathBuf) {
+        let dir =
+            std::env::temp_dir().join(format!("cs-cli-session-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        (SessionStore::new(&dir), dir)
+    }
+
+    #[test]
+    fn test_session_round_trip_is_encrypted() {
+        let (store, dir) = temp_store("roundtrip");
+        store.save(&sample_session()).unwrap();
+
+        let raw = fs::read(store.session_path()).unwrap();
+        assert!(!String::from_utf8_lossy(&raw).contains("secret"));
+
+        let loaded = store.load().unwrap().unwrap();
+        assert_eq!(loaded.cookies.cell, "us-14496");
+        assert_eq!(loaded.workspace_id.as_deref(), Some("123"));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_tampered_session_is_discarded() {
+        let (store, dir) = temp_store("tampered");
+        store.save(&sample_session()).unwrap();
+
+        let mut raw = fs::read(store.session_path()).unwrap();
+        let last = raw.len() - 1;
+        raw[last] ^= 0xff;
+        fs::write(store.session_path(), raw).unwrap();
+
+        assert!(store.load().unwrap().is_none());
+        assert!(!store.session_path().exists());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
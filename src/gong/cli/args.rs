@@ -5,6 +5,12 @@
 
 use clap::{Args, Parser, Subcommand};
 use clap_complete::Shell;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+use crate::common::events::EventFormat;
+use crate::gong::config::{BackendKind, OutputFormat};
+use crate::gong::output::activity_csv::CsvPreset;
 
 /// CS-CLI: Customer Success Communication Extraction Tool
 ///
@@ -25,10 +31,21 @@ EXAMPLES:
     cs-cli customer \"Fortune 500\" 30 calls emails    Get calls and emails
     cs-cli team                         Get last 7 days of team calls (prompts for stream ID)
     cs-cli team 30                      Get last 30 days of team calls (prompts for stream ID)
+    cs-cli batch portfolio.toml         Extract every customer listed in a portfolio file
+    cs-cli --format html customer Postman    Save a shareable offline HTML report
+    cs-cli doctor                       Diagnose Gong login problems
+    cs-cli config set auth.browser firefox    Save a setting in the user config file
+    cs-cli templates dump               Export the markdown templates to customize
+    cs-cli export sqlite Postman        Add Postman's calls and emails to a SQLite database
+    cs-cli export csv Postman --preset salesforce    Activities CSV for a Salesforce import
+    cs-cli --format html render         Rebuild saved extractions as HTML, offline
+    cs-cli update                       Install the latest verified release
+    cs-cli --events=json customer Postman    Stream NDJSON progress events for other tools
 
 KEYCHAIN (macOS):
-    cs-cli --keychain-password=yourpass customer Postman    Provide password via CLI
-    cs-cli customer Postman                                 Will prompt for password automatically"
+    cs-cli customer Postman                                 Prompts only if the keychain is locked
+    cs-cli --keychain-password-from stdin customer Postman < password.txt
+    cs-cli --keychain-password-from 'cmd:op read op://Private/Mac/password' customer Postman"
 )]
 #[derive(Default)]
 pub struct CliArgs {
@@ -41,9 +58,96 @@ pub struct CliArgs {
     pub no_terminal: bool,
 
     /// macOS keychain password (optional, will prompt if needed)
-    #[arg(long, help = "macOS keychain password for browser cookie access")]
+    #[arg(
+        long,
+        global = true,
+        help = "macOS keychain password for browser cookie access (visible in `ps`; prefer --keychain-password-from)"
+    )]
     pub keychain_password: Option<String>,
 
+    /// Where to read the macOS keychain password from
+    #[arg(
+        long,
+        global = true,
+        value_name = "SOURCE",
+        conflicts_with = "keychain_password",
+        help = "Read the macOS keychain password from: prompt, stdin, fd:N, env:NAME (env = $GONG_KEYCHAIN_PASSWORD), cmd:COMMAND, or none"
+    )]
+    pub keychain_password_from: Option<String>,
+
+    /// Ignore the saved session and re-extract browser cookies
+    #[arg(
+        long,
+        global = true,
+        help = "Ignore the saved Gong session and log in from browser cookies again"
+    )]
+    pub fresh_login: bool,
+
+    /// Browser to read Gong cookies from
+    #[arg(
+        long,
+        global = true,
+        value_name = "NAME",
+        help = "Browser to read Gong cookies from: firefox, chrome, edge, arc, brave, chromium, librewolf, opera, opera-gx, vivaldi, zen, safari, cachy, or auto (default)"
+    )]
+    pub browser: Option<String>,
+
+    /// Browser profile to read Gong cookies from
+    #[arg(
+        long,
+        global = true,
+        value_name = "NAME",
+        help = "Browser profile to use, e.g. \"Profile 1\" (requires --browser)"
+    )]
+    pub browser_profile: Option<String>,
+
+    /// Import Gong cookies instead of reading a browser
+    #[arg(
+        long,
+        global = true,
+        value_name = "SOURCE",
+        help = "Import Gong cookies from a cookies.txt, HAR export or raw Cookie header: a file path, - for stdin, or env:NAME"
+    )]
+    pub cookies: Option<String>,
+
+    /// Gong backend to extract from
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        help = "Gong backend: web (browser session, default) or api (public API with GONG_ACCESS_KEY/GONG_ACCESS_KEY_SECRET)"
+    )]
+    pub backend: Option<BackendKind>,
+
+    /// Output file format
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        help = "Save customer extractions as markdown (default), html, obsidian, docx, pdf, mbox, sqlite, parquet or csv"
+    )]
+    pub format: Option<OutputFormat>,
+
+    /// Progress output format
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "FORMAT",
+        default_value_t = EventFormat::Human,
+        help = "Progress output: human (default) or json (NDJSON events on stdout, human output on stderr)"
+    )]
+    pub events: EventFormat,
+
+    /// Suppress progress output
+    #[arg(
+        short = 'q',
+        long,
+        global = true,
+        help = "Only print errors, no progress output (colors are also off when NO_COLOR is set)"
+    )]
+    pub quiet: bool,
+
     /// Subcommand or flexible arguments
     #[command(subcommand)]
     pub command: Option<Command>,
@@ -59,10 +163,204 @@ pub enum Command {
     Customer(CustomerArgs),
     /// Extract team calls from call stream
     Team(TeamArgs),
+    /// Extract every customer listed in a portfolio file (TOML or CSV)
+    Batch(BatchArgs),
+    /// Diagnose Gong login problems (browsers, cookies, cell, CSRF, endpoints)
+    Doctor(DoctorArgs),
+    /// Show, change or check the configuration files
+    Config(ConfigArgs),
+    /// Export the built-in output templates for customizing
+    Templates(TemplatesArgs),
+    /// Extract a customer into a database for analysis
+    Export(ExportArgs),
+    /// Rebuild output folders from saved extractions without contacting Gong
+    Render(RenderArgs),
+    /// Check for, install or roll back cs-cli releases
+    Update(UpdateArgs),
     /// Generate shell completion scripts
     Completion(CompletionArgs),
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct DoctorArgs {
+    /// Print the report as JSON instead of a table
+    #[arg(long, help = "Print the report as JSON instead of a table")]
+    pub json: bool,
+
+    /// Save the JSON report to a file
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_name = "FILE",
+        help = "Save the report as JSON to FILE (for support tickets)"
+    )]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigAction {
+    /// Print the effective configuration after merging every layer
+    Show {
+        /// Print as JSON instead of TOML
+        #[arg(long, help = "Print as JSON instead of TOML")]
+        json: bool,
+    },
+    /// Print one effective setting, e.g. `http.pool_size`
+    Get {
+        /// Dotted setting name
+        key: String,
+    },
+    /// Save a setting in the user config file (empty value removes it)
+    Set {
+        /// Dotted setting name
+        key: String,
+        /// New value
+        value: String,
+        /// Write to the project `cs-cli.toml` instead of the user config file
+        #[arg(long, help = "Write to ./cs-cli.toml instead of the user config file")]
+        project: bool,
+    },
+    /// Print the config file locations in priority order
+    Path,
+    /// Check the config files for unknown settings and invalid values
+    Validate,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct TemplatesArgs {
+    #[command(subcommand)]
+    pub action: TemplatesAction,
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum TemplatesAction {
+    /// Write the built-in templates to the user templates folder
+    Dump {
+        /// Folder to write to instead of the user templates folder
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "Write to DIR instead of ~/.config/cs-cli/templates"
+        )]
+        dir: Option<PathBuf>,
+        /// Replace templates that already exist
+        #[arg(long, help = "Overwrite templates that already exist")]
+        force: bool,
+    },
+    /// Print the templates folder and which templates are customized
+    Path,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ExportArgs {
+    /// Export format
+    #[arg(value_enum, help = "What to export to")]
+    pub format: ExportFormat,
+
+    /// Where to write instead of the configured location
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_name = "PATH",
+        help = "Where to write: the database file for sqlite, the dataset folder for parquet, the folder for csv"
+    )]
+    pub output: Option<PathBuf>,
+
+    /// CRM column layout for csv
+    #[arg(
+        long,
+        value_enum,
+        help = "Column layout for csv: generic (default), salesforce or hubspot"
+    )]
+    pub preset: Option<CsvPreset>,
+
+    #[command(flatten)]
+    pub customer: CustomerArgs,
+}
+
+/// Formats `cs-cli export` writes
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// SQLite database that accumulates across runs
+    Sqlite,
+    /// Parquet tables partitioned by customer and month
+    Parquet,
+    /// CSV for a CRM activity import
+    Csv,
+}
+
+impl From<ExportFormat> for OutputFormat {
+    fn from(format: ExportFormat) -> Self {
+        match format {
+            ExportFormat::Sqlite => OutputFormat::Sqlite,
+            ExportFormat::Parquet => OutputFormat::Parquet,
+            ExportFormat::Csv => OutputFormat::Csv,
+        }
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct RenderArgs {
+    /// Saved extraction to render
+    #[arg(
+        long,
+        value_name = "PATH|cache",
+        default_value = "cache",
+        help = "Snapshot JSON file or folder, or cache (default) for the extractions saved by earlier runs"
+    )]
+    pub from: String,
+
+    /// Only render this customer
+    #[arg(long, value_name = "NAME", help = "Only render this customer")]
+    pub customer: Option<String>,
+
+    /// Folder for the customer folders instead of the Desktop
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_name = "DIR",
+        help = "Write the ct_<customer> folders into DIR instead of the Desktop"
+    )]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct UpdateArgs {
+    #[command(subcommand)]
+    pub action: Option<UpdateAction>,
+
+    /// Restore the version replaced by the last update (same as `update rollback`)
+    #[arg(long, help = "Restore the version replaced by the last update")]
+    pub rollback: bool,
+
+    /// Install without asking
+    #[arg(
+        short = 'y',
+        long,
+        global = true,
+        help = "Install without asking (needed when not run from a terminal)"
+    )]
+    pub yes: bool,
+}
+
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateAction {
+    /// Look for a newer release on the configured channel
+    Check,
+    /// Install the newest release (the default)
+    Apply,
+    /// Show the version, update policy and last check
+    Status,
+    /// Restore the version replaced by the last update
+    Rollback,
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct CompletionArgs {
     /// Shell to generate completion for
@@ -103,6 +401,42 @@ pub struct CustomerArgs {
     /// Fetch full email body content
     #[arg(long = "fetch-bodies", help = "Fetch full email body content")]
     pub fetch_email_bodies: bool,
+
+    /// Also save the customer's shared Slack channels
+    #[cfg(feature = "slack")]
+    #[arg(
+        long = "slack",
+        help = "Also save the customer's Slack Connect channels (SLACK_TOKEN or SLACK_WORKSPACE)"
+    )]
+    pub slack: bool,
+}
+
+impl CustomerArgs {
+    /// The customer extraction these arguments describe
+    pub fn parsed(&self) -> ParsedCommand {
+        ParsedCommand::Customer {
+            name: self.name.clone(),
+            days: self.days,
+            from_date: self.from_date.clone(),
+            to_date: self.to_date.clone(),
+            content_type: self.content.unwrap_or(ContentType::Both),
+            emails_only: self.emails_only,
+            fetch_email_bodies: self.fetch_email_bodies,
+            slack: self.slack(),
+        }
+    }
+
+    /// Whether Slack channels were requested (always false without the slack feature)
+    pub fn slack(&self) -> bool {
+        #[cfg(feature = "slack")]
+        {
+            self.slack
+        }
+        #[cfg(not(feature = "slack"))]
+        {
+            false
+        }
+    }
 }
 
 #[derive(Args, Debug, Clone)]
@@ -128,7 +462,39 @@ pub struct TeamArgs {
     pub to_date: Option<String>,
 }
 
-#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Args, Debug, Clone)]
+pub struct BatchArgs {
+    /// Portfolio file listing customers
+    #[arg(help = "Portfolio file (.toml or .csv) listing customers to extract")]
+    pub file: PathBuf,
+
+    /// Number of customers to extract concurrently
+    #[arg(
+        short = 'j',
+        long = "parallel",
+        help = "Number of customers to extract concurrently (default: 3)"
+    )]
+    pub parallel: Option<usize>,
+
+    /// Default number of days back for customers without their own value
+    #[arg(
+        short = 'd',
+        long = "days",
+        help = "Default number of days back to search (default: 90)"
+    )]
+    pub days: Option<u32>,
+
+    /// Default content type for customers without their own value
+    #[arg(short = 'c', long = "content", value_enum, help = "Default content to extract")]
+    pub content: Option<ContentType>,
+
+    /// Skip fetching full email bodies
+    #[arg(long = "skip-bodies", help = "Skip fetching full email body content")]
+    pub skip_email_bodies: bool,
+}
+
+#[derive(clap::ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 pub enum ContentType {
     /// Extract only calls
     Calls,
@@ -160,6 +526,8 @@ pub enum ParsedCommand {
         content_type: ContentType,
         emails_only: bool,
         fetch_email_bodies: bool,
+        /// Also extract shared Slack channels
+        slack: bool,
     },
     /// Team extraction mode
     Team {
@@ -168,6 +536,28 @@ pub enum ParsedCommand {
         from_date: Option<String>,
         to_date: Option<String>,
     },
+    /// Batch portfolio extraction mode
+    Batch {
+        file: PathBuf,
+        parallel: Option<usize>,
+        days: Option<u32>,
+        content_type: Option<ContentType>,
+        fetch_email_bodies: bool,
+    },
+    /// Auth diagnostics
+    Doctor { json: bool, output: Option<PathBuf> },
+    /// Configuration management
+    Config(ConfigAction),
+    /// Output template management
+    Templates(TemplatesAction),
+    /// Re-render saved extractions offline
+    Render {
+        from: String,
+        customer: Option<String>,
+        output: Option<PathBuf>,
+    },
+    /// Self-update
+    Update { action: UpdateAction, yes: bool },
     /// Generate shell completion
     Completion { shell: Shell },
     /// Interactive mode (no arguments provided)
@@ -183,21 +573,41 @@ impl CliArgs {
         // If we have a proper subcommand, use it
         if let Some(command) = &self.command {
             return match command {
-                Command::Customer(args) => Ok(ParsedCommand::Customer {
-                    name: args.name.clone(),
-                    days: args.days,
-                    from_date: args.from_date.clone(),
-                    to_date: args.to_date.clone(),
-                    content_type: args.content.unwrap_or(ContentType::Both),
-                    emails_only: args.emails_only,
-                    fetch_email_bodies: args.fetch_email_bodies,
-                }),
+                Command::Customer(args) => Ok(args.parsed()),
                 Command::Team(args) => Ok(ParsedCommand::Team {
                     stream_id: args.stream_id.clone(),
                     days: args.days,
                     from_date: args.from_date.clone(),
                     to_date: args.to_date.clone(),
                 }),
+                Command::Batch(args) => Ok(ParsedCommand::Batch {
+                    file: args.file.clone(),
+                    parallel: args.parallel,
+                    days: args.days,
+                    content_type: args.content,
+                    fetch_email_bodies: !args.skip_email_bodies,
+                }),
+                Command::Doctor(args) => Ok(ParsedCommand::Doctor {
+                    json: args.json,
+                    output: args.output.clone(),
+                }),
+                Command::Config(args) => Ok(ParsedCommand::Config(args.action.clone())),
+                Command::Templates(args) => Ok(ParsedCommand::Templates(args.action.clone())),
+                // An export is a customer extraction saved in the export's format
+                Command::Export(args) => Ok(args.customer.parsed()),
+                Command::Render(args) => Ok(ParsedCommand::Render {
+                    from: args.from.clone(),
+                    customer: args.customer.clone(),
+                    output: args.output.clone(),
+                }),
+                Command::Update(args) => Ok(ParsedCommand::Update {
+                    action: match args.action {
+                        Some(action) => action,
+                        None if args.rollback => UpdateAction::Rollback,
+                        None => UpdateAction::Apply,
+                    },
+                    yes: args.yes,
+                }),
                 Command::Completion(args) => Ok(ParsedCommand::Completion { shell: args.shell }),
             };
         }
@@ -373,6 +783,7 @@ impl CliArgs {
             content_type,
             emails_only,
             fetch_email_bodies: matches!(content_type, ContentType::Emails | ContentType::Both),
+            slack: false,
         })
     }
 }
@@ -388,6 +799,7 @@ mod tests {
             keychain_password: None,
             command: None,
             raw_args: vec!["Postman".to_string(), "30".to_string(), "calls".to_string()],
+            ..Default::default()
         };
 
         match args.parse_command().unwrap() {
@@ -416,6 +828,7 @@ mod tests {
                 "30".to_string(),
                 "Postman".to_string(),
             ],
+            ..Default::default()
         };
 
         match args.parse_command().unwrap() {
@@ -446,6 +859,7 @@ mod tests {
                 "365".to_string(),
                 "emails".to_string(),
             ],
+            ..Default::default()
         };
 
         match args.parse_command().unwrap() {
@@ -470,6 +884,7 @@ mod tests {
             keychain_password: None,
             command: None,
             raw_args: vec!["team".to_string(), "14".to_string()],
+            ..Default::default()
         };
 
         match args.parse_command().unwrap() {
@@ -487,6 +902,7 @@ mod tests {
             keychain_password: None,
             command: None,
             raw_args: vec![],
+            ..Default::default()
         };
 
         match args.parse_command().unwrap() {
@@ -507,6 +923,7 @@ mod tests {
                 "emails".to_string(),
                 "30".to_string(),
             ],
+            ..Default::default()
         };
 
         match args.parse_command().unwrap() {
@@ -523,4 +940,20 @@ mod tests {
             _ => panic!("Expected customer command"),
         }
     }
+
+    #[test]
+    fn test_login_flags_after_subcommand() {
+        let args = CliArgs::try_parse_from([
+            "cs-cli",
+            "customer",
+            "Postman",
+            "--fresh-login",
+            "--keychain-password-from",
+            "stdin",
+        ])
+        .unwrap();
+
+        assert!(args.fresh_login);
+        assert_eq!(args.keychain_password_from.as_deref(), Some("stdin"));
+    }
 }
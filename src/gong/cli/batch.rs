@@ -0,0 +1,629 @@
+//! Batch portfolio extraction
+//!
+//! Runs customer extraction for every account listed in a portfolio file
+//! (TOML or CSV) against a single authenticated extractor, so a CSM with a
+//! book of accounts only pays for setup and authentication once.
+
+use figment::providers::{Format, Toml};
+use figment::Figment;
+use futures::stream::{self, StreamExt};
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use tracing::{error, info};
+
+use super::args::ContentType;
+use super::{CustomerExtraction, TeamCallsExtractor};
+use crate::common::events::{emit, status, Event};
+use crate::{CsCliError, Result};
+
+/// Default number of customers extracted concurrently
+pub const DEFAULT_BATCH_PARALLELISM: usize = 3;
+
+/// A single customer entry in a portfolio file
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct PortfolioEntry {
+    /// Customer/company name to search for
+    pub name: String,
+    /// Days back to search (overrides batch defaults)
+    #[serde(default)]
+    pub days: Option<u32>,
+    /// Content to extract (overrides batch defaults)
+    #[serde(default)]
+    pub content: Option<ContentType>,
+}
+
+/// Portfolio of customers to extract in one batch run
+///
+/// TOML portfolios may set file-wide defaults:
+///
+/// ```toml
+/// days = 90
+/// content = "both"
+/// parallel = 4
+///
+/// [[customers]]
+/// name = "Wells Fargo"
+/// days = 30
+///
+/// [[customers]]
+/// name = "7-11"
+/// content = "emails"
+/// ```
+///
+/// CSV portfolios use the columns `name,days,content`; only `name` is required
+/// and the header row is optional.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+pub struct Portfolio {
+    /// Default days back for entries without their own value
+    #[serde(default)]
+    pub days: Option<u32>,
+    /// Default content type for entries without their own value
+    #[serde(default)]
+    pub content: Option<ContentType>,
+    /// Number of customers to extract concurrently
+    #[serde(default)]
+    pub parallel: Option<usize>,
+    /// Customers to extract
+    #[serde(default)]
+    pub customers: Vec<PortfolioEntry>,
+}
+
+impl Portfolio {
+    /// Load a portfolio from a `.toml` or `.csv` file
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path).map_err(|e| {
+            CsCliError::FileIo(format!(
+                "Failed to read portfolio file {}: {e}",
+                path.display()
+            ))
+        })?;
+
+        let is_csv = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+
+        let portfolio = if is_csv {
+            Self::from_csv_str(&contents)?
+        } else {
+            Self::from_toml_str(&contents)?
+        };
+
+        if portfolio.customers.is_empty() {
+            return Err(CsCliError::InvalidArguments {
+                message: format!("Portfolio file {} lists no customers", path.display()),
+            });
+        }
+
+        Ok(portfolio)
+    }
+
+    /// Parse a TOML portfolio
+    pub fn from_toml_str(contents: &str) -> Result<Self> {
+        Figment::from(Toml::string(contents))
+            .extract()
+            .map_err(|e| CsCliError::Configuration(format!("Invalid portfolio TOML: {e}")))
+    }
+
+    /// Parse a CSV portfolio with columns `name,days,content`
+    pub fn from_csv_str(contents: &str) -> Result<Self> {
+        let mut customers = Vec::new();
+
+        for (line_num, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = split_csv_line(line);
+            let name = fields.first().map(|f| f.trim()).unwrap_or("");
+
+            // Skip an optional header row
+            if customers.is_empty() && name.eq_ignore_ascii_case("name") {
+                continue;
+            }
+
+            if name.is_empty() {
+                return Err(CsCliError::InvalidArguments {
+                    message: format!("Portfolio CSV line {}: customer name is empty", line_num + 1),
+                });
+            }
+
+            let days = match fields.get(1).map(|f| f.trim()).filter(|f| !f.is_empty()) {
+                Some(value) => Some(value.parse::<u32>().map_err(|_| {
+                    CsCliError::InvalidArguments {
+                        message: format!(
+                            "Portfolio CSV line {}: invalid days value '{value}'",
+                            line_num + 1
+                        ),
+                    }
+                })?),
+                None => None,
+            };
+
+            let content = match fields.get(2).map(|f| f.trim()).filter(|f| !f.is_empty()) {
+                Some(value) => Some(
+                    <ContentType as clap::ValueEnum>::from_str(value, true).map_err(|_| {
+                        CsCliError::InvalidArguments {
+                            message: format!(
+                                "Portfolio CSV line {}: invalid content value '{value}' (expected calls, emails or both)",
+                                line_num + 1
+                            ),
+                        }
+                    })?,
+                ),
+                None => None,
+            };
+
+            customers.push(PortfolioEntry {
+                name: name.to_string(),
+                days,
+                content,
+            });
+        }
+
+        Ok(Self {
+            customers,
+            ..Self::default()
+        })
+    }
+}
+
+/// Split a CSV line into fields, honouring double-quoted values
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+
+    fields
+}
+
+/// Options applied to every customer in a batch run
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// Number of customers to extract concurrently
+    pub parallel: Option<usize>,
+    /// Default days back from the command line
+    pub days: Option<u32>,
+    /// Default content type from the command line
+    pub content_type: Option<ContentType>,
+    /// Whether to fetch full email bodies
+    pub fetch_email_bodies: bool,
+}
+
+/// Outcome of one customer in a batch run
+#[derive(Debug, Clone)]
+pub struct BatchOutcome {
+    /// Customer name as listed in the portfolio
+    pub name: String,
+    /// Days back that were searched
+    pub days: u32,
+    /// Extraction result or error message
+    pub result: std::result::Result<CustomerExtraction, String>,
+}
+
+impl BatchOutcome {
+    /// Whether this customer was extracted successfully
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Summary for a finished customer, or a warning for a failed one
+    fn event(&self) -> Event {
+        match &self.result {
+            Ok(extraction) => Event::Summary {
+                customer: Some(extraction.resolved_name.clone()),
+                calls: extraction.calls.len(),
+                emails: extraction.emails.len(),
+                files: extraction.saved_files.len(),
+                output_dir: extraction
+                    .saved_files
+                    .first()
+                    .and_then(|f| f.parent())
+                    .map(|dir| dir.to_path_buf()),
+            },
+            Err(message) => Event::Warning {
+                message: format!("Extraction failed for '{}': {message}", self.name),
+            },
+        }
+    }
+}
+
+/// Run every portfolio entry against an already set up extractor
+///
+/// Customers are processed `parallel` at a time. A failure for one customer is
+/// recorded in its outcome and does not stop the rest of the batch. Outcomes are
+/// returned in portfolio order.
+pub async fn run_batch(
+    extractor: &TeamCallsExtractor,
+    portfolio: &Portfolio,
+    options: &BatchOptions,
+) -> Vec<BatchOutcome> {
+    let parallel = options
+        .parallel
+        .or(portfolio.parallel)
+        .unwrap_or(DEFAULT_BATCH_PARALLELISM)
+        .max(1);
+
+    info!(
+        customers = portfolio.customers.len(),
+        parallel = parallel,
+        "Starting batch extraction"
+    );
+
+    stream::iter(portfolio.customers.iter())
+        .map(|entry| {
+            // Entry values win, then command line flags, then file-wide defaults
+            let days = entry
+                .days
+                .or(options.days)
+                .or(portfolio.days)
+                .unwrap_or(90);
+            let content_type = entry
+                .content
+                .or(options.content_type)
+                .or(portfolio.content)
+                .unwrap_or(ContentType::Both);
+
+            async move {
+                status(
+                    format!("Starting batch extraction for '{}'...", entry.name)
+                        .truecolor(255, 142, 100)
+                );
+
+                let result = extractor
+                    .extract_and_save_customer(
+                        &entry.name,
+                        days,
+                        content_type,
+                        content_type == ContentType::Emails,
+                        options.fetch_email_bodies,
+                    )
+                    .await
+                    .map_err(|e| {
+                        error!(customer = %entry.name, error = %e, "Batch extraction failed for customer");
+                        e.to_string()
+                    });
+
+                let outcome = BatchOutcome {
+                    name: entry.name.clone(),
+                    days,
+                    result,
+                };
+                emit(outcome.event());
+                outcome
+            }
+        })
+        .buffered(parallel)
+        .collect()
+        .await
+}
+
+/// Error when any customer in the batch failed
+fn batch_result(outcomes: &[BatchOutcome]) -> Result<()> {
+    let failed = outcomes.iter().filter(|o| !o.is_success()).count();
+    if failed > 0 {
+        return Err(CsCliError::Generic(format!(
+            "{failed} of {} customers failed during batch extraction",
+            outcomes.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Print a consolidated status table for a batch run
+pub fn print_batch_summary(outcomes: &[BatchOutcome]) {
+    const HEADERS: [&str; 6] = ["Customer", "Days", "Status", "Calls", "Emails", "Files"];
+
+    let rows: Vec<[String; 6]> = outcomes
+        .iter()
+        .map(|outcome| match &outcome.result {
+            Ok(extraction) => [
+                if extraction.resolved_name != outcome.name {
+                    format!("{} ({})", outcome.name, extraction.resolved_name)
+                } else {
+                    outcome.name.clone()
+                },
+                outcome.days.to_string(),
+                "ok".to_string(),
+                extraction.calls.len().to_string(),
+                extraction.emails.len().to_string(),
+                extraction.saved_files.len().to_string(),
+            ],
+            Err(_) => [
+                outcome.name.clone(),
+                outcome.days.to_string(),
+                "failed".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+            ],
+        })
+        .collect();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    status("");
+    status("Batch Extraction Complete!".bold().truecolor(255, 108, 55));
+    status(
+        format_row(&HEADERS.map(String::from)).bold()
+    );
+
+    for (row, outcome) in rows.iter().zip(outcomes) {
+        let line = format_row(row);
+        if outcome.is_success() {
+            status(line.green());
+        } else {
+            status(line.red());
+        }
+    }
+
+    let failures: Vec<&BatchOutcome> = outcomes.iter().filter(|o| !o.is_success()).collect();
+    if !failures.is_empty() {
+        status("");
+        status("Failures:".red().bold());
+        for outcome in failures {
+            if let Err(message) = &outcome.result {
+                status(format!("  {}: {}", outcome.name, message));
+            }
+        }
+    }
+
+    let succeeded = outcomes.len() - outcomes.iter().filter(|o| !o.is_success()).count();
+    status("");
+    status(format!(
+        "{}/{} customers extracted successfully",
+        succeeded,
+        outcomes.len()
+    ));
+}
+
+/// Load a portfolio, run it with one shared extractor and report the results
+///
+/// Returns an error when any customer failed so the process exits non-zero.
+pub async fn execute_batch(
+    file: &Path,
+    options: BatchOptions,
+    mut extractor: TeamCallsExtractor,
+) -> Result<()> {
+    // Validate the portfolio before paying for authentication
+    let portfolio = Portfolio::load(file)?;
+
+    status(
+        format!(
+            "Loaded {} customers from {}",
+            portfolio.customers.len(),
+            file.display()
+        )
+        .cyan()
+    );
+
+    extractor.setup().await?;
+
+    let outcomes = run_batch(&extractor, &portfolio, &options).await;
+    print_batch_summary(&outcomes);
+
+    extractor.cleanup().await;
+
+    batch_result(&outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::api::backend::{
+        AccountCommunications, CallTranscript, GongBackend, ResolvedCustomer,
+    };
+    use crate::gong::config::AppConfig;
+    use crate::gong::models::{Call, CallDirection, Email};
+    use async_trait::async_trait;
+    use jiff::Zoned;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Backend with one call per customer that fails for "Broken" and
+    /// tracks how many customers it resolves at once
+    #[derive(Default)]
+    struct FakeBackend {
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl GongBackend for FakeBackend {
+        fn name(&self) -> &'static str {
+            "fake"
+        }
+
+        fn supports_emails(&self) -> bool {
+            true
+        }
+
+        async fn resolve_customer(
+            &self,
+            name: &str,
+            _start: &Zoned,
+            _end: &Zoned,
+        ) -> Result<ResolvedCustomer> {
+            let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(running, Ordering::SeqCst);
+            // Earlier customers finish last, so output order is not finish order
+            let delay = match name {
+                "Acme" => 40,
+                "Broken" => 20,
+                _ => 5,
+            };
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            if name == "Broken" {
+                return Err(CsCliError::NoData(format!("No customer matching '{name}'")));
+            }
+            Ok(ResolvedCustomer {
+                name: format!("{name} Inc"),
+                account_ids: vec![format!("acc-{name}")],
+            })
+        }
+
+        async fn account_communications(
+            &self,
+            account_id: &str,
+            _start: &Zoned,
+            _end: &Zoned,
+        ) -> Result<AccountCommunications> {
+            Ok(AccountCommunications {
+                calls: vec![Call::new(
+                    format!("call-{account_id}"),
+                    account_id.to_string(),
+                    "Kickoff".to_string(),
+                    CallDirection::Outbound,
+                    1800,
+                    Zoned::now(),
+                )],
+                ..Default::default()
+            })
+        }
+
+        async fn call_transcript(&self, _call: &Call) -> Result<Option<CallTranscript>> {
+            Ok(None)
+        }
+
+        async fn enhance_emails(&self, emails: Vec<Email>) -> Result<Vec<Email>> {
+            Ok(emails)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_continues_past_failures() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-batch-{}", std::process::id()));
+        let backend = Arc::new(FakeBackend::default());
+        let mut extractor =
+            TeamCallsExtractor::new(AppConfig::default()).with_output_dir(dir.clone());
+        extractor.backend = Some(backend.clone());
+
+        let portfolio = Portfolio {
+            customers: ["Acme", "Broken", "Globex", "Initech"]
+                .map(|name| PortfolioEntry {
+                    name: name.to_string(),
+                    days: None,
+                    content: None,
+                })
+                .to_vec(),
+            ..Portfolio::default()
+        };
+        let options = BatchOptions {
+            parallel: Some(2),
+            days: Some(30),
+            content_type: None,
+            fetch_email_bodies: false,
+        };
+
+        let outcomes = run_batch(&extractor, &portfolio, &options).await;
+
+        let names: Vec<&str> = outcomes.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, ["Acme", "Broken", "Globex", "Initech"]);
+        assert_eq!(backend.peak.load(Ordering::SeqCst), 2);
+
+        let succeeded: Vec<bool> = outcomes.iter().map(BatchOutcome::is_success).collect();
+        assert_eq!(succeeded, [true, false, true, true]);
+        let extraction = outcomes[2].result.as_ref().unwrap();
+        assert_eq!(extraction.resolved_name, "Globex Inc");
+        assert_eq!(extraction.calls.len(), 1);
+
+        match outcomes[1].event() {
+            Event::Warning { message } => {
+                assert!(
+                    message.starts_with("Extraction failed for 'Broken'"),
+                    "{message}"
+                );
+            }
+            event => panic!("Expected a warning, got {event:?}"),
+        }
+        assert!(matches!(outcomes[0].event(), Event::Summary { .. }));
+
+        let error = batch_result(&outcomes).unwrap_err();
+        assert!(
+            error.to_string().contains("1 of 4 customers failed"),
+            "{error}"
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_toml_portfolio() {
+        let portfolio = Portfolio::from_toml_str(
+            r#"
+            days = 60
+            parallel = 5
+
+            [[customers]]
+            name = "Wells Fargo"
+            days = 30
+
+            [[customers]]
+            name = "7-11"
+            content = "emails"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(portfolio.days, Some(60));
+        assert_eq!(portfolio.parallel, Some(5));
+        assert_eq!(portfolio.customers.len(), 2);
+        assert_eq!(portfolio.customers[0].days, Some(30));
+        assert_eq!(portfolio.customers[1].content, Some(ContentType::Emails));
+    }
+
+    #[test]
+    fn test_csv_portfolio_with_header_and_quotes() {
+        let portfolio = Portfolio::from_csv_str(
+            "name,days,content\n\"Acme, Inc.\",30,calls\nPostman,,\n# comment\n",
+        )
+        .unwrap();
+
+        assert_eq!(portfolio.customers.len(), 2);
+        assert_eq!(portfolio.customers[0].name, "Acme, Inc.");
+        assert_eq!(portfolio.customers[0].days, Some(30));
+        assert_eq!(portfolio.customers[0].content, Some(ContentType::Calls));
+        assert_eq!(portfolio.customers[1].name, "Postman");
+        assert_eq!(portfolio.customers[1].days, None);
+    }
+
+    #[test]
+    fn test_csv_portfolio_invalid_days() {
+        assert!(Portfolio::from_csv_str("Postman,abc\n").is_err());
+    }
+}
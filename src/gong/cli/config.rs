@@ -0,0 +1,261 @@
+//! `cs-cli config` and config file migration
+//!
+//! Settings live in layered TOML files (see [`crate::common::config::layered`]).
+//! This module prints and edits them, and moves the team stream ID out of the
+//! old `~/.cs-cli-config.json` on first run.
+
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+use super::ConfigAction;
+use crate::common::config::{
+    get_key, read_config_file, set_key_in_file, ConfigSources, CONFIG_ENV_PREFIX,
+    PROJECT_CONFIG_FILE,
+};
+use crate::common::events::status;
+use crate::gong::config::AppConfig;
+use crate::{CsCliError, Result};
+
+/// Settings that are masked in `config show` and `config get`
+const SECRET_KEYS: &[&str] = &["backend.access_key", "backend.access_key_secret"];
+
+/// Config file written before settings moved to `config.toml`
+#[derive(Debug, Deserialize, Default)]
+struct LegacyConfig {
+    team_call_stream_id: Option<String>,
+}
+
+fn legacy_config_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".cs-cli-config.json"))
+}
+
+/// Move settings from `~/.cs-cli-config.json` into the user config file
+///
+/// The old file is kept as `.cs-cli-config.json.bak`. Failures are logged and
+/// never stop the CLI from running.
+pub fn migrate_legacy_config() {
+    let (Some(legacy), Some(user)) = (legacy_config_path(), ConfigSources::discover().user) else {
+        return;
+    };
+    if !legacy.is_file() {
+        return;
+    }
+
+    if let Err(e) = migrate_legacy_file(&legacy, &user) {
+        warn!(error = %e, path = %legacy.display(), "Failed to migrate old config file");
+    }
+}
+
+fn migrate_legacy_file(legacy: &Path, user: &Path) -> Result<()> {
+    let contents = fs::read_to_string(legacy)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to read {}: {e}", legacy.display())))?;
+    let old: LegacyConfig = serde_json::from_str(&contents).unwrap_or_default();
+
+    if let Some(stream_id) = old.team_call_stream_id {
+        let already_set =
+            user.is_file() && get_key(&read_config_file(user)?, "team.call_stream_id").is_some();
+        if !already_set {
+            set_key_in_file(
+                user,
+                "team.call_stream_id",
+                &stream_id,
+                &AppConfig::schema(),
+            )?;
+        }
+    }
+
+    let backup = legacy.with_extension("json.bak");
+    fs::rename(legacy, &backup)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to rename {}: {e}", legacy.display())))?;
+
+    status(
+        format!(
+            "Moved settings from {} to {}",
+            legacy.display(),
+            user.display()
+        )
+        .truecolor(255, 142, 100),
+    );
+    Ok(())
+}
+
+/// Remember the team call stream ID in the user config file
+pub fn save_team_stream_id(stream_id: &str) -> Result<()> {
+    let user = ConfigSources::discover().user.ok_or_else(|| {
+        CsCliError::Configuration("Could not find the user config directory".to_string())
+    })?;
+    set_key_in_file(
+        &user,
+        "team.call_stream_id",
+        stream_id,
+        &AppConfig::schema(),
+    )
+}
+
+/// Run a `cs-cli config` subcommand
+pub fn execute_config(action: ConfigAction) -> Result<()> {
+    let sources = ConfigSources::discover();
+
+    match action {
+        ConfigAction::Show { json } => {
+            let config = masked(&AppConfig::load_from(&sources)?)?;
+            let rendered = if json {
+                serde_json::to_string_pretty(&config)
+                    .map_err(|e| CsCliError::Generic(format!("Failed to render config: {e}")))?
+            } else {
+                // TOML has no null, so unset settings are left out
+                toml::to_string_pretty(&without_nulls(config))
+                    .map_err(|e| CsCliError::Generic(format!("Failed to render config: {e}")))?
+            };
+            println!("{}", rendered.trim_end());
+        }
+        ConfigAction::Get { key } => {
+            let config = masked(&AppConfig::load_from(&sources)?)?;
+            match get_key(&config, &key) {
+                Some(Value::String(value)) => println!("{value}"),
+                Some(Value::Null) => println!(),
+                Some(value) => println!("{value}"),
+                None => {
+                    return Err(CsCliError::InvalidArguments {
+                        message: format!("Unknown setting '{key}' (see `cs-cli config show`)"),
+                    })
+                }
+            }
+        }
+        ConfigAction::Set {
+            key,
+            value,
+            project,
+        } => {
+            let path = if project {
+                sources
+                    .project
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from(PROJECT_CONFIG_FILE))
+            } else {
+                sources.user.clone().ok_or_else(|| {
+                    CsCliError::Configuration(
+                        "Could not find the user config directory".to_string(),
+                    )
+                })?
+            };
+
+            // Keep the old file so an invalid value can be rolled back
+            let previous = fs::read_to_string(&path).ok();
+            set_key_in_file(&path, &key, &value, &AppConfig::schema())?;
+
+            let written = ConfigSources {
+                project: if project {
+                    Some(path.clone())
+                } else {
+                    sources.project.clone()
+                },
+                ..sources
+            };
+            if let Err(e) = AppConfig::load_from(&written) {
+                let _ = match previous {
+                    Some(contents) => fs::write(&path, contents),
+                    None => fs::remove_file(&path),
+                };
+                return Err(e);
+            }
+
+            status(format!("Saved {key} in {}", path.display()).green());
+        }
+        ConfigAction::Path => {
+            let describe = |path: &Option<PathBuf>| match path {
+                Some(path) if path.is_file() => path.display().to_string(),
+                Some(path) => format!("{} (not created yet)", path.display()),
+                None => "(none)".to_string(),
+            };
+            println!("user:    {}", describe(&sources.user));
+            println!("project: {}", describe(&sources.project));
+            println!("env:     {CONFIG_ENV_PREFIX}* (sections separated by __) and GONG_*");
+        }
+        ConfigAction::Validate => {
+            AppConfig::load_from(&sources)?;
+            let files = sources.files();
+            if files.is_empty() {
+                status("No config files found; using defaults".green());
+            }
+            for path in files {
+                status(format!("✓ {}", path.display()).green());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Effective configuration as JSON with credentials hidden
+fn masked(config: &AppConfig) -> Result<Value> {
+    let mut value = serde_json::to_value(config)
+        .map_err(|e| CsCliError::Generic(format!("Failed to render config: {e}")))?;
+
+    for key in SECRET_KEYS {
+        let (section, leaf) = key.split_once('.').expect("secret keys are dotted");
+        if let Some(entry) = value.get_mut(section).and_then(|s| s.get_mut(leaf)) {
+            if entry.is_string() {
+                *entry = Value::String("********".to_string());
+            }
+        }
+    }
+
+    Ok(value)
+}
+
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(entries) => Value::Object(
+            entries
+                .into_iter()
+                .filter(|(_, entry)| !entry.is_null())
+                .map(|(key, entry)| (key, without_nulls(entry)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masked_hides_credentials() {
+        let mut config = AppConfig::create_default();
+        config.backend.access_key_secret = Some("s3cret".to_string());
+
+        let value = masked(&config).unwrap();
+        assert_eq!(
+            get_key(&value, "backend.access_key_secret"),
+            Some(&Value::from("********"))
+        );
+        assert_eq!(get_key(&value, "backend.access_key"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn test_migrate_legacy_file() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-migrate-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let legacy = dir.join(".cs-cli-config.json");
+        let user = dir.join("cs-cli").join("config.toml");
+        fs::write(&legacy, r#"{"team_call_stream_id": "12345"}"#).unwrap();
+
+        migrate_legacy_file(&legacy, &user).unwrap();
+
+        assert!(!legacy.exists());
+        assert!(dir.join(".cs-cli-config.json.bak").exists());
+        let written = read_config_file(&user).unwrap();
+        assert_eq!(
+            get_key(&written, "team.call_stream_id"),
+            Some(&Value::from("12345"))
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
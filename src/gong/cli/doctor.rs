@@ -0,0 +1,456 @@
+//! `cs-cli doctor` diagnostics
+//!
+//! Steps through the same pipeline as `GongAuthenticator::authenticate` one stage
+//! at a time and records a pass/fail row for each, with a remediation hint, so a
+//! failed login can be diagnosed (or attached to a support ticket as JSON)
+//! instead of ending in "No valid Gong browser session detected".
+
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::common::auth::{
+    discover_profiles, BrowserKind, BrowserSelection, CookieCandidate, CookieExtractor,
+    CookieImportSource, CookieImporter,
+};
+use crate::gong::api::client::HttpClientPool;
+use crate::gong::api::customer::GongCustomerSearchClient;
+use crate::gong::auth::{gong_cookie_domains, GongAuthenticator};
+use crate::gong::config::{AppConfig, BackendKind};
+use crate::{CsCliError, Result};
+
+/// Outcome of a single diagnostic check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+    /// Not run because an earlier check failed
+    Skip,
+}
+
+impl std::fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckStatus::Pass => write!(f, "PASS"),
+            CheckStatus::Warn => write!(f, "WARN"),
+            CheckStatus::Fail => write!(f, "FAIL"),
+            CheckStatus::Skip => write!(f, "SKIP"),
+        }
+    }
+}
+
+/// One row of the doctor report
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticCheck {
+    /// Short check name
+    pub name: String,
+    pub status: CheckStatus,
+    /// What was found
+    pub detail: String,
+    /// What to do about a warning or failure
+    pub remediation: Option<String>,
+}
+
+impl DiagnosticCheck {
+    fn new(name: &str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            detail: detail.into(),
+            remediation: None,
+        }
+    }
+
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Pass, detail)
+    }
+
+    fn warn(name: &str, detail: impl Into<String>, remediation: &str) -> Self {
+        Self::new(name, CheckStatus::Warn, detail).with_remediation(remediation)
+    }
+
+    fn fail(name: &str, detail: impl Into<String>, remediation: &str) -> Self {
+        Self::new(name, CheckStatus::Fail, detail).with_remediation(remediation)
+    }
+
+    fn skip(name: &str) -> Self {
+        Self::new(name, CheckStatus::Skip, "Skipped because an earlier check failed")
+    }
+
+    fn with_remediation(mut self, remediation: &str) -> Self {
+        self.remediation = Some(remediation.to_string());
+        self
+    }
+}
+
+/// Full doctor report
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorReport {
+    /// cs-cli version
+    pub version: String,
+    /// Operating system and architecture
+    pub platform: String,
+    /// When the report was generated (RFC 3339)
+    pub generated_at: String,
+    pub checks: Vec<DiagnosticCheck>,
+}
+
+impl DoctorReport {
+    fn new() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            platform: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+            generated_at: jiff::Timestamp::now().to_string(),
+            checks: Vec::new(),
+        }
+    }
+
+    /// Number of failed checks
+    pub fn failures(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|c| c.status == CheckStatus::Fail)
+            .count()
+    }
+
+    /// Report as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| CsCliError::Generic(format!("Failed to serialize doctor report: {e}")))
+    }
+
+    /// Print the report as a table
+    pub fn print_table(&self) {
+        println!();
+        println!(
+            "{}",
+            format!("CS-CLI Doctor (v{}, {})", self.version, self.platform).truecolor(255, 108, 55)
+        );
+        println!();
+
+        let name_width = self.checks.iter().map(|c| c.name.len()).max().unwrap_or(5).max(5);
+
+        for check in &self.checks {
+            let status = match check.status {
+                CheckStatus::Pass => check.status.green().to_string(),
+                CheckStatus::Warn => check.status.yellow().to_string(),
+                CheckStatus::Fail => check.status.red().to_string(),
+                CheckStatus::Skip => check.status.dimmed().to_string(),
+            };
+            println!("  {status}  {:<name_width$}  {}", check.name, check.detail);
+            if let Some(remediation) = &check.remediation {
+                println!("        {:<name_width$}  {}", "", format!("-> {remediation}").cyan());
+            }
+        }
+
+        println!();
+        match self.failures() {
+            0 => println!("{}", "All checks passed".green()),
+            n => println!("{}", format!("{n} check(s) failed").red()),
+        }
+    }
+}
+
+/// Run every diagnostic check against the configured auth pipeline
+pub async fn run_doctor(config: &AppConfig) -> Result<DoctorReport> {
+    let mut report = DoctorReport::new();
+
+    if config.backend.kind == BackendKind::Api {
+        report.checks.push(DiagnosticCheck::warn(
+            "Backend",
+            "Public API backend selected; browser checks below apply to the web backend only",
+            "Unset GONG_BACKEND or pass --backend web to diagnose the browser login",
+        ));
+    }
+
+    // 1. Cookie sources: browsers/profiles, or the configured import
+    let candidates = match &config.auth.cookie_import {
+        Some(spec) => check_cookie_import(spec, &mut report),
+        None => check_browsers(config, &mut report),
+    };
+
+    // 2. Cell detection
+    let mut auth = GongAuthenticator::new(config.auth.clone()).await?;
+    let Some(candidate) = candidates.first() else {
+        for name in ["Cell", "CSRF token", "Workspace", "HTTP version", "Sample search"] {
+            report.checks.push(DiagnosticCheck::skip(name));
+        }
+        return Ok(report);
+    };
+
+    match auth.detect_cell(&candidate.cookies) {
+        Ok((cell, strategy)) => report.checks.push(DiagnosticCheck::pass(
+            "Cell",
+            format!("{cell} (strategy: {strategy}, from {})", candidate.source),
+        )),
+        Err(e) => {
+            report.checks.push(DiagnosticCheck::fail(
+                "Cell",
+                e.to_string(),
+                "The Gong 'cell' cookie is missing; open Gong in the browser once so it is set again",
+            ));
+            for name in ["CSRF token", "Workspace", "HTTP version", "Sample search"] {
+                report.checks.push(DiagnosticCheck::skip(name));
+            }
+            return Ok(report);
+        }
+    }
+
+    // 3. CSRF fetch against /ajax/common/rtkn, trying candidates freshest first
+    let mut accepted = None;
+    for candidate in &candidates {
+        if let Ok(true) = auth.try_cookie_candidate(candidate).await {
+            accepted = Some(candidate);
+            break;
+        }
+    }
+
+    let Some(accepted) = accepted else {
+        report.checks.push(DiagnosticCheck::fail(
+            "CSRF token",
+            format!("/ajax/common/rtkn rejected all {} session(s)", candidates.len()),
+            "Your Gong session has expired: log out and back into Gong in the browser, then run cs-cli --fresh-login",
+        ));
+        for name in ["Workspace", "HTTP version", "Sample search"] {
+            report.checks.push(DiagnosticCheck::skip(name));
+        }
+        return Ok(report);
+    };
+    report.checks.push(DiagnosticCheck::pass(
+        "CSRF token",
+        format!("Obtained from /ajax/common/rtkn using {}", accepted.source),
+    ));
+
+    // 4. Workspace extraction
+    match auth.extract_workspace_id().await {
+        Ok(Some(workspace_id)) => report
+            .checks
+            .push(DiagnosticCheck::pass("Workspace", format!("Workspace ID {workspace_id}"))),
+        Ok(None) => report.checks.push(DiagnosticCheck::warn(
+            "Workspace",
+            "No workspace ID found on the Gong home page",
+            "Some searches may fail; make sure your Gong user has access to a workspace",
+        )),
+        Err(e) => report.checks.push(DiagnosticCheck::warn(
+            "Workspace",
+            e.to_string(),
+            "Some searches may fail; check network access to the Gong home page",
+        )),
+    }
+
+    // 5. HTTP version negotiation and 6. a sample search, through the real client pool
+    let http = Arc::new(HttpClientPool::new(Some(config.http.clone())).await?);
+    http.set_cookies(auth.get_session_cookies()?).await?;
+    let base_url = auth.get_base_url()?.to_string();
+
+    report.checks.push(check_http_version(config, &http, &base_url).await);
+
+    let auth = Arc::new(auth);
+    let search = GongCustomerSearchClient::new(http, auth, Some(config.clone()))?;
+    report.checks.push(match search.search_customers("a").await {
+        Ok(results) if !results.is_empty() => DiagnosticCheck::pass(
+            "Sample search",
+            format!("Customer search returned {} result(s)", results.len()),
+        ),
+        Ok(_) => DiagnosticCheck::warn(
+            "Sample search",
+            "Customer search returned no results",
+            "Check that your Gong user can see accounts in Gong's own search",
+        ),
+        Err(e) => DiagnosticCheck::fail(
+            "Sample search",
+            e.to_string(),
+            "Run with --debug and attach the output to a support ticket",
+        ),
+    });
+
+    Ok(report)
+}
+
+/// Browser and profile discovery plus per-profile cookie counts
+fn check_browsers(config: &AppConfig, report: &mut DoctorReport) -> Vec<CookieCandidate> {
+    let selection = match BrowserSelection::from_names(
+        config.auth.browser.as_deref(),
+        config.auth.browser_profile.as_deref(),
+    ) {
+        Ok(selection) => selection,
+        Err(e) => {
+            report.checks.push(DiagnosticCheck::fail(
+                "Browsers",
+                e.to_string(),
+                "Fix --browser/--browser-profile (or GONG_BROWSER/GONG_BROWSER_PROFILE)",
+            ));
+            return Vec::new();
+        }
+    };
+
+    let profiles: Vec<String> = BrowserKind::ALL
+        .into_iter()
+        .filter(|b| b.is_supported_on_platform())
+        .filter(|b| selection.browser.is_none() || selection.browser == Some(*b))
+        .flat_map(discover_profiles)
+        .map(|p| p.label())
+        .collect();
+
+    report.checks.push(if profiles.is_empty() {
+        DiagnosticCheck::warn(
+            "Browsers",
+            "No browser profiles found on disk",
+            "Install a supported browser, or import cookies with --cookies",
+        )
+    } else {
+        DiagnosticCheck::pass(
+            "Browsers",
+            format!("{} profile(s): {}", profiles.len(), profiles.join(", ")),
+        )
+    });
+
+    let extractor = CookieExtractor::new(gong_cookie_domains());
+    match extractor.collect_candidates(&selection) {
+        Ok(candidates) => {
+            report.checks.push(cookie_check(&candidates));
+            candidates
+        }
+        Err(e) => {
+            report.checks.push(DiagnosticCheck::fail(
+                "Cookies",
+                e.to_string(),
+                "Log into Gong in your browser (on macOS, allow keychain access when asked), or use --cookies",
+            ));
+            Vec::new()
+        }
+    }
+}
+
+/// Cookies from a cookies.txt, HAR or raw header import
+fn check_cookie_import(spec: &str, report: &mut DoctorReport) -> Vec<CookieCandidate> {
+    let imported = CookieImportSource::parse(spec).and_then(|source| {
+        let cookies = CookieImporter::new(gong_cookie_domains()).import(&source)?;
+        Ok(CookieCandidate::new(source.label(), cookies))
+    });
+
+    match imported {
+        Ok(candidate) => {
+            report.checks.push(DiagnosticCheck::pass(
+                "Browsers",
+                format!("Skipped, using {}", candidate.source),
+            ));
+            let candidates = vec![candidate];
+            report.checks.push(cookie_check(&candidates));
+            candidates
+        }
+        Err(e) => {
+            report.checks.push(DiagnosticCheck::fail(
+                "Cookies",
+                e.to_string(),
+                "Export cookies again while logged into Gong (cookies.txt, HAR, or a Cookie header)",
+            ));
+            Vec::new()
+        }
+    }
+}
+
+/// Summarize cookie counts and expiry for each candidate
+fn cookie_check(candidates: &[CookieCandidate]) -> DiagnosticCheck {
+    let summary = candidates
+        .iter()
+        .map(|c| {
+            let expiry = c
+                .latest_expiry
+                .and_then(|e| jiff::Timestamp::from_second(e as i64).ok())
+                .map(|ts| format!("expires {}", ts.strftime("%Y-%m-%d %H:%M UTC")))
+                .unwrap_or_else(|| "session cookies".to_string());
+            format!("{}: {} cookies, {expiry}", c.source, c.cookies.len())
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    if candidates.iter().any(|c| c.cookies.iter().any(|k| k.name == "cell")) {
+        DiagnosticCheck::pass("Cookies", summary)
+    } else {
+        DiagnosticCheck::warn(
+            "Cookies",
+            summary,
+            "No 'cell' cookie found; cell detection will fall back to cookie domains",
+        )
+    }
+}
+
+/// Which HTTP version Gong negotiated with the impersonating client
+async fn check_http_version(config: &AppConfig, http: &HttpClientPool, base_url: &str) -> DiagnosticCheck {
+    let wants_http3 = config.http.enable_http3 || config.http.force_http3;
+
+    match http.get(&format!("{base_url}/ajax/common/rtkn")).await {
+        Ok(response) => {
+            let version = response.version();
+            let detail = format!(
+                "Negotiated {version:?} (HTTP/3 {})",
+                if wants_http3 { "enabled" } else { "disabled" }
+            );
+            if wants_http3 && version != reqwest::Version::HTTP_3 {
+                DiagnosticCheck::warn(
+                    "HTTP version",
+                    detail,
+                    "HTTP/3 fell back to HTTP/2; this is harmless, but UDP may be blocked on this network",
+                )
+            } else {
+                DiagnosticCheck::pass("HTTP version", detail)
+            }
+        }
+        Err(e) => DiagnosticCheck::fail(
+            "HTTP version",
+            e.to_string(),
+            "Check network/VPN/proxy access to *.app.gong.io",
+        ),
+    }
+}
+
+/// Run doctor, print the table and optionally export JSON
+pub async fn execute_doctor(config: &AppConfig, json: bool, output: Option<&Path>) -> Result<()> {
+    let report = run_doctor(config).await?;
+
+    if json {
+        println!("{}", report.to_json()?);
+    } else {
+        report.print_table();
+    }
+
+    if let Some(path) = output {
+        std::fs::write(path, report.to_json()?).map_err(|e| {
+            CsCliError::FileIo(format!("Failed to write doctor report to {}: {e}", path.display()))
+        })?;
+        if !json {
+            println!(
+                "{}",
+                format!("Report saved to {} (attach it to your support ticket)", path.display()).green()
+            );
+        }
+    }
+
+    match report.failures() {
+        0 => Ok(()),
+        n => Err(CsCliError::Authentication(format!("doctor found {n} failing check(s)"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_json_includes_remediation() {
+        let mut report = DoctorReport::new();
+        report.checks.push(DiagnosticCheck::pass("Cell", "us-14496"));
+        report.checks.push(DiagnosticCheck::fail("CSRF token", "401", "Log in again"));
+
+        assert_eq!(report.failures(), 1);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["checks"][0]["status"], "pass");
+        assert_eq!(json["checks"][1]["status"], "fail");
+        assert_eq!(json["checks"][1]["remediation"], "Log in again");
+    }
+}
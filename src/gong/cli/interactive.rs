@@ -57,6 +57,7 @@ pub fn interactive_mode() -> Result<ParsedCommand> {
         content_type,
         emails_only,
         fetch_email_bodies,
+        slack: false,
     })
 }
 
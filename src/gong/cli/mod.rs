@@ -4,30 +4,49 @@
 //! coordinating argument parsing, interactive mode, and extraction workflows.
 
 pub mod args;
+pub mod batch;
+pub mod config;
+pub mod doctor;
 pub mod interactive;
+pub mod progress;
+pub mod render;
+pub mod templates;
 
 pub use args::*;
+pub use batch::*;
+pub use config::*;
+pub use doctor::*;
 pub use interactive::*;
+pub use progress::*;
+pub use render::*;
+pub use templates::*;
+
+pub use crate::gong::extractor::CustomerExtraction;
 
 use owo_colors::OwoColorize;
-use indicatif::{ProgressBar, ProgressStyle};
-use serde::{Deserialize, Serialize};
-use std::fs;
+use std::io::IsTerminal;
 use std::path::PathBuf;
 use std::sync::Arc;
-use tracing::{info, warn};
 
-use crate::common::auth::unlock_keychain_with_cli_password;
-use crate::gong::api::client::HttpClientPool;
+use crate::common::auth::{
+    is_keychain_unlocked, unlock_keychain_from, BrowserSelection, CookieImportSource,
+    KeychainPasswordSource,
+};
+use crate::common::events::{emit, init_events, status, warning, Event, EventFormat, Stage};
+use crate::gong::api::backend::{GongBackend, WebSessionBackend};
 use crate::gong::api::customer::CustomerCallInfo;
 use crate::gong::api::customer::GongCustomerSearchClient;
-use crate::gong::api::email::EmailEnhancer;
 use crate::gong::api::library::{CallDetailsFetcher, GongLibraryClient, LibraryCallInfo};
-use crate::gong::api::timeline::TimelineExtractor;
-use crate::gong::auth::GongAuthenticator;
-use crate::gong::config::AppConfig;
-use crate::gong::models::{Call, CallDirection, Email};
+use crate::gong::api::public_api::PublicApiBackend;
+use crate::gong::config::{AppConfig, BackendKind, OutputFormat};
+use crate::gong::extractor::{
+    open_web_session, ActivityCsvSink, BriefSink, ExtractRequest, Extractor, HtmlSink, MailboxSink,
+    MarkdownSink, OutputSink, ParquetSink, SessionOptions, SqliteSink, VaultSink, WebSession,
+};
+use crate::gong::models::{Call, CallDirection, Email, EmailFilterDecision};
+use crate::gong::output::manifest::{ExtractionParameters, ManifestWriter, EMAIL_FILTERS};
 use crate::gong::output::markdown::{CallMarkdownFormatter, CallSummaryReporter};
+use crate::gong::output::snapshot::{default_snapshot_dir, with_generated_at, ExtractionSnapshot};
 use crate::Result;
 use clap::{CommandFactory, Parser};
 use clap_complete::{generate, Shell};
@@ -192,69 +211,21 @@ fn convert_customer_call_info_to_call(info: CustomerCallInfo) -> Call {
     }
 }
 
-/// Configuration file structure for persistent settings
-#[derive(Debug, Serialize, Deserialize, Default)]
-pub struct CliConfig {
-    /// Previously used team call stream ID
-    pub team_call_stream_id: Option<String>,
-}
-
-/// Configuration file path
-fn config_file_path() -> PathBuf {
-    dirs::home_dir()
-        .expect("Could not find home directory")
-        .join(".cs-cli-config.json")
-}
-
-/// Load saved configuration from disk
-pub fn load_config() -> CliConfig {
-    let config_path = config_file_path();
-
-    if !config_path.exists() {
-        return CliConfig::default();
-    }
-
-    match fs::read_to_string(&config_path) {
-        Ok(contents) => match serde_json::from_str(&contents) {
-            Ok(config) => config,
-            Err(e) => {
-                warn!("Failed to parse config file: {}, using defaults", e);
-                CliConfig::default()
-            }
-        },
-        Err(e) => {
-            warn!("Failed to read config file: {}, using defaults", e);
-            CliConfig::default()
-        }
-    }
-}
-
-/// Save configuration to disk
-pub fn save_config(config: &CliConfig) -> Result<()> {
-    let config_path = config_file_path();
-
-    let contents = serde_json::to_string_pretty(config).map_err(|e| {
-        crate::CsCliError::Configuration(format!("Failed to serialize config: {e}"))
-    })?;
-
-    fs::write(&config_path, contents)
-        .map_err(|e| crate::CsCliError::FileIo(format!("Failed to save config: {e}")))?;
-
-    Ok(())
-}
-
 /// Main orchestrator for team calls extraction with all API clients
 pub struct TeamCallsExtractor {
     config: AppConfig,
-    http: Option<HttpClientPool>,
-    auth: Option<GongAuthenticator>,
     library_client: Option<GongLibraryClient>,
     details_fetcher: Option<CallDetailsFetcher>,
     customer_search_client: Option<GongCustomerSearchClient>,
-    timeline_extractor: Option<TimelineExtractor>,
-    email_enhancer: Option<EmailEnhancer>,
+    backend: Option<Arc<dyn GongBackend>>,
     formatter: CallMarkdownFormatter,
     summary_reporter: CallSummaryReporter,
+    /// Folder for customer output instead of the Desktop
+    output_dir: Option<PathBuf>,
+    keychain_password: KeychainPasswordSource,
+    fresh_login: bool,
+    /// Where the Gong session came from, recorded in `manifest.json`
+    auth_source: Option<String>,
 }
 
 impl TeamCallsExtractor {
@@ -262,56 +233,79 @@ impl TeamCallsExtractor {
     pub fn new(config: AppConfig) -> Self {
         Self {
             config,
-            http: None,
-            auth: None,
+            keychain_password: KeychainPasswordSource::Skip,
+            fresh_login: false,
             library_client: None,
             details_fetcher: None,
             customer_search_client: None,
-            timeline_extractor: None,
-            email_enhancer: None,
+            backend: None,
             formatter: CallMarkdownFormatter::new(None),
             summary_reporter: CallSummaryReporter::new(),
+            output_dir: None,
+            auth_source: None,
         }
     }
 
-    /// Initialize all components (setup phase)
-    pub async fn setup(&mut self) -> Result<()> {
-        println!("{}", "Setting up CS-CLI extractor...".truecolor(255, 142, 100));
+    /// Where the macOS keychain password comes from if browser extraction is needed
+    pub fn with_keychain_password(mut self, source: KeychainPasswordSource) -> Self {
+        self.keychain_password = source;
+        self
+    }
 
-        // Initialize HTTP client pool
-        self.http = Some(HttpClientPool::new(Some(self.config.http.clone())).await?);
+    /// Save customer folders under `output_dir` instead of the Desktop
+    pub fn with_output_dir(mut self, output_dir: PathBuf) -> Self {
+        self.formatter = CallMarkdownFormatter::new(Some(output_dir.clone()));
+        self.output_dir = Some(output_dir);
+        self
+    }
 
-        // Initialize authenticator and authenticate
-        let mut auth = GongAuthenticator::new(self.config.auth.clone()).await?;
-        let authenticated = auth.authenticate().await?;
-        if !authenticated {
-            return Err(crate::CsCliError::Authentication(
-                "Authentication failed: No valid Gong browser session detected".to_string(),
-            ));
-        }
-        self.auth = Some(auth);
-
-        // Set cookies on all HTTP clients in the pool
-        if let Some(auth) = &self.auth {
-            let auth_state = auth.get_auth_state();
-            if auth_state.get("authenticated") == Some(&"true".to_string()) {
-                if let Some(http) = &self.http {
-                    // Get cookies from auth state and set them on the pool
-                    if let Ok(session_cookies) = auth.get_session_cookies() {
-                        http.set_cookies(session_cookies.clone()).await?;
-                        info!(
-                            cookies_count = session_cookies.len(),
-                            "Cookies set on HTTP client pool"
-                        );
-                    }
-                }
-            }
-        }
+    /// Ignore any persisted session and re-extract browser cookies
+    pub fn with_fresh_login(mut self, fresh_login: bool) -> Self {
+        self.fresh_login = fresh_login;
+        self
+    }
 
-        // Initialize all API clients with Arc wrappers
-        let http_arc = Arc::new(self.http.take().unwrap());
-        let auth_arc = Arc::new(self.auth.take().unwrap());
+    /// Initialize all components (setup phase)
+    pub async fn setup(&mut self) -> Result<()> {
+        status("Setting up CS-CLI extractor...".truecolor(255, 142, 100));
+        emit(Event::StageStarted {
+            stage: Stage::Setup,
+            customer: None,
+        });
+
+        // The public API backend needs no browser session at all
+        if self.config.backend.kind == BackendKind::Api {
+            let backend = PublicApiBackend::new(&self.config.backend, &self.config.http)?;
+            self.backend = Some(Arc::new(backend));
+            self.auth_source = Some("public-api".to_string());
+            status("Using Gong public API backend".truecolor(255, 142, 100));
+            status("Setup complete!".truecolor(255, 255, 255));
+            emit(Event::StageFinished {
+                stage: Stage::Setup,
+                customer: None,
+                count: None,
+            });
+            return Ok(());
+        }
 
+        // Log into Gong, preferring imported cookies, then a persisted session, then the browser
+        let options = SessionOptions {
+            keychain_password: self.keychain_password.clone(),
+            fresh_login: self.fresh_login,
+        };
+        let WebSession {
+            http: http_arc,
+            auth: auth_arc,
+        } = open_web_session(
+            &self.config,
+            &options,
+            &TerminalReporter::new(),
+            &unlock_keychain_if_needed,
+        )
+        .await?;
+        self.auth_source = auth_arc.get_auth_state().get("browser").cloned();
+
+        // Initialize all API clients
         self.library_client = Some(GongLibraryClient::new(
             http_arc.clone(),
             auth_arc.clone(),
@@ -327,20 +321,18 @@ impl TeamCallsExtractor {
             auth_arc.clone(),
             Some(self.config.clone()),
         )?);
-        self.timeline_extractor = Some(TimelineExtractor::new(
-            http_arc.clone(),
-            auth_arc.clone(),
-            Some(self.config.clone()),
-            None,
-        )?);
-        self.email_enhancer = Some(EmailEnhancer::new(
-            http_arc.clone(),
-            auth_arc.clone(),
-            Some(self.config.clone()),
-            None,
-        ));
-
-        println!("{}", "Setup complete!".truecolor(255, 255, 255));
+        self.backend = Some(Arc::new(WebSessionBackend::new(
+            http_arc,
+            auth_arc,
+            &self.config,
+        )?));
+
+        status("Setup complete!".truecolor(255, 255, 255));
+        emit(Event::StageFinished {
+            stage: Stage::Setup,
+            customer: None,
+            count: None,
+        });
         Ok(())
     }
 
@@ -352,6 +344,12 @@ impl TeamCallsExtractor {
         from_date: Option<&str>,
         to_date: Option<&str>,
     ) -> Result<Vec<Call>> {
+        if self.config.backend.kind == BackendKind::Api {
+            return Err(crate::CsCliError::Configuration(
+                "Team call streams are only available with the web backend".to_string(),
+            ));
+        }
+
         let library_client = self.library_client.as_ref().ok_or_else(|| {
             crate::CsCliError::Generic("Library client not initialized".to_string())
         })?;
@@ -368,20 +366,16 @@ impl TeamCallsExtractor {
             format!("last {days_value} days")
         };
 
-        println!(
-            "{}",
+        status(
             format!("Extracting team calls from {date_range_desc}...").cyan()
         );
 
         // Create progress indicator
-        let pb = ProgressBar::new_spinner();
-        pb.set_style(
-            ProgressStyle::default_spinner()
-                .template("{spinner:.green} {msg}")
-                .unwrap(),
-        );
-        pb.set_message("Fetching call stream...");
-        pb.enable_steady_tick(std::time::Duration::from_millis(100));
+        let pb = spinner("Fetching call stream...");
+        emit(Event::StageStarted {
+            stage: Stage::ListCommunications,
+            customer: None,
+        });
 
         // Get all calls with pagination
         let mut all_calls = Vec::new();
@@ -419,21 +413,23 @@ impl TeamCallsExtractor {
         }
 
         pb.finish_with_message(format!("Found {} calls in stream", all_calls.len()));
+        emit(Event::StageFinished {
+            stage: Stage::ListCommunications,
+            customer: None,
+            count: Some(all_calls.len()),
+        });
 
         if all_calls.is_empty() {
-            println!("{}", "No calls found in call stream!".yellow());
+            warning("No calls found in call stream", "No calls found in call stream!".yellow());
             return Ok(Vec::new());
         }
 
         // Enhance calls with detailed information (transcripts)
-        let pb = ProgressBar::new(all_calls.len() as u64);
-        pb.set_style(
-            ProgressStyle::default_bar()
-                .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
-                .unwrap()
-                .progress_chars("#>-"),
-        );
-        pb.set_message("Fetching call details...");
+        let pb = progress_bar(all_calls.len(), "Fetching call details...");
+        emit(Event::StageStarted {
+            stage: Stage::FetchTranscripts,
+            customer: None,
+        });
 
         // Fetch detailed information including transcripts
         let details_fetcher = self.details_fetcher.as_ref().ok_or_else(|| {
@@ -497,11 +493,23 @@ impl TeamCallsExtractor {
                     enhanced_call.title = details.generated_title;
                 }
 
+                emit(Event::CallFetched {
+                    call_id: enhanced_call.id.clone(),
+                    title: enhanced_call.title.clone(),
+                    customer: None,
+                    has_transcript: true,
+                });
                 enhanced_calls.push(enhanced_call);
             } else {
                 // If details fetch failed, keep the original call with no transcript
                 let mut enhanced_call = call.clone();
                 enhanced_call.transcript = Some("No transcript available.".to_string());
+                emit(Event::CallFetched {
+                    call_id: enhanced_call.id.clone(),
+                    title: enhanced_call.title.clone(),
+                    customer: None,
+                    has_transcript: false,
+                });
                 enhanced_calls.push(enhanced_call);
             }
 
@@ -509,9 +517,13 @@ impl TeamCallsExtractor {
         }
 
         pb.finish_with_message("Call details fetching complete");
+        emit(Event::StageFinished {
+            stage: Stage::FetchTranscripts,
+            customer: None,
+            count: Some(enhanced_calls.len()),
+        });
 
-        println!(
-            "{}",
+        status(
             format!(
                 "Successfully extracted {} team calls",
                 enhanced_calls.len()
@@ -545,8 +557,7 @@ impl TeamCallsExtractor {
             format!("last {days_value} days")
         };
 
-        println!(
-            "{}",
+        status(
             format!(
                 "Extracting calls for '{name}' from {date_range_desc}..."
             )
@@ -575,14 +586,11 @@ impl TeamCallsExtractor {
             (None, None)
         };
 
-        let pb = ProgressBar::new_spinner();
-        pb.set_style(
-            ProgressStyle::default_spinner()
-                .template("{spinner:.green} {msg}")
-                .unwrap(),
-        );
-        pb.set_message("Searching for customer...");
-        pb.enable_steady_tick(std::time::Duration::from_millis(100));
+        let pb = spinner("Searching for customer...");
+        emit(Event::StageStarted {
+            stage: Stage::ListCommunications,
+            customer: Some(name.to_string()),
+        });
 
         // Get customer calls with smart pagination
         let mut all_calls = Vec::new();
@@ -605,6 +613,11 @@ impl TeamCallsExtractor {
             if offset == 0 && !response.companies.is_empty() {
                 resolved_name = response.companies[0].clone();
                 pb.set_message(format!("Resolved customer: '{resolved_name}'"));
+                emit(Event::AccountResolved {
+                    query: name.to_string(),
+                    customer: resolved_name.clone(),
+                    account_ids: response.account_ids.clone(),
+                });
             }
 
             if response.calls.is_empty() {
@@ -651,9 +664,21 @@ impl TeamCallsExtractor {
             all_calls.len(),
             resolved_name
         ));
+        emit(Event::StageFinished {
+            stage: Stage::ListCommunications,
+            customer: Some(resolved_name.clone()),
+            count: Some(all_calls.len()),
+        });
+        for call in &all_calls {
+            emit(Event::CallFetched {
+                call_id: call.id.clone(),
+                title: call.title.clone(),
+                customer: Some(resolved_name.clone()),
+                has_transcript: call.transcript.is_some(),
+            });
+        }
 
-        println!(
-            "{}",
+        status(
             format!(
                 "Successfully extracted {} customer calls",
                 all_calls.len()
@@ -664,16 +689,18 @@ impl TeamCallsExtractor {
     }
 
     /// Extract customer communications (calls + emails)
+    ///
+    /// Runs the library [`Extractor`] against the already connected backend,
+    /// with terminal progress output.
     pub async fn extract_customer_communications(
-        &mut self,
+        &self,
         name: &str,
         days: u32,
         include_emails: bool,
         emails_only: bool,
         fetch_email_bodies: bool,
-    ) -> Result<(Vec<Call>, Vec<Email>, String)> {
-        println!(
-            "{}",
+    ) -> Result<CustomerExtraction> {
+        status(
             format!(
                 "Extracting communications for '{name}' from last {days} days..."
             )
@@ -681,205 +708,44 @@ impl TeamCallsExtractor {
         );
 
         if emails_only {
-            println!(
-                "{}",
+            status(
                 "Extracting only emails (calls will be ignored)".yellow()
             );
         } else if include_emails {
-            println!(
-                "{}",
+            status(
                 "Filtering emails to remove blasts and spam".yellow()
             );
         }
 
-        // Step 1: Calculate date range
-        let now = jiff::Zoned::now();
-        let start_date = now
-            .checked_sub(jiff::Span::new().days(days as i64))
-            .unwrap_or(now.clone());
-        let end_date = Some(now);
-
-        // Step 2: Find customer accounts using customer search API
-        let customer_client = self.customer_search_client.as_ref().ok_or_else(|| {
-            crate::CsCliError::Generic("Customer search client not initialized".to_string())
+        let backend = self.backend.as_ref().ok_or_else(|| {
+            crate::CsCliError::Generic("Gong backend not initialized".to_string())
         })?;
 
-        // Get customer calls to extract account IDs
-        let customer_response = customer_client
-            .get_customer_calls(name, 10, 0, false)
+        let extractor = Extractor::builder()
+            .with_backend(backend.clone())
+            .with_reporter(TerminalReporter::new())
+            .build()
             .await?;
 
-        // Capture resolved customer name from response
-        let resolved_customer_name = if !customer_response.companies.is_empty() {
-            customer_response.companies[0].clone()
-        } else {
-            name.to_string()
-        };
+        let request = ExtractRequest::new(name)
+            .with_days(days)
+            .with_calls(!emails_only)
+            .with_emails(include_emails || emails_only)
+            .with_email_bodies(fetch_email_bodies);
 
-        println!(
-            "{}",
-            format!(
-                "Resolved customer name: '{resolved_customer_name}'"
-            )
-            .green()
-        );
-
-        // Get account IDs from the response
-        let account_ids = customer_response.account_ids;
-
-        if account_ids.is_empty() {
-            println!(
-                "{}",
-                format!("No accounts found for customer '{name}'").red()
-            );
-            return Ok((Vec::new(), Vec::new(), resolved_customer_name));
-        }
-
-        println!(
-            "{}",
-            format!(
-                "Found {} accounts for customer '{name}'",
-                account_ids.len()
-            )
-            .green()
-        );
-
-        // Step 3: Use timeline extractor to get communications from these accounts
-        let timeline_extractor = self.timeline_extractor.as_mut().ok_or_else(|| {
-            crate::CsCliError::Generic("Timeline extractor not initialized".to_string())
-        })?;
-
-        let mut all_calls: Vec<Call> = Vec::new();
-        let mut all_emails: Vec<Email> = Vec::new();
-
-        for account_id in &account_ids {
-            match timeline_extractor
-                .extract_account_timeline(account_id, start_date.clone(), end_date.clone())
-                .await
-            {
-                Ok(timeline_result) => {
-                    if !emails_only {
-                        all_calls.extend(timeline_result.calls);
-                    }
-                    if include_emails || emails_only {
-                        all_emails.extend(timeline_result.emails);
-                    }
-                }
-                Err(e) => {
-                    warn!(
-                        account_id = %account_id,
-                        error = %e,
-                        "Failed to extract timeline for account"
-                    );
-                    continue;
-                }
-            }
-        }
-
-        println!(
-            "{}",
-            format!(
-                "Timeline extraction complete: {} calls, {} emails",
-                all_calls.len(),
-                all_emails.len()
-            )
-            .green()
-        );
-
-        // Step 4: Enhance email bodies if requested
-        let mut emails = all_emails;
-        if (include_emails || emails_only) && fetch_email_bodies && !emails.is_empty() {
-            if let Some(email_enhancer) = &self.email_enhancer {
-                println!("{}", "Fetching email body content...".truecolor(255, 142, 100));
-
-                let pb = ProgressBar::new(emails.len() as u64);
-                pb.set_style(
-                    ProgressStyle::default_bar()
-                        .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
-                        .unwrap()
-                        .progress_chars("#>-"),
-                );
-                pb.set_message("Fetching email bodies...");
-
-                emails = email_enhancer
-                    .enhance_emails_with_progress(emails, true)
-                    .await
-                    .map_err(|e| {
-                        crate::CsCliError::Generic(format!("Failed to enhance emails: {e}"))
-                    })?;
+        let extraction = extractor.extract(&request).await?;
 
-                pb.finish_with_message("Email body enhancement complete");
-                println!("{}", "Email body enhancement complete".truecolor(255, 255, 255));
-            }
-        }
-
-        // Step 5: For calls, get detailed information (transcripts) if not emails-only
-        let mut detailed_calls = Vec::new();
-        if !emails_only && !all_calls.is_empty() {
-            let details_fetcher = self.details_fetcher.as_ref().ok_or_else(|| {
-                crate::CsCliError::Generic("Details fetcher not initialized".to_string())
-            })?;
-
-            let pb = ProgressBar::new(all_calls.len() as u64);
-            pb.set_style(
-                ProgressStyle::default_bar()
-                    .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
-                    .unwrap()
-                    .progress_chars("#>-"),
-            );
-            pb.set_message("Fetching call details...");
-
-            for (i, call) in all_calls.iter().enumerate() {
-                pb.set_message(format!(
-                    "Fetching details for call {}/{}",
-                    i + 1,
-                    all_calls.len()
-                ));
-
-                let mut enhanced_call = call.clone();
-
-                // Get detailed call information (mainly for transcript)
-                if let Some(details) = details_fetcher.get_call_details(&call.id).await? {
-                    enhanced_call.transcript = if !details.transcript.is_empty() {
-                        Some(details.transcript)
-                    } else {
-                        Some("No transcript available.".to_string())
-                    };
-
-                    // Add generated title for intelligent file naming
-                    if !details.generated_title.is_empty() {
-                        enhanced_call.generated_title = Some(details.generated_title.clone());
-                        // Also update title if it's generic
-                        if enhanced_call.title == "Call" || enhanced_call.title.is_empty() {
-                            enhanced_call.title = details.generated_title;
-                        }
-                    }
-                } else {
-                    enhanced_call.transcript = Some("No transcript available.".to_string());
-                }
-
-                // Set the resolved customer name on the call
-                enhanced_call.customer_name = Some(resolved_customer_name.clone());
-
-                detailed_calls.push(enhanced_call);
-                pb.inc(1);
-            }
-
-            pb.finish_with_message("Call details fetching complete");
-        }
-
-        println!(
-            "{}",
+        status(
             format!(
                 "Successfully extracted {} calls and {} emails for customer '{}'",
-                detailed_calls.len(),
-                emails.len(),
-                resolved_customer_name
+                extraction.calls.len(),
+                extraction.emails.len(),
+                extraction.resolved_name
             )
             .green()
         );
 
-        Ok((detailed_calls, emails, resolved_customer_name))
+        Ok(extraction)
     }
 
     /// Save calls as markdown files
@@ -892,15 +758,15 @@ impl TeamCallsExtractor {
             return Ok(Vec::new());
         }
 
-        println!("{}", "Generating markdown files...".truecolor(255, 142, 100));
+        status("Generating markdown files...".truecolor(255, 142, 100));
 
         let saved_files = self
             .formatter
             .save_multiple_calls(calls, customer_name)
             .map_err(|e| crate::CsCliError::Generic(format!("Failed to save calls: {e}")))?;
+        emit_files_written(&saved_files);
 
-        println!(
-            "{}",
+        status(
             format!("Saved {} markdown files", saved_files.len()).green()
         );
 
@@ -915,8 +781,10 @@ impl TeamCallsExtractor {
                             "Failed to generate summary: {e}"
                         ))
                     })?;
-                println!(
-                    "{}",
+                emit(Event::FileWritten {
+                    path: summary_path.clone(),
+                });
+                status(
                     format!(
                         "Summary report saved to {}",
                         summary_path.display()
@@ -940,15 +808,15 @@ impl TeamCallsExtractor {
             return Ok(Vec::new());
         }
 
-        println!("{}", "Generating markdown files...".truecolor(255, 142, 100));
+        status("Generating markdown files...".truecolor(255, 142, 100));
 
         let saved_files = self
             .formatter
             .save_multiple_calls(calls, customer_name)
             .map_err(|e| crate::CsCliError::Generic(format!("Failed to save calls: {e}")))?;
+        emit_files_written(&saved_files);
 
-        println!(
-            "{}",
+        status(
             format!("Saved {} markdown files", saved_files.len()).green()
         );
 
@@ -965,8 +833,10 @@ impl TeamCallsExtractor {
                             "Failed to generate summary: {e}"
                         ))
                     })?;
-                println!(
-                    "{}",
+                emit(Event::FileWritten {
+                    path: summary_path.clone(),
+                });
+                status(
                     format!(
                         "Summary report saved to {}",
                         summary_path.display()
@@ -989,7 +859,7 @@ impl TeamCallsExtractor {
             return Ok(Vec::new());
         }
 
-        println!("{}", "Generating email markdown files...".truecolor(255, 142, 100));
+        status("Generating email markdown files...".truecolor(255, 142, 100));
 
         let saved_files = self
             .formatter
@@ -997,9 +867,9 @@ impl TeamCallsExtractor {
             .map_err(|e| {
                 crate::CsCliError::Generic(format!("Failed to save emails: {e}"))
             })?;
+        emit_files_written(&saved_files);
 
-        println!(
-            "{}",
+        status(
             format!(
                 "Saved {} emails across {} batch files",
                 emails.len(),
@@ -1011,18 +881,249 @@ impl TeamCallsExtractor {
         Ok(saved_files)
     }
 
+    /// Sink for the configured output format
+    fn output_sink(&self) -> Box<dyn OutputSink> {
+        let output = &self.config.output;
+        let output_dir = self.output_dir.clone();
+        match output.format {
+            OutputFormat::Markdown => Box::new(MarkdownSink::new(output_dir)),
+            OutputFormat::Html => Box::new(HtmlSink::new(output_dir)),
+            OutputFormat::Obsidian => {
+                Box::new(VaultSink::new(output_dir, output.vault_path.clone()))
+            }
+            OutputFormat::Docx => Box::new(BriefSink::docx(output_dir)),
+            OutputFormat::Pdf => Box::new(BriefSink::pdf(output_dir)),
+            OutputFormat::Mbox => Box::new(MailboxSink::new(output_dir)),
+            OutputFormat::Sqlite => Box::new(SqliteSink::new(output.database_path.clone())),
+            OutputFormat::Parquet => Box::new(ParquetSink::new(output.parquet_path.clone())),
+            OutputFormat::Csv => Box::new(ActivityCsvSink::new(output_dir, output.csv.clone())),
+        }
+    }
+
+    /// Write a customer's calls and emails in the configured output format
+    ///
+    /// Used after extraction and by `cs-cli render` for saved snapshots.
+    pub fn save_customer(
+        &self,
+        calls: &[Call],
+        emails: &[Email],
+        filter_decisions: &[EmailFilterDecision],
+        resolved_name: &str,
+        emails_only: bool,
+    ) -> Result<Vec<PathBuf>> {
+        let sink = self.output_sink();
+        // Recorded even when every email was filtered out
+        sink.write_filter_decisions(resolved_name, filter_decisions)?;
+
+        let calls: &[Call] = if emails_only { &[] } else { calls };
+        if calls.is_empty() && emails.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let format = format!("{:?}", self.config.output.format).to_lowercase();
+        status(format!("Writing {format} output...").truecolor(255, 142, 100));
+
+        let saved_files = sink.write(resolved_name, calls, emails)?;
+        emit_files_written(&saved_files);
+
+        if let Some(output_dir) = output_directory(&saved_files) {
+            status(
+                format!(
+                    "Saved {} {format} files to {}",
+                    saved_files.len(),
+                    output_dir.display()
+                )
+                .green(),
+            );
+        }
+
+        Ok(saved_files)
+    }
+
+    /// Record a customer folder's files and provenance in `manifest.json`
+    ///
+    /// Skipped for stores shared by every customer (SQLite, Parquet and an
+    /// Obsidian `vault_path`), which have no `ct_<customer>` folder.
+    pub fn write_manifest(&self, snapshot: &ExtractionSnapshot, saved_files: &[PathBuf]) {
+        if saved_files.is_empty() || self.writes_shared_store() {
+            return;
+        }
+
+        let folder = self.formatter.customer_output_dir(&snapshot.customer);
+        let format = format!("{:?}", self.config.output.format).to_lowercase();
+        match ManifestWriter::new(&folder).write(snapshot, &format, saved_files) {
+            Ok(path) => emit(Event::FileWritten { path }),
+            Err(e) => tracing::warn!(error = %e, "Failed to write manifest"),
+        }
+    }
+
+    /// Whether the output format writes one store for all customers
+    /// instead of a folder per customer
+    fn writes_shared_store(&self) -> bool {
+        let output = &self.config.output;
+        match output.format {
+            OutputFormat::Sqlite | OutputFormat::Parquet => true,
+            OutputFormat::Obsidian => output.vault_path.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Extract and save communications for a single customer
+    ///
+    /// Shared by the `customer` and `batch` commands so both produce the same
+    /// output layout for a given customer.
+    pub async fn extract_and_save_customer(
+        &self,
+        name: &str,
+        days: u32,
+        content_type: ContentType,
+        emails_only: bool,
+        fetch_email_bodies: bool,
+    ) -> Result<CustomerExtraction> {
+        // The public API backend has no customer search, so calls go through the timeline path
+        let use_timeline = matches!(content_type, ContentType::Emails | ContentType::Both)
+            || self.config.backend.kind == BackendKind::Api;
+
+        let (calls, emails, filter_decisions, resolved_name) = if use_timeline {
+            // Extract communications (calls + emails)
+            let extraction = self
+                .extract_customer_communications(
+                    name,
+                    days,
+                    !matches!(content_type, ContentType::Calls), // include_emails
+                    emails_only,
+                    fetch_email_bodies,
+                )
+                .await?;
+            (
+                extraction.calls,
+                extraction.emails,
+                extraction.filter_decisions,
+                extraction.resolved_name,
+            )
+        } else {
+            // Extract calls only
+            let (calls, resolved_name) = self
+                .extract_customer_calls(name, Some(days), None, None)
+                .await?;
+            (calls, Vec::new(), Vec::new(), resolved_name)
+        };
+
+        emit(Event::StageStarted {
+            stage: Stage::WriteFiles,
+            customer: Some(resolved_name.clone()),
+        });
+        let parameters = ExtractionParameters {
+            query: name.to_string(),
+            days,
+            content_type: format!("{content_type:?}").to_lowercase(),
+            emails_only,
+            fetch_email_bodies,
+            filters: if content_type == ContentType::Calls {
+                Vec::new()
+            } else {
+                Vec::from(EMAIL_FILTERS.map(String::from))
+            },
+            backend: format!("{:?}", self.config.backend.kind).to_lowercase(),
+            auth_source: self.auth_source.clone(),
+        };
+        let snapshot = ExtractionSnapshot::new(&resolved_name, &calls, &emails, emails_only)
+            .with_parameters(parameters)
+            .with_filter_decisions(filter_decisions.clone());
+
+        // Stamp files with the snapshot time so `cs-cli render` reproduces them exactly
+        let saved_files = with_generated_at(&snapshot.extracted_at, || -> Result<Vec<PathBuf>> {
+            let saved_files = self.save_customer(
+                &calls,
+                &emails,
+                &filter_decisions,
+                &resolved_name,
+                emails_only,
+            )?;
+            self.write_manifest(&snapshot, &saved_files);
+            Ok(saved_files)
+        })?;
+        if let Some(dir) = default_snapshot_dir() {
+            if let Err(e) = snapshot.save(&dir) {
+                tracing::warn!(error = %e, "Failed to save extraction snapshot");
+            }
+        }
+        emit(Event::StageFinished {
+            stage: Stage::WriteFiles,
+            customer: Some(resolved_name.clone()),
+            count: Some(saved_files.len()),
+        });
+
+        Ok(CustomerExtraction {
+            calls,
+            emails,
+            filter_decisions,
+            resolved_name,
+            saved_files,
+        })
+    }
+
     /// Cleanup resources
     pub async fn cleanup(&mut self) {
         // Cleanup HTTP client if needed
         // Note: HttpClientPool may not have a cleanup method,
         // resources should be cleaned up when dropped
-        self.http = None;
-        self.auth = None;
         self.library_client = None;
         self.details_fetcher = None;
         self.customer_search_client = None;
-        self.timeline_extractor = None;
-        self.email_enhancer = None;
+        self.backend = None;
+    }
+}
+
+/// Emit a `file_written` event for each saved file
+fn emit_files_written(paths: &[PathBuf]) {
+    for path in paths {
+        emit(Event::FileWritten { path: path.clone() });
+    }
+}
+
+/// Directory holding the saved files
+fn output_directory(files: &[PathBuf]) -> Option<PathBuf> {
+    files.first().and_then(|f| f.parent()).map(|dir| dir.to_path_buf())
+}
+
+/// Unlock the macOS keychain before browser cookie extraction (prompt if needed)
+///
+/// Only called when no persisted session could be reused, and skipped when the
+/// keychain is already unlocked, so most runs never ask for the password.
+fn unlock_keychain_if_needed(keychain_password: &KeychainPasswordSource) -> Result<()> {
+    if !cfg!(target_os = "macos") || is_keychain_unlocked().unwrap_or(false) {
+        return Ok(());
+    }
+
+    if *keychain_password == KeychainPasswordSource::Prompt {
+        status("Make sure you're logged into Gong in your browser!".truecolor(255, 142, 100));
+    }
+
+    // Reads from the source (prompting on the terminal for `prompt`)
+    unlock_keychain_from(keychain_password)
+}
+
+/// Pick the keychain password source from the flags, then the config
+///
+/// Without either, interactive runs prompt and everything else skips the unlock.
+fn keychain_password_source(args: &CliArgs, config: &AppConfig) -> Result<KeychainPasswordSource> {
+    if let Some(spec) = &args.keychain_password_from {
+        return KeychainPasswordSource::parse(spec);
+    }
+    if let Some(password) = &args.keychain_password {
+        let message = "--keychain-password is visible in shell history and `ps`; prefer --keychain-password-from";
+        warning(message, message.yellow());
+        return Ok(KeychainPasswordSource::Value(password.clone()));
+    }
+    if let Some(spec) = &config.auth.keychain_password_from {
+        return KeychainPasswordSource::parse(spec);
+    }
+
+    if args.events == EventFormat::Human && std::io::stdin().is_terminal() {
+        Ok(KeychainPasswordSource::Prompt)
+    } else {
+        Ok(KeychainPasswordSource::Skip)
     }
 }
 
@@ -1030,49 +1131,145 @@ impl TeamCallsExtractor {
 pub async fn run_cli() -> Result<()> {
     // Parse command line arguments
     let args = CliArgs::parse();
+    init_events(args.events, args.quiet);
     let command = args.parse_command()?;
 
     // Initialize logging based on debug flag
-    // Use try_init to avoid panic if subscriber already set
-    if args.debug {
-        let _ = tracing_subscriber::fmt()
-            .with_max_level(tracing::Level::DEBUG)
-            .try_init();
+    // Use try_init to avoid panic if subscriber already set; logs go to stderr
+    let level = if args.debug {
+        tracing::Level::DEBUG
     } else {
-        let _ = tracing_subscriber::fmt()
-            .with_max_level(tracing::Level::INFO)
-            .try_init();
+        tracing::Level::INFO
+    };
+    let _ = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .try_init();
+
+    // Config management works on the files themselves, so it runs before loading them
+    migrate_legacy_config();
+    if let ParsedCommand::Config(action) = &command {
+        return execute_config(action.clone());
     }
-
-    // Unlock macOS keychain once at startup (prompt if needed)
-    if cfg!(target_os = "macos") {
-        let password = match &args.keychain_password {
-            Some(p) => p.clone(),
-            None => {
-                println!("{}", "Make sure you're logged into Gong in your browser!".truecolor(255, 142, 100));
-                rpassword::prompt_password(&format!("{}", "Enter your Macbook password: ".truecolor(111, 44, 186)))
-                    .map_err(|e| crate::CsCliError::Authentication(format!("Failed to read password: {}", e)))?
+    if let ParsedCommand::Templates(action) = &command {
+        return execute_templates(action.clone());
+    }
+    if let ParsedCommand::Update { action, yes } = command {
+        let settings = AppConfig::load()?.update;
+        let interactive = args.events == EventFormat::Human
+            && std::io::stdin().is_terminal()
+            && std::io::stdout().is_terminal();
+        return match action {
+            UpdateAction::Check => crate::updater::run_update_check(&settings).await,
+            UpdateAction::Apply => {
+                crate::updater::run_update_apply(&settings, yes, interactive).await
             }
+            UpdateAction::Status => crate::updater::run_update_status(&settings),
+            UpdateAction::Rollback => crate::updater::run_update_rollback(),
         };
-        
-        unlock_keychain_with_cli_password(&password)?;
-        info!("Keychain unlocked for session");
     }
 
-    // Load application configuration
-    let mut cli_config = load_config();
-    let app_config = AppConfig::from_env()?;
+    // Load application configuration (defaults, config files, env; flags below)
+    let mut app_config = AppConfig::load()?;
+
+    // Command-line browser selection overrides GONG_BROWSER/GONG_BROWSER_PROFILE
+    if args.browser.is_some() {
+        app_config.auth.browser = args.browser.clone();
+    }
+    if args.browser_profile.is_some() {
+        app_config.auth.browser_profile = args.browser_profile.clone();
+    }
+    BrowserSelection::from_names(
+        app_config.auth.browser.as_deref(),
+        app_config.auth.browser_profile.as_deref(),
+    )?;
+    if args.cookies.is_some() {
+        app_config.auth.cookie_import = args.cookies.clone();
+    }
+    if let Some(spec) = &app_config.auth.cookie_import {
+        CookieImportSource::parse(spec)?;
+    }
+    if let Some(format) = args.format {
+        app_config.output.format = format;
+    }
+    if let Some(Command::Export(export)) = &args.command {
+        app_config.output.format = export.format.into();
+        if let Some(output) = &export.output {
+            match export.format {
+                ExportFormat::Sqlite => app_config.output.database_path = Some(output.clone()),
+                ExportFormat::Parquet => app_config.output.parquet_path = Some(output.clone()),
+                ExportFormat::Csv => app_config.output.csv.path = Some(output.clone()),
+            }
+        }
+        if let Some(preset) = export.preset {
+            app_config.output.csv.preset = preset;
+            app_config.output.csv.columns.clear();
+        }
+    }
+    if let Some(backend) = args.backend {
+        app_config.backend.kind = backend;
+        app_config.validate()?;
+    }
+
+    // Rendering saved extractions needs no login
+    if let ParsedCommand::Render {
+        from,
+        customer,
+        output,
+    } = &command
+    {
+        return execute_render(from, customer.as_deref(), output.as_deref(), &app_config);
+    }
+
+    // Keychain unlock is deferred until browser extraction is actually needed
+    let keychain_password = keychain_password_source(&args, &app_config)?;
+    let extractor = TeamCallsExtractor::new(app_config)
+        .with_keychain_password(keychain_password.clone())
+        .with_fresh_login(args.fresh_login);
 
     // Route to appropriate handler based on command
     match command {
         ParsedCommand::Interactive => {
+            if args.events == EventFormat::Json {
+                return Err(crate::CsCliError::InvalidArguments {
+                    message: "--events=json needs a command (customer, team or batch); interactive mode reads from the terminal".to_string(),
+                });
+            }
+
             // Launch interactive mode
             let interactive_command = interactive_mode()?;
-            execute_command(interactive_command, &mut cli_config, app_config).await
+            execute_command(interactive_command, extractor).await
         }
         ParsedCommand::Customer { .. } | ParsedCommand::Team { .. } => {
-            execute_command(command, &mut cli_config, app_config).await
+            execute_command(command, extractor).await
         }
+        ParsedCommand::Batch {
+            file,
+            parallel,
+            days,
+            content_type,
+            fetch_email_bodies,
+        } => {
+            let options = BatchOptions {
+                parallel,
+                days,
+                content_type,
+                fetch_email_bodies,
+            };
+            execute_batch(&file, options, extractor).await
+        }
+        ParsedCommand::Doctor { json, output } => {
+            if extractor.config.auth.cookie_import.is_none() {
+                unlock_keychain_if_needed(&keychain_password)?;
+            }
+            execute_doctor(&extractor.config, json, output.as_deref()).await
+        }
+        ParsedCommand::Config(_) => unreachable!("Config is handled before loading settings"),
+        ParsedCommand::Templates(_) => {
+            unreachable!("Templates is handled before loading settings")
+        }
+        ParsedCommand::Update { .. } => unreachable!("Update is handled before loading settings"),
+        ParsedCommand::Render { .. } => unreachable!("Render is handled before logging in"),
         ParsedCommand::Completion { shell } => {
             generate_completion(shell);
             Ok(())
@@ -1090,12 +1287,8 @@ fn generate_completion(shell: Shell) {
 /// Execute the parsed command
 async fn execute_command(
     command: ParsedCommand,
-    cli_config: &mut CliConfig,
-    app_config: AppConfig,
+    mut extractor: TeamCallsExtractor,
 ) -> Result<()> {
-    // Initialize extractor
-    let mut extractor = TeamCallsExtractor::new(app_config);
-
     // Setup components
     extractor.setup().await?;
 
@@ -1106,20 +1299,26 @@ async fn execute_command(
             stream_id, days, ..
         } => {
             // Handle team extraction
+            if extractor.config.output.format != OutputFormat::Markdown {
+                let format = format!("{:?}", extractor.config.output.format).to_lowercase();
+                let message = format!(
+                    "--format {format} applies to customer extractions; team calls are saved as markdown"
+                );
+                warning(&message, message.yellow());
+            }
             let stream_id = match stream_id {
                 Some(id) => id,
                 None => {
                     // Interactive team mode
                     let team_command =
-                        interactive_team_mode(cli_config.team_call_stream_id.clone())?;
+                        interactive_team_mode(extractor.config.team.call_stream_id.clone())?;
                     if let ParsedCommand::Team {
                         stream_id: Some(id),
                         ..
                     } = team_command
                     {
                         // Save the stream ID for future use
-                        cli_config.team_call_stream_id = Some(id.clone());
-                        save_config(cli_config)?;
+                        save_team_stream_id(&id)?;
                         id
                     } else {
                         return Err(crate::CsCliError::Generic(
@@ -1135,19 +1334,41 @@ async fn execute_command(
                 .await?;
 
             if !calls.is_empty() {
+                emit(Event::StageStarted {
+                    stage: Stage::WriteFiles,
+                    customer: None,
+                });
                 let call_files = extractor.save_calls_as_markdown_with_resolved_name(
                     &calls,
                     Some("Team"),
                     Some("Team"),
                 )?;
                 saved_files.extend(call_files);
+                emit(Event::StageFinished {
+                    stage: Stage::WriteFiles,
+                    customer: None,
+                    count: Some(saved_files.len()),
+                });
             }
 
             // Display results
-            println!();
-            println!("{}", "Extraction Complete!".bold().truecolor(255, 108, 55));
-            println!("Extracted {} team calls", calls.len());
-            println!("Saved {} markdown files", saved_files.len());
+            status("");
+            status("Extraction Complete!".bold().truecolor(255, 108, 55));
+            status(format!("Extracted {} team calls", calls.len()));
+            status(format!("Saved {} markdown files", saved_files.len()));
+            emit(Event::Summary {
+                customer: None,
+                calls: calls.len(),
+                emails: 0,
+                files: saved_files.len(),
+                output_dir: output_directory(&saved_files),
+            });
+
+            if calls.is_empty() {
+                return Err(crate::CsCliError::NoData(format!(
+                    "No team calls found in the last {days} days"
+                )));
+            }
         }
 
         ParsedCommand::Customer {
@@ -1156,92 +1377,131 @@ async fn execute_command(
             content_type,
             emails_only,
             fetch_email_bodies,
+            slack,
             ..
         } => {
             // Handle customer extraction
             let days = days.unwrap_or(90);
 
-            let (calls, emails, resolved_name) =
-                if matches!(content_type, ContentType::Emails | ContentType::Both) {
-                    // Extract communications (calls + emails)
-                    extractor
-                        .extract_customer_communications(
-                            &name,
-                            days,
-                            true, // include_emails
-                            emails_only,
-                            fetch_email_bodies,
-                        )
-                        .await?
-                } else {
-                    // Extract calls only
-                    let (calls, resolved_name) = extractor
-                        .extract_customer_calls(&name, Some(days), None, None)
-                        .await?;
-                    (calls, Vec::new(), resolved_name)
-                };
-
-            // Save results
-            if !calls.is_empty() && !emails_only {
-                let call_files = extractor.save_calls_as_markdown_with_resolved_name(
-                    &calls,
-                    Some(&resolved_name),
-                    Some(&resolved_name),
-                )?;
-                saved_files.extend(call_files);
-            }
-
-            if !emails.is_empty() {
-                let email_files = extractor.save_emails_as_markdown(&emails, &resolved_name)?;
-                saved_files.extend(email_files);
+            let CustomerExtraction {
+                calls,
+                emails,
+                resolved_name,
+                saved_files: customer_files,
+                ..
+            } = extractor
+                .extract_and_save_customer(
+                    &name,
+                    days,
+                    content_type,
+                    emails_only,
+                    fetch_email_bodies,
+                )
+                .await?;
+            saved_files.extend(customer_files);
+
+            // Shared Slack channels go in the same customer folder
+            #[cfg(feature = "slack")]
+            if slack {
+                status("Fetching shared Slack channels...".truecolor(255, 142, 100));
+                let output_dir = output_directory(&saved_files).unwrap_or_else(|| {
+                    CallMarkdownFormatter::new(None).customer_output_dir(&resolved_name)
+                });
+                let extraction = crate::slack::extract_customer_slack(
+                    &crate::slack::SlackSettings::from_env(),
+                    &resolved_name,
+                    days,
+                    output_dir,
+                )
+                .await?;
+                status(format!(
+                    "Saved {} Slack messages from {} shared channels",
+                    extraction.message_count(),
+                    extraction.conversations.len()
+                ));
+                saved_files.extend(extraction.saved_files);
             }
+            #[cfg(not(feature = "slack"))]
+            let _ = slack;
 
             // Display results
-            println!();
-            println!("{}", "Extraction Complete!".bold().truecolor(255, 108, 55));
+            status("");
+            status("Extraction Complete!".bold().truecolor(255, 108, 55));
 
             if emails_only {
-                println!("Extracted {} emails for '{}'", emails.len(), resolved_name);
+                status(format!("Extracted {} emails for '{}'", emails.len(), resolved_name));
                 if !emails.is_empty() {
                     let emails_with_bodies = emails
                         .iter()
                         .filter(|e| e.body_text.as_ref().is_some_and(|b| !b.trim().is_empty()))
                         .count();
-                    println!(
+                    status(format!(
                         "{}/{} emails have full body content",
                         emails_with_bodies,
                         emails.len()
-                    );
+                    ));
                 }
             } else if matches!(content_type, ContentType::Both) {
-                println!(
+                status(format!(
                     "Extracted {} calls and {} emails for '{}'",
                     calls.len(),
                     emails.len(),
                     resolved_name
-                );
+                ));
                 if !emails.is_empty() {
                     let emails_with_bodies = emails
                         .iter()
                         .filter(|e| e.body_text.as_ref().is_some_and(|b| !b.trim().is_empty()))
                         .count();
-                    println!(
+                    status(format!(
                         "Advanced BDR/SPAM filtering applied - {}/{} emails have full content",
                         emails_with_bodies,
                         emails.len()
-                    );
+                    ));
                 }
             } else {
-                println!("Extracted {} calls for '{}'", calls.len(), resolved_name);
+                status(format!("Extracted {} calls for '{}'", calls.len(), resolved_name));
             }
 
-            println!("Saved {} markdown files", saved_files.len());
+            status(format!("Saved {} files", saved_files.len()));
+            emit(Event::Summary {
+                customer: Some(resolved_name.clone()),
+                calls: calls.len(),
+                emails: emails.len(),
+                files: saved_files.len(),
+                output_dir: output_directory(&saved_files),
+            });
+
+            if calls.is_empty() && emails.is_empty() && saved_files.is_empty() {
+                return Err(crate::CsCliError::NoData(format!(
+                    "No {content_type} found for '{resolved_name}' in the last {days} days"
+                )));
+            }
         }
 
         ParsedCommand::Interactive => {
             // This should not happen as interactive is handled above
             unreachable!("Interactive mode should be handled before this point");
         }
+        ParsedCommand::Batch { .. } => {
+            // This should not happen as batch runs are handled above
+            unreachable!("Batch should be handled before this point");
+        }
+        ParsedCommand::Doctor { .. } => {
+            unreachable!("Doctor should be handled before this point");
+        }
+        ParsedCommand::Config(_) => {
+            unreachable!("Config should be handled before this point");
+        }
+        ParsedCommand::Templates(_) => {
+            unreachable!("Templates should be handled before this point");
+        }
+        ParsedCommand::Update { .. } => {
+            unreachable!("Update should be handled before this point");
+        }
+        ParsedCommand::Render { .. } => {
+            unreachable!("Render should be handled before this point");
+        }
         ParsedCommand::Completion { .. } => {
             // This should not happen as completion is handled above
             unreachable!("Completion should be handled before this point");
@@ -1250,11 +1510,11 @@ async fn execute_command(
 
     // Show output directory
     if !saved_files.is_empty() {
-        if let Some(output_directory) = saved_files.first().and_then(|f| f.parent()) {
-            println!(
+        if let Some(output_directory) = output_directory(&saved_files) {
+            status(format!(
                 "Output directory: {}",
                 output_directory.display().to_string().bold()
-            );
+            ));
         }
     }
 
@@ -0,0 +1,101 @@
+//! Terminal progress output
+//!
+//! Spinners, progress bars and the [`TerminalReporter`] that turns library
+//! extractor progress into the CLI's colored output. Bars are hidden under
+//! `--quiet` and `NO_COLOR`.
+
+use indicatif::{ProgressBar, ProgressStyle};
+use owo_colors::OwoColorize;
+use std::sync::Mutex;
+
+use crate::common::events::{emit, fancy_output, status, Event, Stage};
+use crate::gong::extractor::{MessageLevel, Reporter};
+
+/// Spinner for work of unknown length
+pub fn spinner(message: &'static str) -> ProgressBar {
+    if !fancy_output() {
+        return ProgressBar::hidden();
+    }
+
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    pb.set_message(message);
+    pb.enable_steady_tick(std::time::Duration::from_millis(100));
+    pb
+}
+
+/// Progress bar for `len` items
+pub fn progress_bar(len: usize, message: &'static str) -> ProgressBar {
+    if !fancy_output() {
+        return ProgressBar::hidden();
+    }
+
+    let pb = ProgressBar::new(len as u64);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+            .unwrap()
+            .progress_chars("#>-"),
+    );
+    pb.set_message(message);
+    pb
+}
+
+/// Reporter printing extractor progress to the terminal
+///
+/// Events also go to the `--events=json` stream.
+#[derive(Default)]
+pub struct TerminalReporter {
+    bar: Mutex<Option<ProgressBar>>,
+}
+
+impl TerminalReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Reporter for TerminalReporter {
+    fn event(&self, event: &Event) {
+        emit(event.clone());
+    }
+
+    fn message(&self, level: MessageLevel, text: &str) {
+        match level {
+            MessageLevel::Info => status(text.truecolor(255, 142, 100)),
+            MessageLevel::Success => status(text.green()),
+            MessageLevel::Warning => status(text.yellow()),
+        }
+    }
+
+    fn progress(&self, stage: Stage, done: usize, total: usize) {
+        let Ok(mut bar) = self.bar.lock() else {
+            return;
+        };
+
+        let pb = bar.get_or_insert_with(|| {
+            progress_bar(
+                total,
+                match stage {
+                    Stage::FetchEmailBodies => "Fetching email bodies...",
+                    _ => "Fetching call details...",
+                },
+            )
+        });
+        pb.set_position(done as u64);
+
+        if done >= total {
+            pb.finish_with_message(match stage {
+                Stage::FetchEmailBodies => "Email body enhancement complete",
+                _ => "Call details fetching complete",
+            });
+            *bar = None;
+        } else if stage == Stage::FetchTranscripts {
+            pb.set_message(format!("Fetching details for call {}/{}", done + 1, total));
+        }
+    }
+}
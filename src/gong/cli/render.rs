@@ -0,0 +1,110 @@
+//! `cs-cli render`: rebuild output folders from saved extractions, offline
+
+use owo_colors::OwoColorize;
+use std::path::{Path, PathBuf};
+
+use super::{output_directory, TeamCallsExtractor};
+use crate::common::events::{emit, status, Event, Stage};
+use crate::gong::config::AppConfig;
+use crate::gong::output::markdown::CallMarkdownFormatter;
+use crate::gong::output::snapshot::{
+    default_snapshot_dir, with_generated_at, ExtractionSnapshot, SNAPSHOT_CACHE,
+};
+use crate::{CsCliError, Result};
+
+/// Render saved extractions in the configured output format
+///
+/// `from` is a snapshot file, a folder of them, or `cache` for the ones the
+/// CLI saves after each customer extraction. Nothing is fetched from Gong.
+pub fn execute_render(
+    from: &str,
+    customer: Option<&str>,
+    output: Option<&Path>,
+    config: &AppConfig,
+) -> Result<()> {
+    let path = if from == SNAPSHOT_CACHE {
+        default_snapshot_dir().ok_or_else(|| {
+            CsCliError::Configuration(
+                "Could not find the cache directory; pass --from with a snapshot file".to_string(),
+            )
+        })?
+    } else {
+        PathBuf::from(from)
+    };
+    if !path.exists() {
+        return Err(CsCliError::NoData(format!(
+            "No saved extractions at {}; run `cs-cli customer` first",
+            path.display()
+        )));
+    }
+
+    let snapshots: Vec<ExtractionSnapshot> = ExtractionSnapshot::load_all(&path)?
+        .into_iter()
+        .filter(|snapshot| match customer {
+            Some(name) => snapshot.customer.eq_ignore_ascii_case(name),
+            None => true,
+        })
+        .collect();
+    if snapshots.is_empty() {
+        return Err(CsCliError::NoData(match customer {
+            Some(name) => format!("No saved extraction for '{name}' in {}", path.display()),
+            None => format!("No saved extractions in {}", path.display()),
+        }));
+    }
+
+    let mut total_files = 0;
+    for snapshot in &snapshots {
+        let mut extractor = TeamCallsExtractor::new(config.clone());
+        if let Some(root) = output {
+            let folder = CallMarkdownFormatter::new(None).customer_folder_name(&snapshot.customer);
+            extractor = extractor.with_output_dir(root.join(folder));
+        }
+
+        status(
+            format!(
+                "Rendering {} (extracted {})...",
+                snapshot.customer,
+                snapshot.extracted_at.strftime("%Y-%m-%d %H:%M")
+            )
+            .truecolor(255, 142, 100),
+        );
+        emit(Event::StageStarted {
+            stage: Stage::WriteFiles,
+            customer: Some(snapshot.customer.clone()),
+        });
+        let saved_files = with_generated_at(&snapshot.extracted_at, || -> Result<Vec<PathBuf>> {
+            let saved_files = extractor.save_customer(
+                &snapshot.calls,
+                &snapshot.emails,
+                &snapshot.filter_decisions,
+                &snapshot.customer,
+                snapshot.emails_only,
+            )?;
+            extractor.write_manifest(snapshot, &saved_files);
+            Ok(saved_files)
+        })?;
+        emit(Event::StageFinished {
+            stage: Stage::WriteFiles,
+            customer: Some(snapshot.customer.clone()),
+            count: Some(saved_files.len()),
+        });
+        emit(Event::Summary {
+            customer: Some(snapshot.customer.clone()),
+            calls: snapshot.calls.len(),
+            emails: snapshot.emails.len(),
+            files: saved_files.len(),
+            output_dir: output_directory(&saved_files),
+        });
+        total_files += saved_files.len();
+    }
+
+    status(
+        format!(
+            "Rendered {} customer(s) into {total_files} files without contacting Gong",
+            snapshots.len()
+        )
+        .green(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! `cs-cli templates`: export and locate the markdown output templates
+
+use owo_colors::OwoColorize;
+
+use super::TemplatesAction;
+use crate::common::events::status;
+use crate::gong::output::templates::{
+    dump_templates, user_templates_dir, TemplateRenderer, BUILTIN_TEMPLATES,
+};
+use crate::{CsCliError, Result};
+
+/// Run a `cs-cli templates` subcommand
+pub fn execute_templates(action: TemplatesAction) -> Result<()> {
+    match action {
+        TemplatesAction::Dump { dir, force } => {
+            let dir = match dir.or_else(user_templates_dir) {
+                Some(dir) => dir,
+                None => {
+                    return Err(CsCliError::Configuration(
+                        "Could not find the user config directory; pass --dir".to_string(),
+                    ))
+                }
+            };
+
+            let written = dump_templates(&dir, force)?;
+            for path in &written {
+                status(format!("✓ {}", path.display()).green());
+            }
+            let kept = BUILTIN_TEMPLATES.len() - written.len();
+            if kept > 0 {
+                status(
+                    format!("Kept {kept} existing template(s); use --force to replace them")
+                        .yellow(),
+                );
+            }
+            status(
+                format!(
+                    "Edit the templates in {} to change the output",
+                    dir.display()
+                )
+                .truecolor(255, 142, 100),
+            );
+        }
+        TemplatesAction::Path => {
+            match user_templates_dir() {
+                Some(dir) => println!("{}", dir.display()),
+                None => println!("(none)"),
+            }
+            let renderer = TemplateRenderer::shared();
+            for (name, _) in BUILTIN_TEMPLATES {
+                let customized = renderer
+                    .overrides()
+                    .iter()
+                    .any(|path| path.file_name().is_some_and(|file| file == *name));
+                let source = if customized { "custom" } else { "built-in" };
+                println!("  {name:<20} {source}");
+            }
+        }
+    }
+
+    Ok(())
+}
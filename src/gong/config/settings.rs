@@ -1,19 +1,214 @@
+use crate::common::config::{read_config_file, unknown_keys, ConfigSources};
+use crate::gong::output::activity_csv::{CsvColumn, CsvPreset};
 use crate::{CsCliError, Result};
-use figment::{
-    providers::{Env, Format, Toml},
-    Figment,
-};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 // HttpSettings and AuthSettings are now imported from common::config
 // Re-export for backward compatibility within gong modules
-pub use crate::common::config::{AuthSettings, HttpSettings};
+pub use crate::common::config::{AuthSettings, HttpSettings, UpdateSettings};
+
+/// Which Gong backend extraction runs against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendKind {
+    /// Internal web endpoints using the browser session (default)
+    #[default]
+    Web,
+    /// Gong's documented public REST API using an access key and secret
+    Api,
+}
+
+impl std::str::FromStr for BackendKind {
+    type Err = CsCliError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "web" | "session" => Ok(Self::Web),
+            "api" | "public" => Ok(Self::Api),
+            other => Err(CsCliError::Configuration(format!(
+                "Unknown Gong backend '{other}' (expected 'web' or 'api')"
+            ))),
+        }
+    }
+}
+
+/// Backend selection and public API credentials
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendSettings {
+    /// Backend used for customer extraction
+    #[serde(default)]
+    pub kind: BackendKind,
+    /// Base URL of the public API
+    #[serde(default = "default_api_base_url")]
+    pub api_base_url: String,
+    /// Public API access key
+    #[serde(default)]
+    pub access_key: Option<String>,
+    /// Public API access key secret
+    #[serde(default)]
+    pub access_key_secret: Option<String>,
+}
+
+fn default_api_base_url() -> String {
+    "https://api.gong.io".to_string()
+}
+
+impl Default for BackendSettings {
+    fn default() -> Self {
+        Self {
+            kind: BackendKind::default(),
+            api_base_url: default_api_base_url(),
+            access_key: None,
+            access_key_secret: None,
+        }
+    }
+}
+
+/// File format customer extractions are saved in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// One markdown file per call plus batched email files (default)
+    #[default]
+    Markdown,
+    /// Self-contained HTML report: `index.html` plus a page per call
+    Html,
+    /// Obsidian/Logseq notes with YAML front matter and `[[wikilinks]]`
+    Obsidian,
+    /// Word customer brief: cover, summary, activity and recent transcripts
+    Docx,
+    /// PDF customer brief with the same content as `docx`
+    Pdf,
+    /// Markdown calls plus emails as `.eml` files and a per-customer mbox
+    Mbox,
+    /// Upsert into a SQLite database shared by every customer
+    Sqlite,
+    /// Parquet tables partitioned by customer and month
+    Parquet,
+    /// CSV of calls and emails laid out for a CRM activity import
+    Csv,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = CsCliError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            "obsidian" | "vault" | "logseq" => Ok(Self::Obsidian),
+            "docx" | "word" => Ok(Self::Docx),
+            "pdf" => Ok(Self::Pdf),
+            "mbox" | "eml" => Ok(Self::Mbox),
+            "sqlite" | "sqlite3" | "db" => Ok(Self::Sqlite),
+            "parquet" => Ok(Self::Parquet),
+            "csv" => Ok(Self::Csv),
+            other => Err(CsCliError::Configuration(format!(
+                "Unknown output format '{other}' (expected markdown, html, obsidian, docx, pdf, mbox, sqlite, parquet or csv)"
+            ))),
+        }
+    }
+}
+
+/// How extracted communications are written
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutputSettings {
+    /// Format for customer and batch extractions
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// Vault folder for `obsidian` output; defaults to each customer folder
+    #[serde(default)]
+    pub vault_path: Option<PathBuf>,
+    /// Database file for `sqlite` output; defaults to `gong.sqlite` on the Desktop
+    #[serde(default)]
+    pub database_path: Option<PathBuf>,
+    /// Dataset folder for `parquet` output; defaults to `gong-parquet` on the Desktop
+    #[serde(default)]
+    pub parquet_path: Option<PathBuf>,
+    /// Columns and account IDs for `csv` output
+    #[serde(default)]
+    pub csv: CsvSettings,
+}
+
+/// CSV activity export settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvSettings {
+    /// Column layout for a CRM import
+    #[serde(default)]
+    pub preset: CsvPreset,
+    /// Custom columns, used instead of the preset's
+    #[serde(default)]
+    pub columns: Vec<CsvColumn>,
+    /// CRM account IDs by customer name, for the `account_external_id` field
+    #[serde(default)]
+    pub account_ids: BTreeMap<String, String>,
+    /// Folder for the CSV files; defaults to each customer folder
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+    /// Prefix values starting with `=`, `+`, `-`, `@`, a tab or a carriage
+    /// return with `'` so spreadsheets don't run them as formulas
+    #[serde(default = "default_escape_formulas")]
+    pub escape_formulas: bool,
+}
+
+fn default_escape_formulas() -> bool {
+    true
+}
+
+impl Default for CsvSettings {
+    fn default() -> Self {
+        Self {
+            preset: CsvPreset::default(),
+            columns: Vec::new(),
+            account_ids: BTreeMap::new(),
+            path: None,
+            escape_formulas: default_escape_formulas(),
+        }
+    }
+}
+
+impl CsvSettings {
+    /// Custom columns if any, otherwise the preset's
+    pub fn columns(&self) -> Vec<CsvColumn> {
+        if self.columns.is_empty() {
+            self.preset.columns()
+        } else {
+            self.columns.clone()
+        }
+    }
+
+    /// CRM account ID configured for a customer, ignoring case
+    pub fn account_id(&self, customer: &str) -> Option<String> {
+        self.account_ids
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(customer))
+            .map(|(_, id)| id.clone())
+    }
+}
+
+/// Team call extraction settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TeamSettings {
+    /// Gong call stream used by `cs-cli team` (saved after the first run)
+    #[serde(default)]
+    pub call_stream_id: Option<String>,
+}
 
 /// Main application configuration matching Python SimplifiedPerformanceConfig
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub http: HttpSettings,
     pub auth: AuthSettings,
+    #[serde(default)]
+    pub backend: BackendSettings,
+    #[serde(default)]
+    pub team: TeamSettings,
+    #[serde(default)]
+    pub update: UpdateSettings,
+    #[serde(default)]
+    pub output: OutputSettings,
     pub debug: bool,
     pub max_concurrent_timeline_requests: usize,
     pub max_concurrent_email_requests: usize,
@@ -27,6 +222,10 @@ impl Default for AppConfig {
         Self {
             http: HttpSettings::default(),
             auth: AuthSettings::default(),
+            backend: BackendSettings::default(),
+            team: TeamSettings::default(),
+            update: UpdateSettings::default(),
+            output: OutputSettings::default(),
             debug: false,
             max_concurrent_timeline_requests: 105,
             max_concurrent_email_requests: 135,
@@ -61,7 +260,17 @@ impl AppConfig {
                 retry_attempts: 3,
                 retry_backoff_base: 2.0,
                 retry_backoff_seconds: 1.0,
+                persist_session: true,
+                session_max_age_hours: 72,
+                browser: None,
+                browser_profile: None,
+                cookie_import: None,
+                keychain_password_from: None,
             },
+            backend: BackendSettings::default(),
+            team: TeamSettings::default(),
+            update: UpdateSettings::default(),
+            output: OutputSettings::default(),
             debug: false,
             max_concurrent_timeline_requests: 105,
             max_concurrent_email_requests: 135,
@@ -72,8 +281,18 @@ impl AppConfig {
     }
 
     /// Load configuration from environment matching Python from_env()
+    ///
+    /// Ignores config files; see [`AppConfig::load`] for the full layering.
     pub fn from_env() -> Result<Self> {
         let mut config = Self::create_default();
+        config.apply_env_overrides()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Apply the `GONG_*` environment variables on top of the current settings
+    pub fn apply_env_overrides(&mut self) -> Result<()> {
+        let config = self;
 
         // Check debug flag
         if let Ok(debug_val) = std::env::var("GONG_DEBUG") {
@@ -83,6 +302,61 @@ impl AppConfig {
             }
         }
 
+        // Allow opting out of the persisted session file
+        if let Ok(persist_val) = std::env::var("GONG_PERSIST_SESSION") {
+            if matches!(persist_val.to_lowercase().as_str(), "false" | "0" | "no") {
+                config.auth.persist_session = false;
+            }
+        }
+
+        // Browser/profile to read Gong cookies from
+        if let Ok(browser) = std::env::var("GONG_BROWSER") {
+            config.auth.browser = Some(browser);
+        }
+        if let Ok(profile) = std::env::var("GONG_BROWSER_PROFILE") {
+            config.auth.browser_profile = Some(profile);
+        }
+
+        // Imported cookies: a cookies.txt/HAR path (or "-" for stdin), or the data itself
+        if let Ok(path) = std::env::var("GONG_COOKIES_FILE") {
+            config.auth.cookie_import = Some(path);
+        } else if std::env::var("GONG_COOKIES").is_ok() {
+            config.auth.cookie_import = Some("env:GONG_COOKIES".to_string());
+        }
+
+        // Keychain password source, e.g. "cmd:op read op://Private/Mac/password"
+        if let Ok(source) = std::env::var("GONG_KEYCHAIN_PASSWORD_FROM") {
+            config.auth.keychain_password_from = Some(source);
+        }
+
+        // Public API backend: credentials and selection
+        if let Ok(access_key) = std::env::var("GONG_ACCESS_KEY") {
+            config.backend.access_key = Some(access_key);
+        }
+        if let Ok(secret) = std::env::var("GONG_ACCESS_KEY_SECRET") {
+            config.backend.access_key_secret = Some(secret);
+        }
+        if let Ok(base_url) = std::env::var("GONG_API_BASE_URL") {
+            config.backend.api_base_url = base_url;
+        }
+        if let Ok(backend) = std::env::var("GONG_BACKEND") {
+            config.backend.kind = backend.parse()?;
+        }
+
+        // Output format for customer extractions
+        if let Ok(format) = std::env::var("GONG_OUTPUT_FORMAT") {
+            config.output.format = format.parse()?;
+        }
+        if let Ok(vault_path) = std::env::var("GONG_VAULT_PATH") {
+            config.output.vault_path = Some(PathBuf::from(vault_path));
+        }
+        if let Ok(database_path) = std::env::var("GONG_DATABASE_PATH") {
+            config.output.database_path = Some(PathBuf::from(database_path));
+        }
+        if let Ok(parquet_path) = std::env::var("GONG_PARQUET_PATH") {
+            config.output.parquet_path = Some(PathBuf::from(parquet_path));
+        }
+
         // Handle HTTP concurrency override
         if let Ok(concurrency_val) = std::env::var("GONG_HTTP_CONCURRENCY") {
             if let Ok(total_concurrency) = concurrency_val.parse::<usize>() {
@@ -97,24 +371,55 @@ impl AppConfig {
             }
         }
 
-        config.validate()?;
-        Ok(config)
+        Ok(())
     }
 
     /// Load configuration from multiple sources with precedence:
-    /// 1. Environment variables (highest priority)
-    /// 2. Config file (cs-cli.toml)
-    /// 3. Defaults (lowest priority)
+    /// 1. `GONG_*` environment variables (highest priority)
+    /// 2. `CS_CLI_*` environment variables
+    /// 3. Project config file (`cs-cli.toml`)
+    /// 4. User config file (`~/.config/cs-cli/config.toml`)
+    /// 5. Defaults (lowest priority)
+    ///
+    /// Command-line flags are applied on top by the caller.
     pub fn load() -> Result<Self> {
-        let config = Figment::new()
-            .merge(Toml::file("cs-cli.toml")) // Optional config file
-            .merge(Env::prefixed("CS_CLI_")) // Environment variables
+        Self::load_from(&ConfigSources::discover())
+    }
+
+    /// Load configuration using the given config files
+    pub fn load_from(sources: &ConfigSources) -> Result<Self> {
+        Self::check_config_files(sources)?;
+
+        let mut config: Self = sources
+            .figment(&Self::create_default())
             .extract()
             .map_err(|e| CsCliError::Configuration(format!("Configuration error: {e}")))?;
-
+        config.apply_env_overrides()?;
+        config.validate()?;
         Ok(config)
     }
 
+    /// Every setting with its default value, used to check config keys
+    pub fn schema() -> serde_json::Value {
+        serde_json::to_value(Self::create_default()).unwrap_or_default()
+    }
+
+    /// Reject config files containing settings that don't exist
+    pub fn check_config_files(sources: &ConfigSources) -> Result<()> {
+        let schema = Self::schema();
+        for path in sources.files() {
+            let unknown = unknown_keys(&read_config_file(path)?, &schema);
+            if !unknown.is_empty() {
+                return Err(CsCliError::Configuration(format!(
+                    "Unknown setting(s) in {}: {}",
+                    path.display(),
+                    unknown.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Validate configuration matching Python validate()
     pub fn validate(&self) -> Result<()> {
         if self.http.pool_size == 0 {
@@ -127,6 +432,21 @@ impl AppConfig {
                 "HTTP max_concurrency_per_client must be positive".to_string(),
             ));
         }
+        if let Some(spec) = &self.auth.keychain_password_from {
+            crate::common::auth::KeychainPasswordSource::parse(spec).map_err(|e| {
+                CsCliError::Configuration(format!("auth.keychain_password_from: {e}"))
+            })?;
+        }
+        if let Some(pin) = &self.update.pin {
+            crate::updater::parse_pin(pin)?;
+        }
+        if self.backend.kind == BackendKind::Api
+            && (self.backend.access_key.is_none() || self.backend.access_key_secret.is_none())
+        {
+            return Err(CsCliError::Configuration(
+                "The api backend needs GONG_ACCESS_KEY and GONG_ACCESS_KEY_SECRET".to_string(),
+            ));
+        }
         Ok(())
     }
 
@@ -135,6 +455,10 @@ impl AppConfig {
         Self {
             http,
             auth,
+            backend: BackendSettings::default(),
+            team: TeamSettings::default(),
+            update: UpdateSettings::default(),
+            output: OutputSettings::default(),
             debug: false,
             max_concurrent_timeline_requests: 105,
             max_concurrent_email_requests: 135,
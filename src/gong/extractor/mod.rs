@@ -0,0 +1,635 @@
+//! Headless extraction API
+//!
+//! [`Extractor`] runs customer extraction without any terminal UI so other Rust
+//! services can embed cs-cli. Progress goes to a [`Reporter`], results come back
+//! as a stream of [`Communication`]s or are written to an [`OutputSink`]. The
+//! CLI is one consumer of this API.
+//!
+//! ```no_run
+//! # async fn example() -> cs_cli::Result<()> {
+//! use cs_cli::gong::extractor::{ExtractRequest, Extractor, MarkdownSink};
+//! use futures::StreamExt;
+//!
+//! let extractor = Extractor::builder()
+//!     .with_sink(MarkdownSink::new(Some("/tmp/gong".into())))
+//!     .build()
+//!     .await?;
+//!
+//! let mut stream = extractor.extract_stream(ExtractRequest::new("Postman").with_days(30));
+//! while let Some(communication) = stream.next().await {
+//!     println!("{}", communication?.title);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod reporter;
+pub mod session;
+pub mod sink;
+
+pub use reporter::*;
+pub use session::*;
+pub use sink::*;
+
+use futures::stream::{self, Stream};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::common::auth::KeychainPasswordSource;
+use crate::common::events::{Event, Stage};
+use crate::gong::api::backend::GongBackend;
+use crate::gong::config::AppConfig;
+use crate::gong::models::{Call, Communication, Email, EmailFilterDecision};
+use crate::Result;
+
+/// Items buffered between the extraction task and a slow stream consumer
+const STREAM_BUFFER: usize = 32;
+
+/// What to extract for one customer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractRequest {
+    /// Customer name (partial names are resolved by Gong)
+    pub customer: String,
+    /// Days back to search
+    pub days: u32,
+    /// Include calls with transcripts
+    pub calls: bool,
+    /// Include emails
+    pub emails: bool,
+    /// Fetch full email bodies
+    pub fetch_email_bodies: bool,
+}
+
+impl ExtractRequest {
+    /// Calls and emails with bodies for the last 90 days
+    pub fn new(customer: impl Into<String>) -> Self {
+        Self {
+            customer: customer.into(),
+            days: 90,
+            calls: true,
+            emails: true,
+            fetch_email_bodies: true,
+        }
+    }
+
+    pub fn with_days(mut self, days: u32) -> Self {
+        self.days = days;
+        self
+    }
+
+    pub fn with_calls(mut self, calls: bool) -> Self {
+        self.calls = calls;
+        self
+    }
+
+    pub fn with_emails(mut self, emails: bool) -> Self {
+        self.emails = emails;
+        self
+    }
+
+    pub fn with_email_bodies(mut self, fetch_email_bodies: bool) -> Self {
+        self.fetch_email_bodies = fetch_email_bodies;
+        self
+    }
+}
+
+/// Result of extracting (and optionally saving) a single customer's communications
+#[derive(Debug, Clone)]
+pub struct CustomerExtraction {
+    /// Calls with transcripts
+    pub calls: Vec<Call>,
+    /// Filtered emails
+    pub emails: Vec<Email>,
+    /// Why each email was kept or dropped by the email filters
+    pub filter_decisions: Vec<EmailFilterDecision>,
+    /// Customer name as resolved by Gong search
+    pub resolved_name: String,
+    /// Files written for this customer
+    pub saved_files: Vec<PathBuf>,
+}
+
+/// Headless customer extractor
+///
+/// Cheap to clone; clones share the backend connection.
+#[derive(Clone)]
+pub struct Extractor {
+    backend: Arc<dyn GongBackend>,
+    reporter: Arc<dyn Reporter>,
+    sink: Arc<dyn OutputSink>,
+}
+
+/// Builder for [`Extractor`]
+#[derive(Default)]
+pub struct ExtractorBuilder {
+    config: Option<AppConfig>,
+    backend: Option<Arc<dyn GongBackend>>,
+    reporter: Option<Arc<dyn Reporter>>,
+    sink: Option<Arc<dyn OutputSink>>,
+    session: SessionOptions,
+}
+
+impl ExtractorBuilder {
+    /// Configuration used to connect (defaults to [`AppConfig::load`])
+    pub fn with_config(mut self, config: AppConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Use an already connected backend instead of connecting from the config
+    pub fn with_backend(mut self, backend: Arc<dyn GongBackend>) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Where progress is reported (defaults to [`NullReporter`])
+    pub fn with_reporter(mut self, reporter: impl Reporter + 'static) -> Self {
+        self.reporter = Some(Arc::new(reporter));
+        self
+    }
+
+    /// Where [`Extractor::extract_to_sink`] writes (defaults to [`MarkdownSink`])
+    pub fn with_sink(mut self, sink: impl OutputSink + 'static) -> Self {
+        self.sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// macOS keychain password for browser cookie access; without it the
+    /// extractor never prompts
+    pub fn with_keychain_password(mut self, password: Option<String>) -> Self {
+        self.session.keychain_password = match password {
+            Some(password) => KeychainPasswordSource::Value(password),
+            None => KeychainPasswordSource::Skip,
+        };
+        self
+    }
+
+    /// Read the macOS keychain password from stdin, a file descriptor, an
+    /// environment variable or a command when the keychain is locked
+    pub fn with_keychain_password_source(mut self, source: KeychainPasswordSource) -> Self {
+        self.session.keychain_password = source;
+        self
+    }
+
+    /// Ignore any persisted session and re-extract browser cookies
+    pub fn with_fresh_login(mut self, fresh_login: bool) -> Self {
+        self.session.fresh_login = fresh_login;
+        self
+    }
+
+    /// Connect to Gong (unless a backend was given) and build the extractor
+    pub async fn build(self) -> Result<Extractor> {
+        let reporter = self.reporter.unwrap_or_else(|| Arc::new(NullReporter));
+
+        let backend = match self.backend {
+            Some(backend) => backend,
+            None => {
+                let config = match self.config {
+                    Some(config) => config,
+                    None => AppConfig::load()?,
+                };
+                connect_backend(
+                    &config,
+                    &self.session,
+                    reporter.as_ref(),
+                    &unlock_keychain_without_prompt,
+                )
+                .await?
+            }
+        };
+
+        Ok(Extractor {
+            backend,
+            reporter,
+            sink: self
+                .sink
+                .unwrap_or_else(|| Arc::new(MarkdownSink::default())),
+        })
+    }
+}
+
+impl Extractor {
+    pub fn builder() -> ExtractorBuilder {
+        ExtractorBuilder::default()
+    }
+
+    /// Extract a customer's communications into memory
+    pub async fn extract(&self, request: &ExtractRequest) -> Result<CustomerExtraction> {
+        let (tx, mut rx) = mpsc::channel::<Result<Communication>>(STREAM_BUFFER);
+
+        let collect = async {
+            let mut calls = Vec::new();
+            let mut emails = Vec::new();
+            while let Some(Ok(communication)) = rx.recv().await {
+                if let Some(call) = communication.call {
+                    calls.push(call);
+                } else if let Some(email) = communication.email {
+                    emails.push(email);
+                }
+            }
+            (calls, emails)
+        };
+
+        let (outcome, (calls, emails)) = tokio::join!(self.run(request, tx), collect);
+        let (resolved_name, filter_decisions) = outcome?;
+
+        Ok(CustomerExtraction {
+            calls,
+            emails,
+            filter_decisions,
+            resolved_name,
+            saved_files: Vec::new(),
+        })
+    }
+
+    /// Extract a customer's communications and write them to the sink
+    pub async fn extract_to_sink(&self, request: &ExtractRequest) -> Result<CustomerExtraction> {
+        let mut extraction = self.extract(request).await?;
+
+        self.reporter.event(&Event::StageStarted {
+            stage: Stage::WriteFiles,
+            customer: Some(extraction.resolved_name.clone()),
+        });
+        self.sink
+            .write_filter_decisions(&extraction.resolved_name, &extraction.filter_decisions)?;
+        extraction.saved_files =
+            self.sink
+                .write(&extraction.resolved_name, &extraction.calls, &extraction.emails)?;
+        for path in &extraction.saved_files {
+            self.reporter.event(&Event::FileWritten { path: path.clone() });
+        }
+        self.reporter.event(&Event::StageFinished {
+            stage: Stage::WriteFiles,
+            customer: Some(extraction.resolved_name.clone()),
+            count: Some(extraction.saved_files.len()),
+        });
+
+        Ok(extraction)
+    }
+
+    /// Stream a customer's communications as they are fetched
+    ///
+    /// Emails arrive first (after body enhancement), then each call as soon as
+    /// its transcript is fetched. A failure ends the stream with an `Err` item.
+    /// Extraction runs on a spawned Tokio task and stops when the stream is dropped.
+    pub fn extract_stream(
+        &self,
+        request: ExtractRequest,
+    ) -> impl Stream<Item = Result<Communication>> + Send + 'static {
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+        let extractor = self.clone();
+
+        tokio::spawn(async move {
+            let error_tx = tx.clone();
+            if let Err(e) = extractor.run(&request, tx).await {
+                let _ = error_tx.send(Err(e)).await;
+            }
+        });
+
+        stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        })
+    }
+
+    /// Run the extraction, sending each communication to `tx`
+    ///
+    /// Returns the resolved customer name and the email filter decisions.
+    /// Stops early (successfully) when the receiver is dropped.
+    async fn run(
+        &self,
+        request: &ExtractRequest,
+        tx: mpsc::Sender<Result<Communication>>,
+    ) -> Result<(String, Vec<EmailFilterDecision>)> {
+        let reporter = self.reporter.as_ref();
+        let backend = self.backend.as_ref();
+        let name = request.customer.as_str();
+
+        let include_emails = request.emails && backend.supports_emails();
+        if request.emails && !backend.supports_emails() {
+            self.warn(format!(
+                "The {} backend cannot fetch emails; extracting calls only",
+                backend.name()
+            ));
+        }
+
+        // Date range
+        let now = jiff::Zoned::now();
+        let start_date = now
+            .checked_sub(jiff::Span::new().days(request.days as i64))
+            .unwrap_or(now.clone());
+        let end_date = now;
+
+        // Find customer accounts
+        reporter.event(&Event::StageStarted {
+            stage: Stage::ResolveCustomer,
+            customer: Some(name.to_string()),
+        });
+        let customer = backend
+            .resolve_customer(name, &start_date, &end_date)
+            .await?;
+        reporter.event(&Event::AccountResolved {
+            query: name.to_string(),
+            customer: customer.name.clone(),
+            account_ids: customer.account_ids.clone(),
+        });
+        reporter.event(&Event::StageFinished {
+            stage: Stage::ResolveCustomer,
+            customer: Some(customer.name.clone()),
+            count: Some(customer.account_ids.len()),
+        });
+
+        let resolved_name = customer.name;
+        reporter.message(
+            MessageLevel::Success,
+            &format!("Resolved customer name: '{resolved_name}'"),
+        );
+
+        if customer.account_ids.is_empty() {
+            self.warn(format!("No accounts found for customer '{name}'"));
+            return Ok((resolved_name, Vec::new()));
+        }
+
+        reporter.message(
+            MessageLevel::Success,
+            &format!(
+                "Found {} accounts for customer '{name}'",
+                customer.account_ids.len()
+            ),
+        );
+
+        // Communications from every account
+        reporter.event(&Event::StageStarted {
+            stage: Stage::ListCommunications,
+            customer: Some(resolved_name.clone()),
+        });
+        let mut calls: Vec<Call> = Vec::new();
+        let mut emails: Vec<Email> = Vec::new();
+        let mut filter_decisions: Vec<EmailFilterDecision> = Vec::new();
+
+        for account_id in &customer.account_ids {
+            let communications = backend
+                .account_communications(account_id, &start_date, &end_date)
+                .await
+                .inspect_err(|e| {
+                    warn!(
+                        account_id = %account_id,
+                        error = %e,
+                        "Failed to extract timeline for account"
+                    );
+                })?;
+
+            if request.calls {
+                calls.extend(communications.calls);
+            }
+            if include_emails {
+                emails.extend(communications.emails);
+                filter_decisions.extend(communications.filter_decisions);
+            }
+        }
+
+        reporter.message(
+            MessageLevel::Success,
+            &format!(
+                "Timeline extraction complete: {} calls, {} emails",
+                calls.len(),
+                emails.len()
+            ),
+        );
+        reporter.event(&Event::StageFinished {
+            stage: Stage::ListCommunications,
+            customer: Some(resolved_name.clone()),
+            count: Some(calls.len() + emails.len()),
+        });
+
+        // Emails, with bodies if requested
+        if !emails.is_empty() {
+            if request.fetch_email_bodies {
+                reporter.message(MessageLevel::Info, "Fetching email body content...");
+                reporter.event(&Event::StageStarted {
+                    stage: Stage::FetchEmailBodies,
+                    customer: Some(resolved_name.clone()),
+                });
+                let total = emails.len();
+                reporter.progress(Stage::FetchEmailBodies, 0, total);
+
+                emails = backend.enhance_emails(emails).await?;
+
+                reporter.progress(Stage::FetchEmailBodies, total, total);
+                reporter.event(&Event::StageFinished {
+                    stage: Stage::FetchEmailBodies,
+                    customer: Some(resolved_name.clone()),
+                    count: Some(emails.len()),
+                });
+                reporter.message(MessageLevel::Success, "Email body enhancement complete");
+            }
+
+            for email in emails {
+                if tx.send(Ok(Communication::from_email(email))).await.is_err() {
+                    return Ok((resolved_name, filter_decisions));
+                }
+            }
+        }
+
+        // Calls, each with its transcript
+        if !calls.is_empty() {
+            reporter.event(&Event::StageStarted {
+                stage: Stage::FetchTranscripts,
+                customer: Some(resolved_name.clone()),
+            });
+            let total = calls.len();
+            reporter.progress(Stage::FetchTranscripts, 0, total);
+
+            for (i, call) in calls.into_iter().enumerate() {
+                let call = self.with_transcript(call, &resolved_name).await?;
+                reporter.progress(Stage::FetchTranscripts, i + 1, total);
+                if tx.send(Ok(Communication::from_call(call))).await.is_err() {
+                    return Ok((resolved_name, filter_decisions));
+                }
+            }
+
+            reporter.event(&Event::StageFinished {
+                stage: Stage::FetchTranscripts,
+                customer: Some(resolved_name.clone()),
+                count: Some(total),
+            });
+        }
+
+        Ok((resolved_name, filter_decisions))
+    }
+
+    /// Fetch a call's transcript and generated title
+    async fn with_transcript(&self, mut call: Call, customer: &str) -> Result<Call> {
+        let mut has_transcript = false;
+
+        if let Some(details) = self.backend.call_transcript(&call).await? {
+            has_transcript = !details.transcript.is_empty();
+            call.transcript = if has_transcript {
+                Some(details.transcript)
+            } else {
+                Some("No transcript available.".to_string())
+            };
+
+            // Add generated title for intelligent file naming
+            if let Some(generated_title) = details.generated_title {
+                call.generated_title = Some(generated_title.clone());
+                // Also update title if it's generic
+                if call.title == "Call" || call.title.is_empty() {
+                    call.title = generated_title;
+                }
+            }
+        } else {
+            call.transcript = Some("No transcript available.".to_string());
+        }
+
+        call.customer_name = Some(customer.to_string());
+
+        self.reporter.event(&Event::CallFetched {
+            call_id: call.id.clone(),
+            title: call.title.clone(),
+            customer: Some(customer.to_string()),
+            has_transcript,
+        });
+
+        Ok(call)
+    }
+
+    /// Report a warning as both a message and an event
+    fn warn(&self, message: String) {
+        self.reporter.message(MessageLevel::Warning, &message);
+        self.reporter.event(&Event::Warning { message });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::api::backend::{AccountCommunications, CallTranscript, ResolvedCustomer};
+    use crate::gong::models::{CallDirection, EmailDirection, AUTOMATED_EMAIL_FILTER};
+    use async_trait::async_trait;
+    use futures::StreamExt;
+    use jiff::Zoned;
+
+    /// Backend returning one account with two calls and one email
+    struct FakeBackend;
+
+    #[async_trait]
+    impl GongBackend for FakeBackend {
+        fn name(&self) -> &'static str {
+            "fake"
+        }
+
+        fn supports_emails(&self) -> bool {
+            true
+        }
+
+        async fn resolve_customer(
+            &self,
+            _name: &str,
+            _start: &Zoned,
+            _end: &Zoned,
+        ) -> Result<ResolvedCustomer> {
+            Ok(ResolvedCustomer {
+                name: "Postman Inc".to_string(),
+                account_ids: vec!["acc-1".to_string()],
+            })
+        }
+
+        async fn account_communications(
+            &self,
+            account_id: &str,
+            _start: &Zoned,
+            _end: &Zoned,
+        ) -> Result<AccountCommunications> {
+            let call = |id: &str| {
+                Call::new(
+                    id.to_string(),
+                    account_id.to_string(),
+                    "Call".to_string(),
+                    CallDirection::Outbound,
+                    1800,
+                    Zoned::now(),
+                )
+            };
+            let email = |id: &str, subject: &str| {
+                Email::new(
+                    id.to_string(),
+                    account_id.to_string(),
+                    subject.to_string(),
+                    EmailDirection::Inbound,
+                    Zoned::now(),
+                    "buyer@postman.com".to_string(),
+                )
+            };
+            let kept = email("e1", "Renewal");
+            let dropped = email("e2", "Out of office");
+            Ok(AccountCommunications {
+                calls: vec![call("c1"), call("c2")],
+                filter_decisions: vec![
+                    EmailFilterDecision::kept(&kept),
+                    EmailFilterDecision::dropped(&dropped, AUTOMATED_EMAIL_FILTER, "auto-reply"),
+                ],
+                emails: vec![kept],
+            })
+        }
+
+        async fn call_transcript(&self, call: &Call) -> Result<Option<CallTranscript>> {
+            Ok(Some(CallTranscript {
+                transcript: format!("**Alice:** notes for {}", call.id),
+                generated_title: Some(format!("QBR {}", call.id)),
+            }))
+        }
+
+        async fn enhance_emails(&self, emails: Vec<Email>) -> Result<Vec<Email>> {
+            Ok(emails)
+        }
+    }
+
+    async fn extractor(reporter: Arc<RecordingReporter>) -> Extractor {
+        Extractor::builder()
+            .with_backend(Arc::new(FakeBackend))
+            .with_reporter(reporter)
+            .build()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_extract_collects_calls_and_emails() {
+        let reporter = Arc::new(RecordingReporter::new());
+        let extraction = extractor(reporter.clone())
+            .await
+            .extract(&ExtractRequest::new("postman"))
+            .await
+            .unwrap();
+
+        assert_eq!(extraction.resolved_name, "Postman Inc");
+        assert_eq!(extraction.calls.len(), 2);
+        assert_eq!(extraction.emails.len(), 1);
+        assert_eq!(extraction.filter_decisions.len(), 2);
+        assert!(!extraction.filter_decisions[1].kept);
+        assert_eq!(extraction.calls[0].title, "QBR c1");
+        assert_eq!(extraction.calls[0].customer_name.as_deref(), Some("Postman Inc"));
+
+        let fetched = reporter
+            .events()
+            .iter()
+            .filter(|e| matches!(e, Event::CallFetched { .. }))
+            .count();
+        assert_eq!(fetched, 2);
+    }
+
+    #[tokio::test]
+    async fn test_extract_stream_respects_request() {
+        let reporter = Arc::new(RecordingReporter::new());
+        let items: Vec<Communication> = extractor(reporter)
+            .await
+            .extract_stream(ExtractRequest::new("postman").with_emails(false))
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 2);
+        assert!(items.iter().all(|c| c.call.is_some()));
+    }
+}
@@ -0,0 +1,80 @@
+//! Progress reporting for headless extraction
+
+use std::sync::{Arc, Mutex};
+
+use crate::common::events::{Event, Stage};
+
+/// Severity of a human-readable progress message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageLevel {
+    /// Step in progress
+    Info,
+    /// Step completed
+    Success,
+    /// Something was skipped or missing
+    Warning,
+}
+
+/// Receives progress from an [`Extractor`](super::Extractor)
+///
+/// Every method defaults to doing nothing, so implementors only override what
+/// they care about: structured events, human-readable messages, or item counts
+/// for progress bars.
+pub trait Reporter: Send + Sync {
+    /// Structured progress event
+    fn event(&self, _event: &Event) {}
+
+    /// Human-readable progress message
+    fn message(&self, _level: MessageLevel, _text: &str) {}
+
+    /// Item-level progress within a stage (`done` of `total`)
+    fn progress(&self, _stage: Stage, _done: usize, _total: usize) {}
+}
+
+/// Shared reporters, e.g. one reporter observing several extractors
+impl<R: Reporter + ?Sized> Reporter for Arc<R> {
+    fn event(&self, event: &Event) {
+        (**self).event(event);
+    }
+
+    fn message(&self, level: MessageLevel, text: &str) {
+        (**self).message(level, text);
+    }
+
+    fn progress(&self, stage: Stage, done: usize, total: usize) {
+        (**self).progress(stage, done, total);
+    }
+}
+
+/// Reporter that discards all progress
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullReporter;
+
+impl Reporter for NullReporter {}
+
+/// Reporter that keeps every event in memory
+///
+/// Useful for services that show progress after the fact, and for tests.
+#[derive(Debug, Default)]
+pub struct RecordingReporter {
+    events: Mutex<Vec<Event>>,
+}
+
+impl RecordingReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events received so far
+    pub fn events(&self) -> Vec<Event> {
+        self.events.lock().map(|e| e.clone()).unwrap_or_default()
+    }
+}
+
+impl Reporter for RecordingReporter {
+    fn event(&self, event: &Event) {
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event.clone());
+        }
+    }
+}
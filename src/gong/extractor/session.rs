@@ -0,0 +1,156 @@
+//! Backend connection shared by the CLI and the headless extractor
+
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use super::reporter::{MessageLevel, Reporter};
+use crate::common::auth::{unlock_keychain_from, CookieImportSource, KeychainPasswordSource};
+use crate::gong::api::backend::{GongBackend, WebSessionBackend};
+use crate::gong::api::client::HttpClientPool;
+use crate::gong::api::public_api::PublicApiBackend;
+use crate::gong::auth::{GongAuthenticator, SessionStore};
+use crate::gong::config::{AppConfig, BackendKind};
+use crate::{CsCliError, Result};
+
+/// How to log into Gong when using the web backend
+#[derive(Debug, Clone, Default)]
+pub struct SessionOptions {
+    /// Where the macOS keychain password for decrypting browser cookies comes from
+    pub keychain_password: KeychainPasswordSource,
+    /// Ignore any persisted session and re-extract browser cookies
+    pub fresh_login: bool,
+}
+
+/// Authenticated HTTP client pool and authenticator for the web backend
+pub struct WebSession {
+    pub http: Arc<HttpClientPool>,
+    pub auth: Arc<GongAuthenticator>,
+}
+
+/// Log into Gong with the browser session
+///
+/// Imported cookies win, then a persisted session, then browser cookies.
+/// `unlock_keychain` runs right before browser cookies are read (and only then),
+/// so the caller decides whether a missing keychain password may prompt.
+pub async fn open_web_session(
+    config: &AppConfig,
+    options: &SessionOptions,
+    reporter: &dyn Reporter,
+    unlock_keychain: &(dyn Fn(&KeychainPasswordSource) -> Result<()> + Sync),
+) -> Result<WebSession> {
+    let http = HttpClientPool::new(Some(config.http.clone())).await?;
+
+    let mut auth = GongAuthenticator::new(config.auth.clone()).await?;
+    let session_store = if config.auth.persist_session {
+        SessionStore::default_location().ok()
+    } else {
+        None
+    };
+
+    if let Some(store) = &session_store {
+        if options.fresh_login {
+            store.clear()?;
+        }
+    }
+
+    // Imported cookies are explicit, so they win over any saved session
+    let cookie_import = config
+        .auth
+        .cookie_import
+        .as_deref()
+        .map(CookieImportSource::parse)
+        .transpose()?;
+
+    let restored = match &session_store {
+        Some(store) if !options.fresh_login && cookie_import.is_none() => {
+            auth.restore_session(store).await.unwrap_or_else(|e| {
+                warn!(error = %e, "Failed to restore persisted session");
+                false
+            })
+        }
+        _ => false,
+    };
+
+    if restored {
+        reporter.message(MessageLevel::Info, "Reusing saved Gong session");
+    } else {
+        let authenticated = match &cookie_import {
+            Some(source) => auth.authenticate_with_import(source).await?,
+            None => {
+                unlock_keychain(&options.keychain_password)?;
+                auth.authenticate().await?
+            }
+        };
+        if !authenticated {
+            let message = match &cookie_import {
+                Some(source) => format!(
+                    "Authentication failed: Gong did not accept the cookies from {}",
+                    source.label()
+                ),
+                None => "Authentication failed: No valid Gong browser session detected".to_string(),
+            };
+            return Err(CsCliError::Authentication(message));
+        }
+
+        if let Some(browser) = auth.get_auth_state().get("browser") {
+            reporter.message(
+                MessageLevel::Info,
+                &format!("Using Gong session from {browser}"),
+            );
+        }
+
+        if let Some(store) = &session_store {
+            if let Err(e) = auth.persist_session(store).await {
+                warn!(error = %e, "Failed to persist session");
+            }
+        }
+    }
+
+    // Set cookies on all HTTP clients in the pool
+    if auth.get_auth_state().get("authenticated") == Some(&"true".to_string()) {
+        if let Ok(session_cookies) = auth.get_session_cookies() {
+            http.set_cookies(session_cookies.clone()).await?;
+            info!(
+                cookies_count = session_cookies.len(),
+                "Cookies set on HTTP client pool"
+            );
+        }
+    }
+
+    Ok(WebSession {
+        http: Arc::new(http),
+        auth: Arc::new(auth),
+    })
+}
+
+/// Connect to the configured backend
+pub async fn connect_backend(
+    config: &AppConfig,
+    options: &SessionOptions,
+    reporter: &dyn Reporter,
+    unlock_keychain: &(dyn Fn(&KeychainPasswordSource) -> Result<()> + Sync),
+) -> Result<Arc<dyn GongBackend>> {
+    match config.backend.kind {
+        BackendKind::Api => Ok(Arc::new(PublicApiBackend::new(
+            &config.backend,
+            &config.http,
+        )?)),
+        BackendKind::Web => {
+            let session = open_web_session(config, options, reporter, unlock_keychain).await?;
+            Ok(Arc::new(WebSessionBackend::new(
+                session.http,
+                session.auth,
+                config,
+            )?))
+        }
+    }
+}
+
+/// Unlock the macOS keychain if it is locked and a password source was given;
+/// never prompts
+pub fn unlock_keychain_without_prompt(keychain_password: &KeychainPasswordSource) -> Result<()> {
+    match keychain_password {
+        KeychainPasswordSource::Prompt => Ok(()),
+        source => unlock_keychain_from(source),
+    }
+}
@@ -0,0 +1,343 @@
+//! Output sinks for extracted communications
+
+use std::path::PathBuf;
+
+use crate::gong::config::CsvSettings;
+use crate::gong::models::{Call, Email, EmailFilterDecision};
+use crate::gong::output::activity_csv::ActivityCsvWriter;
+use crate::gong::output::docx::DocxBriefWriter;
+use crate::gong::output::dossier::{Dossier, BRIEF_TRANSCRIPTS};
+use crate::gong::output::mailbox::MailboxWriter;
+use crate::gong::output::markdown::{CallMarkdownFormatter, CallSummaryReporter};
+use crate::gong::output::parquet::{default_parquet_path, ParquetWriter};
+use crate::gong::output::pdf::PdfBriefWriter;
+use crate::gong::output::report::HtmlReportWriter;
+use crate::gong::output::sqlite::{default_database_path, SqliteWriter};
+use crate::gong::output::vault::VaultWriter;
+use crate::{CsCliError, Result};
+
+/// Destination for a customer's extracted calls and emails
+pub trait OutputSink: Send + Sync {
+    /// Write a customer's communications, returning the files created
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>>;
+
+    /// Record why each email was kept or dropped; only sinks that store the
+    /// audit trail override this
+    fn write_filter_decisions(
+        &self,
+        _customer: &str,
+        _decisions: &[EmailFilterDecision],
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes the same markdown layout as the CLI: one file per call, batched
+/// email files and a `SUMMARY.md` per customer folder
+pub struct MarkdownSink {
+    formatter: CallMarkdownFormatter,
+    summary_reporter: CallSummaryReporter,
+}
+
+impl MarkdownSink {
+    /// Create a sink writing under `output_dir` (defaults to the Desktop)
+    pub fn new(output_dir: Option<PathBuf>) -> Self {
+        Self {
+            formatter: CallMarkdownFormatter::new(output_dir),
+            summary_reporter: CallSummaryReporter::new(),
+        }
+    }
+}
+
+impl Default for MarkdownSink {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl OutputSink for MarkdownSink {
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        let mut saved_files = Vec::new();
+
+        if !calls.is_empty() {
+            let call_files = self
+                .formatter
+                .save_multiple_calls(calls, Some(customer))
+                .map_err(|e| CsCliError::FileIo(format!("Failed to save calls: {e}")))?;
+
+            if let Some(output_dir) = call_files.first().and_then(|f| f.parent()) {
+                let summary_path = output_dir.join("SUMMARY.md");
+                self.summary_reporter
+                    .generate_summary_report(calls, Some(&summary_path), Some(customer))
+                    .map_err(|e| CsCliError::FileIo(format!("Failed to generate summary: {e}")))?;
+                saved_files.push(summary_path);
+            }
+            saved_files.extend(call_files);
+        }
+
+        if !emails.is_empty() {
+            let email_files = self
+                .formatter
+                .save_emails_as_markdown(emails, customer, Some(customer))
+                .map_err(|e| CsCliError::FileIo(format!("Failed to save emails: {e}")))?;
+            saved_files.extend(email_files);
+        }
+
+        Ok(saved_files)
+    }
+}
+
+/// Writes a self-contained HTML report (`index.html` plus a page per call)
+/// into each customer folder
+pub struct HtmlSink {
+    formatter: CallMarkdownFormatter,
+}
+
+impl HtmlSink {
+    /// Create a sink writing under `output_dir` (defaults to the Desktop)
+    pub fn new(output_dir: Option<PathBuf>) -> Self {
+        Self {
+            formatter: CallMarkdownFormatter::new(output_dir),
+        }
+    }
+}
+
+impl Default for HtmlSink {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl OutputSink for HtmlSink {
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        if calls.is_empty() && emails.is_empty() {
+            return Ok(Vec::new());
+        }
+        HtmlReportWriter::new(self.formatter.customer_output_dir(customer))
+            .write(customer, calls, emails)
+    }
+}
+
+/// Writes Obsidian/Logseq notes with front matter and `[[wikilinks]]`
+///
+/// With a vault path every customer shares one vault, so people and customer
+/// index notes link across customers; otherwise each customer folder is its
+/// own vault.
+pub struct VaultSink {
+    formatter: CallMarkdownFormatter,
+    vault_path: Option<PathBuf>,
+}
+
+impl VaultSink {
+    /// Create a sink writing into `vault_path`, or each customer folder under
+    /// `output_dir` (defaults to the Desktop)
+    pub fn new(output_dir: Option<PathBuf>, vault_path: Option<PathBuf>) -> Self {
+        Self {
+            formatter: CallMarkdownFormatter::new(output_dir),
+            vault_path,
+        }
+    }
+}
+
+impl Default for VaultSink {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl OutputSink for VaultSink {
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        if calls.is_empty() && emails.is_empty() {
+            return Ok(Vec::new());
+        }
+        let vault = match &self.vault_path {
+            Some(path) => path.clone(),
+            None => self.formatter.customer_output_dir(customer),
+        };
+        VaultWriter::new(vault).write(customer, calls, emails)
+    }
+}
+
+/// Writes a DOCX or PDF customer brief into each customer folder
+pub struct BriefSink {
+    formatter: CallMarkdownFormatter,
+    pdf: bool,
+}
+
+impl BriefSink {
+    /// Word briefs under `output_dir` (defaults to the Desktop)
+    pub fn docx(output_dir: Option<PathBuf>) -> Self {
+        Self {
+            formatter: CallMarkdownFormatter::new(output_dir),
+            pdf: false,
+        }
+    }
+
+    /// PDF briefs under `output_dir` (defaults to the Desktop)
+    pub fn pdf(output_dir: Option<PathBuf>) -> Self {
+        Self {
+            formatter: CallMarkdownFormatter::new(output_dir),
+            pdf: true,
+        }
+    }
+}
+
+impl OutputSink for BriefSink {
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        if calls.is_empty() && emails.is_empty() {
+            return Ok(Vec::new());
+        }
+        let output_dir = self.formatter.customer_output_dir(customer);
+        let dossier = Dossier::new(customer, calls, emails, BRIEF_TRANSCRIPTS);
+        let path = if self.pdf {
+            PdfBriefWriter::new(output_dir).write(&dossier)?
+        } else {
+            DocxBriefWriter::new(output_dir).write(&dossier)?
+        };
+        Ok(vec![path])
+    }
+}
+
+/// Writes calls as markdown and emails as `.eml` files plus a per-customer
+/// mbox that mail clients can import
+pub struct MailboxSink {
+    markdown: MarkdownSink,
+    formatter: CallMarkdownFormatter,
+}
+
+impl MailboxSink {
+    /// Create a sink writing under `output_dir` (defaults to the Desktop)
+    pub fn new(output_dir: Option<PathBuf>) -> Self {
+        Self {
+            markdown: MarkdownSink::new(output_dir.clone()),
+            formatter: CallMarkdownFormatter::new(output_dir),
+        }
+    }
+}
+
+impl Default for MailboxSink {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl OutputSink for MailboxSink {
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        let mut saved_files = self.markdown.write(customer, calls, &[])?;
+        if !emails.is_empty() {
+            saved_files.extend(
+                MailboxWriter::new(self.formatter.customer_output_dir(customer))
+                    .write(customer, emails)?,
+            );
+        }
+        Ok(saved_files)
+    }
+}
+
+/// Upserts every customer into one SQLite database
+pub struct SqliteSink {
+    database_path: PathBuf,
+}
+
+impl SqliteSink {
+    /// Write to `database_path` (defaults to `gong.sqlite` on the Desktop)
+    pub fn new(database_path: Option<PathBuf>) -> Self {
+        Self {
+            database_path: database_path.unwrap_or_else(default_database_path),
+        }
+    }
+}
+
+impl Default for SqliteSink {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl OutputSink for SqliteSink {
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        if calls.is_empty() && emails.is_empty() {
+            return Ok(Vec::new());
+        }
+        SqliteWriter::new(&self.database_path).write(customer, calls, emails)?;
+        Ok(vec![self.database_path.clone()])
+    }
+
+    fn write_filter_decisions(
+        &self,
+        customer: &str,
+        decisions: &[EmailFilterDecision],
+    ) -> Result<()> {
+        if decisions.is_empty() {
+            return Ok(());
+        }
+        SqliteWriter::new(&self.database_path).write_filter_decisions(customer, decisions)
+    }
+}
+
+/// Writes every customer into one partitioned Parquet dataset
+pub struct ParquetSink {
+    root: PathBuf,
+}
+
+impl ParquetSink {
+    /// Write under `root` (defaults to `gong-parquet` on the Desktop)
+    pub fn new(root: Option<PathBuf>) -> Self {
+        Self {
+            root: root.unwrap_or_else(default_parquet_path),
+        }
+    }
+}
+
+impl Default for ParquetSink {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl OutputSink for ParquetSink {
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        ParquetWriter::new(&self.root).write(customer, calls, emails)
+    }
+}
+
+/// Writes a CRM activity CSV per customer
+pub struct ActivityCsvSink {
+    formatter: CallMarkdownFormatter,
+    settings: CsvSettings,
+}
+
+impl ActivityCsvSink {
+    /// Write into `settings.path`, or each customer folder under `output_dir`
+    /// (defaults to the Desktop)
+    pub fn new(output_dir: Option<PathBuf>, settings: CsvSettings) -> Self {
+        Self {
+            formatter: CallMarkdownFormatter::new(output_dir),
+            settings,
+        }
+    }
+}
+
+impl Default for ActivityCsvSink {
+    fn default() -> Self {
+        Self::new(None, CsvSettings::default())
+    }
+}
+
+impl OutputSink for ActivityCsvSink {
+    fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        if calls.is_empty() && emails.is_empty() {
+            return Ok(Vec::new());
+        }
+        let output_dir = match &self.settings.path {
+            Some(path) => path.clone(),
+            None => self.formatter.customer_output_dir(customer),
+        };
+        let path = ActivityCsvWriter::new(output_dir)
+            .with_columns(self.settings.columns())
+            .with_account_external_id(self.settings.account_id(customer))
+            .with_escape_formulas(self.settings.escape_formulas)
+            .write(customer, calls, emails)?;
+        Ok(vec![path])
+    }
+}
@@ -260,3 +260,59 @@ impl Email {
             .find(|r| r.recipient_type.to_lowercase() == "to")
     }
 }
+
+/// Filter that drops automated, out-of-office and BDR template emails
+pub const AUTOMATED_EMAIL_FILTER: &str = "automated_and_bdr_emails";
+
+/// Filter that collapses duplicate and mass emails to one representative
+pub const DUPLICATE_EMAIL_FILTER: &str = "duplicate_and_mass_emails";
+
+/// What the email filters decided about one extracted email
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmailFilterDecision {
+    pub email_id: String,
+    pub account_id: String,
+    pub subject: String,
+    pub sender_email: String,
+    pub sent_at: Zoned,
+    /// Whether the email made it into the output
+    pub kept: bool,
+    /// Filter that dropped the email, see [`AUTOMATED_EMAIL_FILTER`] and
+    /// [`DUPLICATE_EMAIL_FILTER`]
+    pub filter: Option<String>,
+    /// Why the filter dropped it
+    pub reason: Option<String>,
+    pub is_automated: bool,
+    pub is_template: bool,
+    /// Highest similarity to another email from the same sender, when that
+    /// is what dropped it
+    pub similarity_score: Option<f64>,
+}
+
+impl EmailFilterDecision {
+    /// An email that passed every filter
+    pub fn kept(email: &Email) -> Self {
+        Self::new(email, true, None, None)
+    }
+
+    /// An email removed by `filter`
+    pub fn dropped(email: &Email, filter: &str, reason: impl Into<String>) -> Self {
+        Self::new(email, false, Some(filter.to_string()), Some(reason.into()))
+    }
+
+    fn new(email: &Email, kept: bool, filter: Option<String>, reason: Option<String>) -> Self {
+        Self {
+            email_id: email.id.clone(),
+            account_id: email.account_id.clone(),
+            subject: email.subject.clone(),
+            sender_email: email.sender.email.clone(),
+            sent_at: email.sent_at.clone(),
+            kept,
+            filter,
+            reason,
+            is_automated: email.is_automated,
+            is_template: email.is_template,
+            similarity_score: email.similarity_score,
+        }
+    }
+}
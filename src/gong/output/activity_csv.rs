@@ -0,0 +1,537 @@
+//! CSV activity export for CRM imports
+//!
+//! Writes one row per call or email with a configurable column layout, so
+//! RevOps can back-fill CRM activities from data that's already extracted.
+//! Presets match the usual Salesforce task and HubSpot activity imports;
+//! `output.csv.columns` replaces them with any headers and fields:
+//!
+//! ```toml
+//! [output.csv]
+//! columns = [
+//!     { header = "Subject", field = "subject" },
+//!     { header = "Status", value = "Completed" },
+//! ]
+//! ```
+//!
+//! Values are quoted per RFC 4180, so multi-line summaries, commas and quotes
+//! survive the import. Values that a spreadsheet would read as a formula
+//! (starting with `=`, `+`, `-`, `@`, a tab or a carriage return) get a
+//! leading `'` unless `output.csv.escape_formulas` is off.
+
+use jiff::Zoned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tracing::info;
+
+use super::slug::slug;
+use crate::gong::models::{Call, Email};
+use crate::{CsCliError, Result};
+
+/// Length of email body previews in `summary`
+const EMAIL_SUMMARY_CHARS: usize = 1000;
+
+/// Column layouts for common CRM imports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CsvPreset {
+    /// Every field with readable headers (default)
+    #[default]
+    Generic,
+    /// Salesforce Task import (Data Import Wizard or Data Loader)
+    Salesforce,
+    /// HubSpot call and email activity import
+    Hubspot,
+}
+
+impl CsvPreset {
+    /// Columns this preset writes
+    pub fn columns(self) -> Vec<CsvColumn> {
+        use ActivityField::*;
+        let columns: &[(&str, Option<ActivityField>, Option<&str>)] = match self {
+            CsvPreset::Generic => &[
+                ("Activity Type", Some(ActivityType), None),
+                ("Subject", Some(Subject), None),
+                ("Date", Some(Datetime), None),
+                ("Duration (seconds)", Some(DurationSeconds), None),
+                ("Direction", Some(Direction), None),
+                ("Owner", Some(OwnerEmail), None),
+                ("Participants", Some(ParticipantEmails), None),
+                ("Customer", Some(Customer), None),
+                ("Account ID", Some(AccountId), None),
+                ("Gong Link", Some(GongLink), None),
+                ("Summary", Some(Summary), None),
+            ],
+            CsvPreset::Salesforce => &[
+                ("Subject", Some(Subject), None),
+                ("ActivityDate", Some(Date), None),
+                ("Type", Some(ActivityType), None),
+                ("TaskSubtype", Some(ActivityType), None),
+                ("Status", None, Some("Completed")),
+                ("CallDurationInSeconds", Some(DurationSeconds), None),
+                ("CallType", Some(Direction), None),
+                ("WhatId", Some(AccountExternalId), None),
+                ("Owner Email", Some(OwnerEmail), None),
+                ("Contact Emails", Some(ExternalEmails), None),
+                ("Description", Some(Notes), None),
+            ],
+            CsvPreset::Hubspot => &[
+                ("Activity Type", Some(ActivityType), None),
+                ("Activity Date", Some(Datetime), None),
+                ("Subject", Some(Subject), None),
+                ("Notes", Some(Notes), None),
+                ("Duration (ms)", Some(DurationMilliseconds), None),
+                ("Direction", Some(Direction), None),
+                ("Activity Assigned To", Some(OwnerEmail), None),
+                ("Contact Email", Some(ExternalEmails), None),
+                ("Company Record ID", Some(AccountExternalId), None),
+            ],
+        };
+        columns
+            .iter()
+            .map(|(header, field, value)| CsvColumn {
+                header: header.to_string(),
+                field: *field,
+                value: value.map(str::to_string),
+            })
+            .collect()
+    }
+}
+
+/// What a CSV column contains
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityField {
+    /// `Call` or `Email`
+    ActivityType,
+    /// Gong call or email ID
+    Id,
+    /// Call title or email subject
+    Subject,
+    /// `YYYY-MM-DD`
+    Date,
+    /// RFC 3339 with the UTC offset
+    Datetime,
+    DurationSeconds,
+    DurationMinutes,
+    DurationMilliseconds,
+    /// `Inbound`, `Outbound` or `Internal`
+    Direction,
+    /// Everyone on the call or email, `;`-separated
+    ParticipantEmails,
+    /// Customer-side addresses only
+    ExternalEmails,
+    /// Our side only
+    InternalEmails,
+    /// First internal person: the rep who owns the activity
+    OwnerEmail,
+    /// Customer name
+    Customer,
+    /// Gong account ID (the CRM account ID with the public API backend)
+    AccountId,
+    /// `output.csv.account_ids` entry for the customer, else the account ID
+    AccountExternalId,
+    /// Link to the call in Gong; empty for emails
+    GongLink,
+    /// Call brief or summary, or the start of the email body
+    Summary,
+    /// Summary followed by the Gong link, for CRMs without a URL field
+    Notes,
+}
+
+/// One CSV column: a header and either a field or a fixed value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CsvColumn {
+    pub header: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<ActivityField>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// Writes `<customer>-activities.csv`
+pub struct ActivityCsvWriter {
+    output_dir: PathBuf,
+    columns: Vec<CsvColumn>,
+    account_external_id: Option<String>,
+    escape_formulas: bool,
+}
+
+impl ActivityCsvWriter {
+    /// Write into `output_dir` with the generic columns
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            columns: CsvPreset::Generic.columns(),
+            account_external_id: None,
+            escape_formulas: true,
+        }
+    }
+
+    /// Use these columns instead
+    pub fn with_columns(mut self, columns: Vec<CsvColumn>) -> Self {
+        if !columns.is_empty() {
+            self.columns = columns;
+        }
+        self
+    }
+
+    /// CRM account ID for `account_external_id`
+    pub fn with_account_external_id(mut self, id: Option<String>) -> Self {
+        self.account_external_id = id;
+        self
+    }
+
+    /// Whether to defuse values a spreadsheet would run as formulas (default on)
+    pub fn with_escape_formulas(mut self, escape_formulas: bool) -> Self {
+        self.escape_formulas = escape_formulas;
+        self
+    }
+
+    /// Save the CSV, returning its path
+    pub fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<PathBuf> {
+        fs::create_dir_all(&self.output_dir)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create output directory: {e}")))?;
+
+        let path = self
+            .output_dir
+            .join(format!("{}-activities.csv", slug(customer, "customer")));
+        fs::write(&path, self.to_csv(customer, calls, emails))
+            .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))?;
+
+        info!(
+            path = %path.display(),
+            rows = calls.len() + emails.len(),
+            "Saved activity CSV"
+        );
+        Ok(path)
+    }
+
+    /// Header plus one row per call and email, oldest first, CRLF line endings
+    pub fn to_csv(&self, customer: &str, calls: &[Call], emails: &[Email]) -> String {
+        let mut activities: Vec<Activity> = calls
+            .iter()
+            .map(Activity::from_call)
+            .chain(emails.iter().map(Activity::from_email))
+            .collect();
+        activities.sort_by(|a, b| a.date.cmp(b.date));
+
+        let mut csv = self.row(self.columns.iter().map(|c| c.header.clone()));
+        for activity in &activities {
+            csv.push_str(&self.row(self.columns.iter().map(|column| {
+                match (&column.field, &column.value) {
+                    (Some(field), _) => self.value(activity, *field, customer),
+                    (None, Some(value)) => value.clone(),
+                    (None, None) => String::new(),
+                }
+            })));
+        }
+        csv
+    }
+
+    /// One RFC 4180 record
+    fn row(&self, values: impl Iterator<Item = String>) -> String {
+        let mut line = values
+            .map(|value| {
+                if self.escape_formulas {
+                    escape(&defuse_formula(&value))
+                } else {
+                    escape(&value)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        line.push_str("\r\n");
+        line
+    }
+
+    fn value(&self, activity: &Activity, field: ActivityField, customer: &str) -> String {
+        let emails = |internal: Option<bool>| {
+            let mut emails: Vec<&str> = Vec::new();
+            for (email, is_internal) in &activity.people {
+                if internal != Some(!*is_internal)
+                    && !emails.iter().any(|e| e.eq_ignore_ascii_case(email))
+                {
+                    emails.push(email);
+                }
+            }
+            emails.join(";")
+        };
+
+        match field {
+            ActivityField::ActivityType => activity.kind.to_string(),
+            ActivityField::Id => activity.id.to_string(),
+            ActivityField::Subject => activity.subject.to_string(),
+            ActivityField::Date => activity.date.strftime("%Y-%m-%d").to_string(),
+            ActivityField::Datetime => activity.date.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+            ActivityField::DurationSeconds => duration(activity.seconds, 1),
+            ActivityField::DurationMinutes => activity
+                .seconds
+                .map(|s| ((s + 30) / 60).to_string())
+                .unwrap_or_default(),
+            ActivityField::DurationMilliseconds => duration(activity.seconds, 1000),
+            ActivityField::Direction => activity.direction.clone(),
+            ActivityField::ParticipantEmails => emails(None),
+            ActivityField::ExternalEmails => emails(Some(false)),
+            ActivityField::InternalEmails => emails(Some(true)),
+            ActivityField::OwnerEmail => activity
+                .people
+                .iter()
+                .find(|(_, internal)| *internal)
+                .map(|(email, _)| email.to_string())
+                .unwrap_or_default(),
+            ActivityField::Customer => customer.to_string(),
+            ActivityField::AccountId => activity.account_id.to_string(),
+            ActivityField::AccountExternalId => self
+                .account_external_id
+                .clone()
+                .unwrap_or_else(|| activity.account_id.to_string()),
+            ActivityField::GongLink => activity.link.clone().unwrap_or_default(),
+            ActivityField::Summary => activity.summary.clone(),
+            ActivityField::Notes => match &activity.link {
+                Some(link) if activity.summary.is_empty() => format!("Gong: {link}"),
+                Some(link) => format!("{}\n\nGong: {link}", activity.summary),
+                None => activity.summary.clone(),
+            },
+        }
+    }
+}
+
+/// A call or email reduced to what CRM activities need
+struct Activity<'a> {
+    kind: &'static str,
+    id: &'a str,
+    subject: &'a str,
+    date: &'a Zoned,
+    seconds: Option<i64>,
+    direction: String,
+    account_id: &'a str,
+    /// Email addresses with whether they're internal; owner candidates first
+    people: Vec<(&'a str, bool)>,
+    summary: String,
+    link: Option<String>,
+}
+
+impl<'a> Activity<'a> {
+    fn from_call(call: &'a Call) -> Self {
+        let link = call
+            .recording_url
+            .clone()
+            .filter(|url| url.contains("/call?id="))
+            .unwrap_or_else(|| format!("https://app.gong.io/call?id={}", call.id));
+        let summary = call
+            .call_brief
+            .as_deref()
+            .or(call.summary.as_deref())
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+
+        Self {
+            kind: "Call",
+            id: &call.id,
+            subject: &call.title,
+            date: &call.scheduled_start,
+            seconds: Some(i64::from(call.duration.max(0))),
+            direction: format!("{:?}", call.direction),
+            account_id: &call.account_id,
+            people: call
+                .participants
+                .iter()
+                .filter_map(|p| Some((p.email.as_deref()?, p.is_internal)))
+                .filter(|(email, _)| !email.is_empty())
+                .collect(),
+            summary,
+            link: Some(link),
+        }
+    }
+
+    fn from_email(email: &'a Email) -> Self {
+        let body = email
+            .body_text
+            .as_deref()
+            .or(email.snippet.as_deref())
+            .unwrap_or_default()
+            .trim();
+        let mut summary: String = body.chars().take(EMAIL_SUMMARY_CHARS).collect();
+        if summary.len() < body.len() {
+            summary.push_str("...");
+        }
+
+        Self {
+            kind: "Email",
+            id: &email.id,
+            subject: &email.subject,
+            date: &email.sent_at,
+            seconds: None,
+            direction: format!("{:?}", email.direction),
+            account_id: &email.account_id,
+            people: std::iter::once(&email.sender)
+                .chain(&email.recipients)
+                .filter(|r| !r.email.is_empty())
+                .map(|r| (r.email.as_str(), r.is_internal))
+                .collect(),
+            summary,
+            link: None,
+        }
+    }
+}
+
+fn duration(seconds: Option<i64>, scale: i64) -> String {
+    seconds.map(|s| (s * scale).to_string()).unwrap_or_default()
+}
+
+/// Prefix values starting with a formula character with `'`, so a
+/// spreadsheet shows `=HYPERLINK(...)` in a subject instead of running it
+fn defuse_formula(value: &str) -> String {
+    if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Quote values containing separators, quotes or line breaks; line breaks
+/// inside values are normalized to CRLF
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        let normalized = value.replace("\r\n", "\n").replace('\r', "\n");
+        format!(
+            "\"{}\"",
+            normalized.replace('"', "\"\"").replace('\n', "\r\n")
+        )
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{CallDirection, CallParticipant, EmailDirection};
+
+    #[test]
+    fn test_salesforce_preset_and_escaping() {
+        let mut call = Call::new(
+            "77823".to_string(),
+            "0013600000ABC".to_string(),
+            "QBR, part \"2\"".to_string(),
+            CallDirection::Outbound,
+            1830,
+            "2025-01-15T11:00:00-05:00[America/New_York]"
+                .parse()
+                .unwrap(),
+        );
+        call.call_brief = Some("Renewal risk.\nNext step: pricing".to_string());
+        for (email, internal) in [("dana@acme.com", false), ("sam@postman.com", true)] {
+            call.participants.push(CallParticipant {
+                id: None,
+                name: email.to_string(),
+                email: Some(email.to_string()),
+                phone: None,
+                title: None,
+                company: None,
+                is_internal: internal,
+                speaking_time: None,
+                talk_ratio: None,
+            });
+        }
+        let email = Email::new(
+            "e1".to_string(),
+            "0013600000ABC".to_string(),
+            "Follow-up".to_string(),
+            EmailDirection::Inbound,
+            "2025-01-10T09:00:00+00:00[UTC]".parse().unwrap(),
+            "dana@acme.com".to_string(),
+        );
+
+        let csv = ActivityCsvWriter::new("/tmp")
+            .with_columns(CsvPreset::Salesforce.columns())
+            .with_account_external_id(Some("001XYZ".to_string()))
+            .to_csv("Acme", &[call], &[email]);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+
+        assert_eq!(
+            lines[0],
+            "Subject,ActivityDate,Type,TaskSubtype,Status,CallDurationInSeconds,CallType,WhatId,Owner Email,Contact Emails,Description"
+        );
+        assert_eq!(
+            lines[1],
+            "Follow-up,2025-01-10,Email,Email,Completed,,Inbound,001XYZ,,dana@acme.com,"
+        );
+        assert_eq!(
+            lines[2],
+            "\"QBR, part \"\"2\"\"\",2025-01-15,Call,Call,Completed,1830,Outbound,001XYZ,sam@postman.com,dana@acme.com,\"Renewal risk."
+        );
+        assert_eq!(lines[3], "Next step: pricing");
+        assert_eq!(lines[4], "");
+        assert_eq!(lines[5], "Gong: https://app.gong.io/call?id=77823\"");
+    }
+
+    #[test]
+    fn test_custom_columns_from_config() {
+        let columns: Vec<CsvColumn> = toml::from_str::<toml::Table>(
+            r#"columns = [
+                { header = "When", field = "datetime" },
+                { header = "Minutes", field = "duration_minutes" },
+                { header = "Source", value = "Gong" },
+            ]"#,
+        )
+        .unwrap()["columns"]
+            .clone()
+            .try_into()
+            .unwrap();
+
+        let call = Call::new(
+            "1".to_string(),
+            "acct".to_string(),
+            "Sync".to_string(),
+            CallDirection::Internal,
+            1790,
+            "2025-01-15T11:00:00-05:00[America/New_York]"
+                .parse()
+                .unwrap(),
+        );
+        let csv = ActivityCsvWriter::new("/tmp")
+            .with_columns(columns)
+            .to_csv("Acme", &[call], &[]);
+        assert_eq!(
+            csv,
+            "When,Minutes,Source\r\n2025-01-15T11:00:00-05:00,30,Gong\r\n"
+        );
+    }
+
+    #[test]
+    fn test_formula_values_are_defused() {
+        let call = Call::new(
+            "1".to_string(),
+            "acct".to_string(),
+            "=HYPERLINK(\"http://evil\",\"x\")".to_string(),
+            CallDirection::Outbound,
+            60,
+            "2025-01-15T11:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        let columns = vec![CsvColumn {
+            header: "Subject".to_string(),
+            field: Some(ActivityField::Subject),
+            value: None,
+        }];
+        let writer = ActivityCsvWriter::new("/tmp").with_columns(columns);
+
+        let csv = writer.to_csv("Acme", std::slice::from_ref(&call), &[]);
+        assert_eq!(
+            csv,
+            "Subject\r\n\"'=HYPERLINK(\"\"http://evil\"\",\"\"x\"\")\"\r\n"
+        );
+
+        let csv = writer
+            .with_escape_formulas(false)
+            .to_csv("Acme", &[call], &[]);
+        assert!(csv.contains("\r\n\"=HYPERLINK("));
+
+        // Some spreadsheets skip leading whitespace before reading a formula
+        assert_eq!(defuse_formula("\t=1+1"), "'\t=1+1");
+        assert_eq!(defuse_formula("\r=1+1"), "'\r=1+1");
+        assert_eq!(defuse_formula("Renewal"), "Renewal");
+    }
+}
@@ -0,0 +1,193 @@
+//! Word (DOCX) customer brief
+//!
+//! Renders a [`Dossier`] with `docx-rs`, so no office suite is needed.
+
+use docx_rs::{
+    AlignmentType, BreakType, Docx, Paragraph, Run, Table, TableCell, TableRow, WidthType,
+};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use super::dossier::{ActivityKind, Dossier};
+use super::report::SPEAKER_COLORS;
+use crate::{CsCliError, Result};
+
+/// Postman orange, used for headings
+const ACCENT: &str = "FF6C37";
+const MUTED: &str = "6B7280";
+
+/// Writes customer briefs as `.docx`
+pub struct DocxBriefWriter {
+    output_dir: PathBuf,
+}
+
+impl DocxBriefWriter {
+    /// Write into `output_dir`, usually the customer's `ct_<customer>` folder
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// Save the brief, returning its path
+    pub fn write(&self, dossier: &Dossier) -> Result<PathBuf> {
+        fs::create_dir_all(&self.output_dir)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create output directory: {e}")))?;
+
+        let path = dossier.file_path(&self.output_dir, "docx");
+        save(build(dossier), &path)?;
+
+        info!(path = %path.display(), "Saved DOCX brief");
+        Ok(path)
+    }
+}
+
+/// Build the document: cover, summary, activity, transcripts
+fn build(dossier: &Dossier) -> Docx {
+    let mut docx = Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_break(BreakType::TextWrapping)))
+        .add_paragraph(Paragraph::new().align(AlignmentType::Center).add_run(text(
+            "Customer brief",
+            28,
+            MUTED,
+        )))
+        .add_paragraph(
+            Paragraph::new()
+                .align(AlignmentType::Center)
+                .add_run(text(&dossier.customer, 64, ACCENT).bold()),
+        )
+        .add_paragraph(Paragraph::new().align(AlignmentType::Center).add_run(text(
+            &dossier.period(),
+            28,
+            MUTED,
+        )))
+        .add_paragraph(Paragraph::new().align(AlignmentType::Center).add_run(text(
+            &format!(
+                "Prepared {} with cs-cli",
+                dossier.generated_at.strftime("%B %d, %Y")
+            ),
+            20,
+            MUTED,
+        )))
+        .add_paragraph(page_break());
+
+    docx = docx.add_paragraph(heading("Summary"));
+    let rows = dossier
+        .stats()
+        .into_iter()
+        .map(|(label, value)| {
+            TableRow::new(vec![
+                TableCell::new()
+                    .width(3200, WidthType::Dxa)
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text(label).bold())),
+                TableCell::new()
+                    .width(5800, WidthType::Dxa)
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text(value))),
+            ])
+        })
+        .collect();
+    docx = docx.add_table(Table::new(rows).set_grid(vec![3200, 5800]));
+
+    docx = docx.add_paragraph(heading("Activity"));
+    if dossier.activity.is_empty() {
+        docx = docx.add_paragraph(Paragraph::new().add_run(text("No calls or emails", 20, MUTED)));
+    }
+    for item in &dossier.activity {
+        let kind = match item.kind {
+            ActivityKind::Call => "Call",
+            ActivityKind::Email => "Email",
+        };
+        docx = docx
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(text(
+                        &format!("{}  ·  {kind}  ·  ", item.date.strftime("%b %d, %Y")),
+                        20,
+                        MUTED,
+                    ))
+                    .add_run(Run::new().add_text(&item.title).bold().size(22)),
+            )
+            .add_paragraph(Paragraph::new().add_run(text(&item.detail, 18, MUTED)));
+    }
+
+    for excerpt in &dossier.transcripts {
+        docx = docx
+            .add_paragraph(page_break())
+            .add_paragraph(heading(&excerpt.title))
+            .add_paragraph(Paragraph::new().add_run(text(&excerpt.meta, 20, MUTED)));
+
+        let mut colors: Vec<&str> = Vec::new();
+        for (speaker, line) in &excerpt.turns {
+            let mut paragraph = Paragraph::new();
+            if let Some(speaker) = speaker {
+                let color = match colors.iter().position(|s| *s == speaker.as_str()) {
+                    Some(index) => SPEAKER_COLORS[index % SPEAKER_COLORS.len()],
+                    None => {
+                        colors.push(speaker);
+                        SPEAKER_COLORS[(colors.len() - 1) % SPEAKER_COLORS.len()]
+                    }
+                };
+                paragraph = paragraph.add_run(
+                    text(&format!("{speaker}: "), 20, color.trim_start_matches('#')).bold(),
+                );
+            }
+            docx = docx.add_paragraph(paragraph.add_run(Run::new().add_text(line).size(20)));
+        }
+    }
+
+    docx
+}
+
+fn save(docx: Docx, path: &Path) -> Result<()> {
+    let file = File::create(path)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to create {}: {e}", path.display())))?;
+    docx.build()
+        .pack(file)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))
+}
+
+/// A run with a size (in half-points) and color
+fn text(value: &str, size: usize, color: &str) -> Run {
+    Run::new().add_text(value).size(size).color(color)
+}
+
+fn heading(title: &str) -> Paragraph {
+    Paragraph::new().add_run(text(title, 32, ACCENT).bold())
+}
+
+fn page_break() -> Paragraph {
+    Paragraph::new().add_run(Run::new().add_break(BreakType::Page))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{Call, CallDirection};
+    use std::io::Read;
+
+    #[test]
+    fn test_write_docx_brief() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-docx-{}", std::process::id()));
+        let mut call = Call::new(
+            "c1".to_string(),
+            "acct".to_string(),
+            "Kickoff".to_string(),
+            CallDirection::Outbound,
+            600,
+            "2025-01-15T16:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        call.transcript = Some("**Dana:** Welcome".to_string());
+        let dossier = Dossier::new("Acme Corp", &[call], &[], 5);
+
+        let path = DocxBriefWriter::new(&dir).write(&dossier).unwrap();
+        assert_eq!(path, dir.join("acme-corp-brief.docx"));
+
+        // DOCX files are zip archives
+        let mut magic = [0u8; 2];
+        File::open(&path).unwrap().read_exact(&mut magic).unwrap();
+        assert_eq!(&magic, b"PK");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,285 @@
+//! Customer dossier for the DOCX and PDF briefs
+//!
+//! Both exporters lay out the same content: a cover page, summary stats, the
+//! calls and emails in chronological order, and the transcripts of the most
+//! recent calls. [`Dossier`] collects that from the models once so the two
+//! formats can't drift apart.
+
+use jiff::Zoned;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use super::report::{format_duration, transcript_turns};
+use super::slug::slug;
+use super::snapshot::generated_at;
+use crate::gong::models::{Call, Email};
+
+/// Transcripts included in a brief, most recent calls first
+pub const BRIEF_TRANSCRIPTS: usize = 5;
+
+/// Length of email previews in the activity list
+const PREVIEW_CHARS: usize = 280;
+
+/// Everything a customer brief shows
+#[derive(Debug, Clone)]
+pub struct Dossier {
+    pub customer: String,
+    pub generated_at: Zoned,
+    pub calls: usize,
+    pub emails: usize,
+    /// Total call time in seconds
+    pub call_seconds: i64,
+    /// Distinct customer-side people across calls and emails
+    pub customer_contacts: usize,
+    /// Distinct people on our side
+    pub internal_contacts: usize,
+    pub first_activity: Option<Zoned>,
+    pub last_activity: Option<Zoned>,
+    /// Oldest first
+    pub activity: Vec<Activity>,
+    /// Most recent first
+    pub transcripts: Vec<TranscriptExcerpt>,
+}
+
+/// Kind of activity entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityKind {
+    Call,
+    Email,
+}
+
+/// One call or email in the activity list
+#[derive(Debug, Clone)]
+pub struct Activity {
+    pub date: Zoned,
+    pub kind: ActivityKind,
+    pub title: String,
+    /// Duration and attendees for calls, sender and preview for emails
+    pub detail: String,
+}
+
+/// A call transcript split into speaker turns
+#[derive(Debug, Clone)]
+pub struct TranscriptExcerpt {
+    pub title: String,
+    pub date: Zoned,
+    pub meta: String,
+    pub turns: Vec<(Option<String>, String)>,
+}
+
+impl Dossier {
+    /// Collect a customer's calls and emails, keeping up to `transcripts`
+    /// transcripts
+    pub fn new(customer: &str, calls: &[Call], emails: &[Email], transcripts: usize) -> Self {
+        let mut activity: Vec<Activity> = calls
+            .iter()
+            .map(|call| Activity {
+                date: call.scheduled_start.clone(),
+                kind: ActivityKind::Call,
+                title: call.title.clone(),
+                detail: call_meta(call),
+            })
+            .chain(emails.iter().map(|email| Activity {
+                date: email.sent_at.clone(),
+                kind: ActivityKind::Email,
+                title: if email.subject.is_empty() {
+                    "No Subject".to_string()
+                } else {
+                    email.subject.clone()
+                },
+                detail: email_detail(email),
+            }))
+            .collect();
+        activity.sort_by(|a, b| a.date.cmp(&b.date));
+
+        let mut customer_people = HashSet::new();
+        let mut internal_people = HashSet::new();
+        let people = calls
+            .iter()
+            .flat_map(|call| &call.participants)
+            .map(|p| (p.email.as_deref().unwrap_or(&p.name), p.is_internal))
+            .chain(
+                emails
+                    .iter()
+                    .flat_map(|e| std::iter::once(&e.sender).chain(&e.recipients))
+                    .map(|r| (r.email.as_str(), r.is_internal)),
+            );
+        for (key, internal) in people.filter(|(key, _)| !key.is_empty()) {
+            let key = key.to_lowercase();
+            if internal {
+                internal_people.insert(key);
+            } else {
+                customer_people.insert(key);
+            }
+        }
+
+        let mut recent: Vec<&Call> = calls
+            .iter()
+            .filter(|call| {
+                call.transcript
+                    .as_deref()
+                    .is_some_and(|t| !t.trim().is_empty())
+            })
+            .collect();
+        recent.sort_by(|a, b| b.scheduled_start.cmp(&a.scheduled_start));
+        let transcripts = recent
+            .into_iter()
+            .take(transcripts)
+            .map(|call| TranscriptExcerpt {
+                title: call.title.clone(),
+                date: call.scheduled_start.clone(),
+                meta: call_meta(call),
+                turns: transcript_turns(call.transcript.as_deref().unwrap_or_default()),
+            })
+            .collect();
+
+        Self {
+            customer: customer.to_string(),
+            generated_at: generated_at(),
+            calls: calls.len(),
+            emails: emails.len(),
+            call_seconds: calls.iter().map(|c| i64::from(c.duration.max(0))).sum(),
+            customer_contacts: customer_people.len(),
+            internal_contacts: internal_people.len(),
+            first_activity: activity.first().map(|a| a.date.clone()),
+            last_activity: activity.last().map(|a| a.date.clone()),
+            activity,
+            transcripts,
+        }
+    }
+
+    /// Date range covered, e.g. `Jan 15, 2025 – Mar 02, 2025`
+    pub fn period(&self) -> String {
+        match (&self.first_activity, &self.last_activity) {
+            (Some(first), Some(last)) => format!(
+                "{} – {}",
+                first.strftime("%b %d, %Y"),
+                last.strftime("%b %d, %Y")
+            ),
+            _ => "No activity".to_string(),
+        }
+    }
+
+    /// Summary table rows
+    pub fn stats(&self) -> Vec<(&'static str, String)> {
+        let hours = self.call_seconds as f64 / 3600.0;
+        vec![
+            ("Period", self.period()),
+            ("Calls", self.calls.to_string()),
+            ("Time on calls", format!("{hours:.1} hours")),
+            ("Emails", self.emails.to_string()),
+            ("Customer contacts", self.customer_contacts.to_string()),
+            ("Internal participants", self.internal_contacts.to_string()),
+        ]
+    }
+
+    /// `<customer>-brief.<extension>` in `dir`
+    pub fn file_path(&self, dir: &Path, extension: &str) -> PathBuf {
+        dir.join(format!(
+            "{}-brief.{extension}",
+            slug(&self.customer, "customer")
+        ))
+    }
+}
+
+fn call_meta(call: &Call) -> String {
+    format!(
+        "{} · {} · {} attendees",
+        call.scheduled_start.strftime("%b %d, %Y %H:%M"),
+        format_duration(call.duration),
+        call.participants.len()
+    )
+}
+
+fn email_detail(email: &Email) -> String {
+    let sender = email
+        .sender
+        .name
+        .as_deref()
+        .unwrap_or(email.sender.email.as_str());
+    let body = email
+        .body_text
+        .as_deref()
+        .or(email.snippet.as_deref())
+        .unwrap_or_default();
+    let preview: String = body
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(PREVIEW_CHARS)
+        .collect();
+
+    if preview.is_empty() {
+        format!("From {sender}")
+    } else {
+        format!("From {sender}: {preview}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{CallDirection, CallParticipant, EmailDirection};
+
+    #[test]
+    fn test_dossier_orders_activity_and_picks_recent_transcripts() {
+        let call = |id: &str, date: &str, transcript: Option<&str>| {
+            let mut call = Call::new(
+                id.to_string(),
+                "acct".to_string(),
+                format!("Call {id}"),
+                CallDirection::Outbound,
+                1800,
+                date.parse().unwrap(),
+            );
+            call.transcript = transcript.map(str::to_string);
+            call.participants.push(CallParticipant {
+                id: None,
+                name: "Dana Customer".to_string(),
+                email: Some("Dana@acme.com".to_string()),
+                phone: None,
+                title: None,
+                company: None,
+                is_internal: false,
+                speaking_time: None,
+                talk_ratio: None,
+            });
+            call
+        };
+        let calls = vec![
+            call("1", "2025-01-10T10:00:00+00:00[UTC]", Some("**Dana:** Hi")),
+            call("2", "2025-03-01T10:00:00+00:00[UTC]", None),
+            call(
+                "3",
+                "2025-02-01T10:00:00+00:00[UTC]",
+                Some("**Sam:** Hello\nagain"),
+            ),
+        ];
+        let email = Email::new(
+            "e1".to_string(),
+            "acct".to_string(),
+            String::new(),
+            EmailDirection::Inbound,
+            "2025-01-20T09:00:00+00:00[UTC]".parse().unwrap(),
+            "dana@acme.com".to_string(),
+        );
+
+        let dossier = Dossier::new("Acme Corp", &calls, &[email], 1);
+        let titles: Vec<&str> = dossier.activity.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, ["Call 1", "No Subject", "Call 3", "Call 2"]);
+        assert_eq!(dossier.customer_contacts, 1);
+        assert_eq!(dossier.period(), "Jan 10, 2025 – Mar 01, 2025");
+
+        assert_eq!(dossier.transcripts.len(), 1);
+        assert_eq!(dossier.transcripts[0].title, "Call 3");
+        assert_eq!(
+            dossier.transcripts[0].turns,
+            vec![(Some("Sam".to_string()), "Hello again".to_string())]
+        );
+        assert_eq!(
+            dossier.file_path(Path::new("/tmp"), "pdf"),
+            Path::new("/tmp/acme-corp-brief.pdf")
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! Email export as `.eml` files and an mbox
+//!
+//! Markdown batches are for reading; these are for mail tools. Each email
+//! becomes an RFC 5322 message with From/To/Cc/Date/Subject/Message-ID
+//! headers and the plain text and HTML bodies as `multipart/alternative`.
+//! Emails sharing a `thread_id` are chained with In-Reply-To/References so
+//! Thunderbird and Apple Mail show them as conversations.
+//!
+//! Message-IDs are derived from the Gong email ID, so re-exports replace
+//! rather than duplicate messages in tools that de-duplicate on them.
+
+use jiff::tz::TimeZone;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use super::slug::{slug, title_slug};
+use crate::gong::models::{Email, EmailRecipient};
+use crate::{CsCliError, Result};
+
+/// Domain part of generated Message-IDs
+const MESSAGE_ID_DOMAIN: &str = "gong.cs-cli";
+
+/// Writes `.eml` files and a per-customer mbox
+pub struct MailboxWriter {
+    output_dir: PathBuf,
+}
+
+impl MailboxWriter {
+    /// Write into `output_dir`, usually the customer's `ct_<customer>` folder
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// Write `eml/<date>-<subject>-<id>.eml` for each email plus
+    /// `<customer>.mbox` with all of them, oldest first
+    pub fn write(&self, customer: &str, emails: &[Email]) -> Result<Vec<PathBuf>> {
+        if emails.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut sorted: Vec<&Email> = emails.iter().collect();
+        sorted.sort_by(|a, b| a.sent_at.cmp(&b.sent_at));
+        let references = thread_references(&sorted);
+
+        let eml_dir = self.output_dir.join("eml");
+        create_dir(&eml_dir)?;
+
+        let mut saved_files = Vec::new();
+        let mut mbox = String::new();
+        for email in sorted {
+            let refs = references
+                .get(email.id.as_str())
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let message = format_eml(email, refs);
+
+            let path = eml_dir.join(eml_file_name(email));
+            write_file(&path, &message)?;
+            saved_files.push(path);
+
+            mbox.push_str(&mbox_entry(email, &message));
+        }
+
+        let mbox_path = self
+            .output_dir
+            .join(format!("{}.mbox", slug(customer, "customer")));
+        write_file(&mbox_path, &mbox)?;
+        saved_files.push(mbox_path);
+
+        info!(
+            emails = emails.len(),
+            output_dir = %self.output_dir.display(),
+            "Saved emails as eml and mbox"
+        );
+        Ok(saved_files)
+    }
+}
+
+/// Stable Message-ID for a Gong email
+pub fn message_id(email: &Email) -> String {
+    let id: String = email
+        .id
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    format!("<{id}@{MESSAGE_ID_DOMAIN}>")
+}
+
+/// Full message with CRLF line endings; `references` are the Message-IDs of
+/// earlier emails in the thread, oldest first
+pub fn format_eml(email: &Email, references: &[String]) -> String {
+    let mut headers = vec![
+        format!("From: {}", address(&email.sender)),
+        format!(
+            "Date: {}",
+            email.sent_at.strftime("%a, %d %b %Y %H:%M:%S %z")
+        ),
+        format!(
+            "Subject: {}",
+            encode_header(if email.subject.is_empty() {
+                "No Subject"
+            } else {
+                &email.subject
+            })
+        ),
+        format!("Message-ID: {}", message_id(email)),
+    ];
+
+    for (header, kind) in [("To", "to"), ("Cc", "cc"), ("Bcc", "bcc")] {
+        let addresses: Vec<String> = email
+            .recipients
+            .iter()
+            .filter(|r| r.recipient_type.eq_ignore_ascii_case(kind) && !r.email.is_empty())
+            .map(address)
+            .collect();
+        if !addresses.is_empty() {
+            headers.push(format!("{header}: {}", addresses.join(",\r\n ")));
+        }
+    }
+
+    if let Some(parent) = references.last() {
+        headers.push(format!("In-Reply-To: {parent}"));
+        headers.push(format!("References: {}", references.join("\r\n ")));
+    }
+    if let Some(thread_id) = &email.thread_id {
+        headers.push(format!("X-Gong-Thread-Id: {thread_id}"));
+    }
+    headers.push(format!("X-Gong-Email-Id: {}", email.id));
+    let direction = format!("{:?}", email.direction).to_lowercase();
+    headers.push(format!("X-Gong-Direction: {direction}"));
+    headers.push("MIME-Version: 1.0".to_string());
+
+    let text = email
+        .body_text
+        .as_deref()
+        .or(email.snippet.as_deref())
+        .filter(|t| !t.trim().is_empty());
+    let html = email.html_body.as_deref().filter(|h| !h.trim().is_empty());
+
+    let body = match (text, html) {
+        (Some(text), Some(html)) => {
+            let boundary = format!("=_cs-cli-{}", message_id(email).trim_matches(['<', '>']));
+            headers.push(format!(
+                "Content-Type: multipart/alternative; boundary=\"{boundary}\""
+            ));
+            format!(
+                "--{boundary}\r\n{}\r\n--{boundary}\r\n{}\r\n--{boundary}--\r\n",
+                body_part("text/plain", text),
+                body_part("text/html", html)
+            )
+        }
+        (None, Some(html)) => single_part(&mut headers, "text/html", html),
+        (text, _) => single_part(&mut headers, "text/plain", text.unwrap_or_default()),
+    };
+
+    format!("{}\r\n\r\n{body}", headers.join("\r\n"))
+}
+
+/// Add the content headers for a single-part message and return its body
+fn single_part(headers: &mut Vec<String>, content_type: &str, content: &str) -> String {
+    headers.push(format!("Content-Type: {content_type}; charset=utf-8"));
+    headers.push("Content-Transfer-Encoding: base64".to_string());
+    format!("{}\r\n", base64_lines(content))
+}
+
+/// One MIME part with its headers
+fn body_part(content_type: &str, content: &str) -> String {
+    format!(
+        "Content-Type: {content_type}; charset=utf-8\r\nContent-Transfer-Encoding: base64\r\n\r\n{}",
+        base64_lines(content)
+    )
+}
+
+/// Base64 wrapped at 76 characters
+fn base64_lines(content: &str) -> String {
+    let encoded = base64_simd::STANDARD.encode_to_string(content.as_bytes());
+    encoded
+        .as_bytes()
+        .chunks(76)
+        .map(|line| String::from_utf8_lossy(line).into_owned())
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// `"Name" <email>`, or the bare address when there is no name
+fn address(recipient: &EmailRecipient) -> String {
+    match recipient.name.as_deref().map(str::trim) {
+        Some(name) if !name.is_empty() && name != recipient.email => {
+            let name = if name.is_ascii() {
+                format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+            } else {
+                encode_header(name)
+            };
+            format!("{name} <{}>", recipient.email)
+        }
+        _ => format!("<{}>", recipient.email),
+    }
+}
+
+/// RFC 2047 encoded word for non-ASCII header text
+fn encode_header(text: &str) -> String {
+    let text = text.replace(['\r', '\n'], " ");
+    if text.is_ascii() {
+        text
+    } else {
+        format!(
+            "=?UTF-8?B?{}?=",
+            base64_simd::STANDARD.encode_to_string(text.as_bytes())
+        )
+    }
+}
+
+/// Message-IDs of the earlier emails in each email's thread
+fn thread_references<'a>(sorted: &[&'a Email]) -> HashMap<&'a str, Vec<String>> {
+    let mut threads: HashMap<&str, Vec<String>> = HashMap::new();
+    let mut references = HashMap::new();
+    for &email in sorted {
+        let Some(thread_id) = email.thread_id.as_deref() else {
+            continue;
+        };
+        let earlier = threads.entry(thread_id).or_default();
+        if !earlier.is_empty() {
+            references.insert(email.id.as_str(), earlier.clone());
+        }
+        earlier.push(message_id(email));
+    }
+    references
+}
+
+/// mboxrd entry: `From ` separator line, LF line endings and `>From` quoting
+fn mbox_entry(email: &Email, message: &str) -> String {
+    let sender = if email.sender.email.is_empty() {
+        "MAILER-DAEMON"
+    } else {
+        &email.sender.email
+    };
+    let date = email
+        .sent_at
+        .with_time_zone(TimeZone::UTC)
+        .strftime("%a %b %e %H:%M:%S %Y");
+
+    let mut entry = format!("From {sender} {date}\n");
+    for line in message.split("\r\n") {
+        if line.trim_start_matches('>').starts_with("From ") {
+            entry.push('>');
+        }
+        entry.push_str(line);
+        entry.push('\n');
+    }
+    entry.push('\n');
+    entry
+}
+
+fn eml_file_name(email: &Email) -> String {
+    let id: String = email
+        .id
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .take(8)
+        .collect();
+    format!(
+        "{}-{}-{id}.eml",
+        email.sent_at.strftime("%Y-%m-%d"),
+        title_slug(&email.subject, "no-subject")
+    )
+}
+
+fn create_dir(dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to create {}: {e}", dir.display())))
+}
+
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::EmailDirection;
+
+    fn email(id: &str, date: &str, thread: Option<&str>) -> Email {
+        let mut email = Email::new(
+            id.to_string(),
+            "acct".to_string(),
+            "Renewal – pricing".to_string(),
+            EmailDirection::Inbound,
+            date.parse().unwrap(),
+            "dana@acme.com".to_string(),
+        );
+        email.sender.name = Some("Dana \"DC\" Customer".to_string());
+        email.thread_id = thread.map(str::to_string);
+        email.recipients = vec![
+            EmailRecipient {
+                email: "sam@postman.com".to_string(),
+                name: Some("Sam".to_string()),
+                recipient_type: "to".to_string(),
+                is_internal: true,
+                title: None,
+                company: None,
+            },
+            EmailRecipient {
+                email: "lee@postman.com".to_string(),
+                name: None,
+                recipient_type: "cc".to_string(),
+                is_internal: true,
+                title: None,
+                company: None,
+            },
+        ];
+        email
+    }
+
+    #[test]
+    fn test_format_eml_headers_and_parts() {
+        let mut message = email("e-1", "2025-01-20T09:00:00-05:00[America/New_York]", None);
+        message.body_text = Some("From our side, yes.".to_string());
+        message.html_body = Some("<p>From our side, yes.</p>".to_string());
+
+        let eml = format_eml(&message, &["<e-0@gong.cs-cli>".to_string()]);
+        let (headers, body) = eml.split_once("\r\n\r\n").unwrap();
+
+        assert!(headers.starts_with("From: \"Dana \\\"DC\\\" Customer\" <dana@acme.com>\r\n"));
+        assert!(headers.contains("Date: Mon, 20 Jan 2025 09:00:00 -0500\r\n"));
+        assert!(headers.contains("Subject: =?UTF-8?B?"));
+        assert!(headers.contains("Message-ID: <e-1@gong.cs-cli>\r\n"));
+        assert!(headers.contains("To: \"Sam\" <sam@postman.com>\r\nCc: <lee@postman.com>\r\n"));
+        assert!(headers.contains("In-Reply-To: <e-0@gong.cs-cli>\r\n"));
+        assert!(headers.contains("multipart/alternative"));
+        assert_eq!(body.matches("Content-Type: text/").count(), 2);
+        assert!(body.ends_with("--=_cs-cli-e-1@gong.cs-cli--\r\n"));
+    }
+
+    #[test]
+    fn test_write_threads_and_mbox() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-mailbox-{}", std::process::id()));
+        let mut reply = email("e-2", "2025-01-21T10:00:00+00:00[UTC]", Some("t1"));
+        reply.body_text = Some("From the top:\nthanks".to_string());
+        let first = email("e-1", "2025-01-20T10:00:00+00:00[UTC]", Some("t1"));
+
+        let files = MailboxWriter::new(&dir)
+            .write("Acme Corp", &[reply, first])
+            .unwrap();
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[2], dir.join("acme-corp.mbox"));
+
+        let first_eml = fs::read_to_string(&files[0]).unwrap();
+        assert!(!first_eml.contains("In-Reply-To"));
+        let reply_eml = fs::read_to_string(&files[1]).unwrap();
+        assert!(reply_eml.contains("In-Reply-To: <e-1@gong.cs-cli>\r\n"));
+
+        let mbox = fs::read_to_string(&files[2]).unwrap();
+        assert!(mbox.starts_with("From dana@acme.com Mon Jan 20 10:00:00 2025\n"));
+        assert_eq!(mbox.matches("\nFrom dana@acme.com ").count(), 1);
+        assert!(!mbox.contains('\r'));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
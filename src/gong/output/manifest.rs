@@ -0,0 +1,338 @@
+//! `manifest.json`: what a customer folder contains and how it was made
+//!
+//! Written after every extraction or render. Each file is listed with the
+//! Gong calls and emails it was built from, a SHA-256 and its size, next to
+//! the extraction parameters, so later runs, `sync` and agents can tell how
+//! fresh and complete the folder is. Files from earlier runs that are still
+//! on disk keep their entries, re-hashed so the checksums stay current.
+
+use jiff::{ToSpan, Zoned};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+use super::snapshot::ExtractionSnapshot;
+use crate::common::hash::sha256_hex;
+use crate::gong::models::{Call, Email, AUTOMATED_EMAIL_FILTER, DUPLICATE_EMAIL_FILTER};
+use crate::{CsCliError, Result};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Bumped when the manifest layout changes incompatibly
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Filters applied to extracted emails
+pub const EMAIL_FILTERS: [&str; 2] = [AUTOMATED_EMAIL_FILTER, DUPLICATE_EMAIL_FILTER];
+
+/// What was asked of Gong
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExtractionParameters {
+    /// Customer name as searched for
+    pub query: String,
+    /// Days back from the extraction time
+    pub days: u32,
+    /// `calls`, `emails` or `both`
+    pub content_type: String,
+    pub emails_only: bool,
+    pub fetch_email_bodies: bool,
+    /// Filters that removed results, see [`EMAIL_FILTERS`]
+    pub filters: Vec<String>,
+    /// `web` or `api`
+    pub backend: String,
+    /// Browser (or cookie import) the Gong session came from
+    pub auth_source: Option<String>,
+}
+
+/// Dates covered by an extraction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ManifestRange {
+    pub from: String,
+    pub to: String,
+}
+
+/// One file in the folder
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ManifestFile {
+    /// Relative to the folder, or absolute for files written elsewhere
+    pub path: String,
+    pub sha256: String,
+    pub bytes: u64,
+    /// Rough LLM token count (bytes / 4) for text files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approx_tokens: Option<u64>,
+    pub call_ids: Vec<String>,
+    pub email_ids: Vec<String>,
+    /// Most recent call or email in the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_activity: Option<Zoned>,
+    /// When the file's data was fetched from Gong
+    pub extracted_at: Zoned,
+}
+
+/// Contents and provenance of a `ct_<customer>` folder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    pub version: u32,
+    pub cs_cli_version: String,
+    pub customer: String,
+    /// Output format of the last run
+    pub format: String,
+    pub parameters: ExtractionParameters,
+    pub range: ManifestRange,
+    /// When the last run fetched its data from Gong
+    pub extracted_at: Zoned,
+    pub files: Vec<ManifestFile>,
+}
+
+/// Builds and saves a folder's manifest
+pub struct ManifestWriter {
+    folder: PathBuf,
+}
+
+impl ManifestWriter {
+    /// Manifest for `folder`, usually the customer's `ct_<customer>` folder
+    pub fn new(folder: impl Into<PathBuf>) -> Self {
+        Self {
+            folder: folder.into(),
+        }
+    }
+
+    /// Record the files a run wrote from `snapshot` and save `manifest.json`
+    pub fn write(
+        &self,
+        snapshot: &ExtractionSnapshot,
+        format: &str,
+        files: &[PathBuf],
+    ) -> Result<PathBuf> {
+        let parameters = &snapshot.parameters;
+        let extracted_at = &snapshot.extracted_at;
+
+        fs::create_dir_all(&self.folder)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create output directory: {e}")))?;
+
+        let mut entries: BTreeMap<String, ManifestFile> = BTreeMap::new();
+        for entry in self.previous_files() {
+            if let Some(entry) = self.refresh(entry) {
+                entries.insert(entry.path.clone(), entry);
+            }
+        }
+        for file in files {
+            let entry = self.describe(file, extracted_at, &snapshot.calls, &snapshot.emails)?;
+            entries.insert(entry.path.clone(), entry);
+        }
+
+        let range_start = extracted_at
+            .checked_sub(i64::from(parameters.days).days())
+            .unwrap_or_else(|_| extracted_at.clone());
+        let manifest = Manifest {
+            version: MANIFEST_VERSION,
+            cs_cli_version: env!("CARGO_PKG_VERSION").to_string(),
+            customer: snapshot.customer.clone(),
+            format: format.to_string(),
+            parameters: parameters.clone(),
+            range: ManifestRange {
+                from: range_start.strftime("%Y-%m-%d").to_string(),
+                to: extracted_at.strftime("%Y-%m-%d").to_string(),
+            },
+            extracted_at: extracted_at.clone(),
+            files: entries.into_values().collect(),
+        };
+
+        let path = self.folder.join(MANIFEST_FILE);
+        let json = serde_json::to_string_pretty(&manifest)
+            .map_err(|e| CsCliError::Generic(format!("Failed to serialize manifest: {e}")))?;
+        fs::write(&path, json + "\n")
+            .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))?;
+
+        info!(path = %path.display(), files = manifest.files.len(), "Saved manifest");
+        Ok(path)
+    }
+
+    /// Entries from the manifest already in the folder
+    fn previous_files(&self) -> Vec<ManifestFile> {
+        let path = self.folder.join(MANIFEST_FILE);
+        let Ok(json) = fs::read_to_string(&path) else {
+            return Vec::new();
+        };
+        match serde_json::from_str::<Manifest>(&json) {
+            Ok(manifest) => manifest.files,
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Ignoring unreadable manifest");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Re-hash an earlier entry's file, which may have been edited since;
+    /// `None` once it's gone
+    fn refresh(&self, mut entry: ManifestFile) -> Option<ManifestFile> {
+        let path = self.resolve(&entry.path);
+        if !path.is_file() {
+            return None;
+        }
+        let data = match fs::read(&path) {
+            Ok(data) => data,
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Dropping unreadable manifest entry");
+                return None;
+            }
+        };
+        entry.sha256 = sha256_hex(&data);
+        entry.bytes = data.len() as u64;
+        entry.approx_tokens = approx_tokens(&data);
+        Some(entry)
+    }
+
+    fn describe(
+        &self,
+        file: &Path,
+        extracted_at: &Zoned,
+        calls: &[Call],
+        emails: &[Email],
+    ) -> Result<ManifestFile> {
+        let data = fs::read(file)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to read {}: {e}", file.display())))?;
+
+        // Text files name their sources; binary ones (DOCX and PDF briefs)
+        // are built from everything in the run
+        let text = std::str::from_utf8(&data).ok();
+        let call_ids: Vec<String> = calls
+            .iter()
+            .filter(|call| mentions(text, &call.id))
+            .map(|call| call.id.clone())
+            .collect();
+        let email_ids: Vec<String> = emails
+            .iter()
+            .filter(|email| mentions(text, &email.id))
+            .map(|email| email.id.clone())
+            .collect();
+        let latest_activity = calls
+            .iter()
+            .filter(|call| call_ids.contains(&call.id))
+            .map(|call| call.scheduled_start.clone())
+            .chain(
+                emails
+                    .iter()
+                    .filter(|email| email_ids.contains(&email.id))
+                    .map(|email| email.sent_at.clone()),
+            )
+            .max();
+
+        Ok(ManifestFile {
+            path: self.relative(file),
+            sha256: sha256_hex(&data),
+            bytes: data.len() as u64,
+            approx_tokens: approx_tokens(&data),
+            call_ids,
+            email_ids,
+            latest_activity,
+            extracted_at: extracted_at.clone(),
+        })
+    }
+
+    /// Path as stored in the manifest, with `/` separators inside the folder
+    fn relative(&self, file: &Path) -> String {
+        match file.strip_prefix(&self.folder) {
+            Ok(relative) => relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            Err(_) => file.display().to_string(),
+        }
+    }
+
+    fn resolve(&self, path: &str) -> PathBuf {
+        let path = Path::new(path);
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.folder.join(path)
+        }
+    }
+}
+
+/// Rough LLM token count of a text file
+fn approx_tokens(data: &[u8]) -> Option<u64> {
+    std::str::from_utf8(data)
+        .ok()
+        .map(|text| text.len().div_ceil(4) as u64)
+}
+
+/// Whether a file mentions a Gong ID; binary files count as mentioning all
+fn mentions(text: Option<&str>, id: &str) -> bool {
+    match text {
+        Some(text) => !id.is_empty() && text.contains(id),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::CallDirection;
+
+    #[test]
+    fn test_manifest_lists_files_with_sources() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-manifest-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let call = Call::new(
+            "1234567890".to_string(),
+            "acct".to_string(),
+            "Kickoff".to_string(),
+            CallDirection::Outbound,
+            600,
+            "2025-01-15T16:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        let other = Call::new(
+            "9876543210".to_string(),
+            "acct".to_string(),
+            "Review".to_string(),
+            CallDirection::Outbound,
+            600,
+            "2025-01-20T16:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        let note = dir.join("kickoff.md");
+        fs::write(&note, "**Call ID:** `1234567890`\n").unwrap();
+        let mut snapshot = ExtractionSnapshot::new("Acme Corp", &[call, other], &[], false)
+            .with_parameters(ExtractionParameters {
+                query: "Acme".to_string(),
+                days: 30,
+                content_type: "calls".to_string(),
+                ..Default::default()
+            });
+        snapshot.extracted_at = "2025-02-01T09:30:00+00:00[UTC]".parse().unwrap();
+
+        let path = ManifestWriter::new(&dir)
+            .write(&snapshot, "markdown", &[note])
+            .unwrap();
+
+        let manifest: Manifest = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(manifest.range.from, "2025-01-02");
+        assert_eq!(manifest.files.len(), 1);
+        let file = &manifest.files[0];
+        assert_eq!(file.path, "kickoff.md");
+        assert_eq!(file.call_ids, vec!["1234567890".to_string()]);
+        assert_eq!(file.bytes, 26);
+        assert_eq!(file.sha256.len(), 64);
+
+        // A later run that didn't rewrite the file still picks up edits
+        fs::write(
+            dir.join("kickoff.md"),
+            "**Call ID:** `1234567890` (edited)\n",
+        )
+        .unwrap();
+        ManifestWriter::new(&dir)
+            .write(&snapshot, "markdown", &[])
+            .unwrap();
+        let updated: Manifest = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(updated.files.len(), 1);
+        assert_eq!(updated.files[0].bytes, 35);
+        assert_ne!(updated.files[0].sha256, file.sha256);
+        assert_eq!(updated.files[0].call_ids, file.call_ids);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
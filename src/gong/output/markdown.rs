@@ -1,10 +1,15 @@
 use anyhow::{Context, Result};
 use jiff::Zoned;
+use minijinja::context;
 use regex::Regex;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tracing::{error, info};
 
+use super::snapshot::generated_at;
+use super::templates::{
+    TemplateRenderer, CALL_TEMPLATE, EMAIL_BATCH_TEMPLATE, EMAIL_TEMPLATE, SUMMARY_TEMPLATE,
+};
 use crate::gong::models::{Call, Email};
 
 /// Formatter for generating markdown reports from team calls and emails
@@ -27,67 +32,10 @@ impl CallMarkdownFormatter {
 
     /// Format a single call into markdown content
     pub fn format_call_to_markdown(&self, call: &Call) -> String {
-        // Extract call information
-        let title = &call.title;
-        let customer = call.customer_name.as_deref()
-            .unwrap_or_else(|| "Unknown Customer");
-        let date = &call.scheduled_start;
-        let attendees = &call.participants;
-        let transcript = call
-            .transcript
-            .as_deref()
-            .unwrap_or("No transcript available");
-        let call_id = &call.id;
-        let call_url = call.recording_url.as_deref().unwrap_or("");
-
-        // Format date as ISO string to match Python
-        let formatted_date = date.strftime("%Y-%m-%dT%H:%M:%S").to_string();
-
-        // Build markdown content - use title as header to match Python
-        let mut markdown_content = format!(
-            "# {title}\n\n**Customer:** {customer}\n**Date:** {formatted_date}\n**Call ID:** `{call_id}`"
-        );
-
-        // Add call URL if available
-        if !call_url.is_empty() {
-            markdown_content.push_str(&format!("\n**Call Link:** {call_url}"));
-        }
-
-        markdown_content.push_str("\n\n## Attendees\n\n");
-
-        // Add attendees section
-        if !attendees.is_empty() {
-            for attendee in attendees {
-                let name = &attendee.name;
-                let title = attendee.title.as_deref().unwrap_or("");
-                let company = attendee.company.as_deref().unwrap_or("");
-                let email = attendee.email.as_deref().unwrap_or("");
-
-                markdown_content.push_str(&format!("- **{name}**"));
-                if !title.is_empty() {
-                    markdown_content.push_str(&format!(" - {title}"));
-                }
-                if !company.is_empty() {
-                    markdown_content.push_str(&format!(" ({company})"));
-                }
-                if !email.is_empty() {
-                    markdown_content.push_str(&format!(" - {email}"));
-                }
-                markdown_content.push('\n');
-            }
-        } else {
-            markdown_content.push_str("No attendee information available.\n");
-        }
-
-        // Add transcript section
-        let cleaned_transcript = self.clean_transcript(transcript);
-        let generated_time = Zoned::now().strftime("%Y-%m-%d %H:%M:%S").to_string();
-
-        markdown_content.push_str(&format!(
-            "\n\n## Transcript\n\n{cleaned_transcript}\n\n---\n*Generated on {generated_time}*\n"
-        ));
-
-        markdown_content
+        TemplateRenderer::shared().render(
+            CALL_TEMPLATE,
+            context! { call, generated_at => generated_at() },
+        )
     }
 
     /// Save a call as a markdown file with proper naming
@@ -157,10 +105,9 @@ impl CallMarkdownFormatter {
                 // CLI mode: use Desktop with custom/dated subdirectory
                 let desktop_path = home.join("Desktop");
                 if let Some(custom_name) = custom_dir_name {
-                    let sanitized_name = self.sanitize_filename(custom_name);
-                    desktop_path.join(format!("ct_{sanitized_name}"))
+                    desktop_path.join(self.customer_folder_name(custom_name))
                 } else {
-                    let today = Zoned::now().strftime("%Y-%m-%d").to_string();
+                    let today = generated_at().strftime("%Y-%m-%d").to_string();
                     desktop_path.join(format!("team-calls-{today}"))
                 }
             } else {
@@ -193,6 +140,23 @@ impl CallMarkdownFormatter {
         Ok(saved_files)
     }
 
+    /// Folder a customer's files are saved in: `ct_<customer>` on the Desktop
+    /// for the CLI, or the configured output directory
+    pub fn customer_output_dir(&self, customer: &str) -> PathBuf {
+        let home = dirs::home_dir().expect("Could not find home directory");
+        if self.output_dir == home.join("Desktop").join("team-calls-output") {
+            home.join("Desktop")
+                .join(self.customer_folder_name(customer))
+        } else {
+            self.output_dir.clone()
+        }
+    }
+
+    /// Name of a customer's folder: `ct_<customer>`
+    pub fn customer_folder_name(&self, customer: &str) -> String {
+        format!("ct_{}", self.sanitize_filename(customer))
+    }
+
     /// Extract customer name from call data
     fn extract_customer_name(&self, call: &Call) -> String {
         // For now, extract from title patterns - this could be enhanced
@@ -233,11 +197,6 @@ impl CallMarkdownFormatter {
         "Unknown Customer".to_string()
     }
 
-    /// Format date for display
-    fn format_date(&self, date: &Zoned) -> String {
-        date.strftime("%B %d, %Y at %I:%M %p").to_string()
-    }
-
     /// Format date for use in filename (includes time for uniqueness)
     fn format_date_for_filename(&self, date: &Zoned) -> String {
         // Format as YYYY-MM-DDtHHMMSS to match Python implementation
@@ -284,47 +243,8 @@ impl CallMarkdownFormatter {
     }
 
     /// Clean and format transcript text for markdown
-    fn clean_transcript(&self, transcript: &str) -> String {
-        if transcript.is_empty() {
-            return "No transcript available.".to_string();
-        }
-
-        let cleaned = transcript.trim();
-
-        // Check if transcript is already properly formatted (contains **Speaker:** patterns)
-        if cleaned.contains("**") && cleaned.contains(":**") {
-            // Already formatted by the API client, just return with minimal cleaning
-            // Remove excessive blank lines (more than 2 consecutive)
-            let regex = Regex::new(r"\n\n\n+").unwrap();
-            return regex.replace_all(cleaned, "\n\n").to_string();
-        }
-
-        // Legacy formatting for unformatted transcripts
-        let lines: Vec<&str> = cleaned.lines().collect();
-        let mut formatted_lines = Vec::new();
-
-        for line in lines {
-            let line = line.trim();
-            if line.is_empty() {
-                continue; // Skip empty lines, let natural spacing handle it
-            }
-
-            // Check if line starts with a speaker name pattern
-            if line.contains(':') && line.split(':').next().unwrap_or("").len() < 50 {
-                let parts: Vec<&str> = line.splitn(2, ':').collect();
-                if parts.len() == 2 {
-                    let speaker = parts[0].trim();
-                    let text = parts[1].trim();
-                    formatted_lines.push(format!("**{speaker}:** {text}"));
-                } else {
-                    formatted_lines.push(line.to_string());
-                }
-            } else {
-                formatted_lines.push(line.to_string());
-            }
-        }
-
-        formatted_lines.join("\n\n")
+    pub fn clean_transcript(&self, transcript: &str) -> String {
+        clean_transcript(transcript)
     }
 }
 
@@ -332,89 +252,7 @@ impl CallMarkdownFormatter {
 impl CallMarkdownFormatter {
     /// Format a single email into markdown content
     pub fn format_email_to_markdown(&self, email: &Email) -> String {
-        // Extract email information
-        let subject = if email.subject.is_empty() {
-            "No Subject"
-        } else {
-            &email.subject
-        };
-        let sender_name = email.sender.name.as_deref().unwrap_or("Unknown Sender");
-        let sender_email = &email.sender.email;
-        let sent_at = self.format_date(&email.sent_at);
-
-        // Build sender info
-        let mut sender_info = sender_name.to_string();
-        if !sender_email.is_empty() {
-            sender_info.push_str(&format!(" ({sender_email})"));
-        }
-        if let Some(title) = &email.sender.title {
-            sender_info.push_str(&format!(" - {title}"));
-        }
-        if let Some(company) = &email.sender.company {
-            sender_info.push_str(&format!(" @ {company}"));
-        }
-
-        // Build markdown content
-        let mut markdown_content = format!(
-            "## {}\n\n**From:** {}\n**Date:** {}\n**Direction:** {}\n**Email ID:** `{}`",
-            subject,
-            sender_info,
-            sent_at,
-            format!("{:?}", email.direction).to_lowercase(),
-            email.id
-        );
-
-        // Add recipients section
-        if !email.recipients.is_empty() {
-            markdown_content.push_str("\n**To:** ");
-            let recipient_names: Vec<String> = email
-                .recipients
-                .iter()
-                .map(|recipient| {
-                    let name = recipient
-                        .name
-                        .as_deref()
-                        .unwrap_or_else(|| recipient.email.split('@').next().unwrap_or("Unknown"));
-                    if !recipient.email.is_empty() {
-                        format!("{} ({})", name, recipient.email)
-                    } else {
-                        name.to_string()
-                    }
-                })
-                .collect();
-            markdown_content.push_str(&recipient_names.join(", "));
-        }
-
-        // Add automation/template info if applicable
-        if email.is_automated || email.is_template {
-            markdown_content.push_str("\n**Type:** ");
-            if email.is_template {
-                markdown_content.push_str("Template/Automated");
-            } else {
-                markdown_content.push_str("Automated");
-            }
-        }
-
-        // Add body content
-        markdown_content.push_str("\n\n### Content\n\n");
-
-        if let Some(body_text) = &email.body_text {
-            if !body_text.trim().is_empty() {
-                let cleaned_body = self.clean_email_body(body_text);
-                markdown_content.push_str(&cleaned_body);
-            }
-        } else if let Some(snippet) = &email.snippet {
-            if !snippet.trim().is_empty() {
-                markdown_content.push_str(&format!(
-                    "*[Preview only - full content not available]*\n\n{snippet}"
-                ));
-            }
-        } else {
-            markdown_content.push_str("*No content available*");
-        }
-
-        markdown_content.push_str("\n\n---\n");
-        markdown_content
+        TemplateRenderer::shared().render(EMAIL_TEMPLATE, context! { email })
     }
 
     /// Format a batch of emails into a single markdown document
@@ -439,7 +277,7 @@ impl CallMarkdownFormatter {
                 .map(|e| &e.sent_at)
                 .filter(|date| {
                     // Exclude emails with dates very close to current time (likely fallback dates)
-                    let now = Zoned::now();
+                    let now = generated_at();
                     let time_diff = now.since(*date).unwrap_or(jiff::Span::default()).abs();
                     // If email date is within 1 minute of current time, it's likely a fallback
                     time_diff.get_seconds() > 60
@@ -461,26 +299,16 @@ impl CallMarkdownFormatter {
             "Unknown Date Range".to_string()
         };
 
-        // Build header
-        let generated_time = Zoned::now().strftime("%B %d, %Y at %I:%M %p").to_string();
-        let mut markdown_content = format!(
-            "# {} - Emails Batch {}\n\n**Date Range:** {}  \n**Total Emails:** {}  \n**Generated:** {}  \n**Advanced BDR/SPAM filtering applied** - Templates, duplicates, and automation removed\n\n---\n\n",
-            customer_name, batch_num, date_range, emails.len(), generated_time
-        );
-
-        // Add each email
-        for (i, email) in sorted_emails.iter().enumerate() {
-            markdown_content.push_str(&format!("### Email {}/{}\n\n", i + 1, emails.len()));
-            markdown_content.push_str(&self.format_email_to_markdown(email));
-            markdown_content.push('\n');
-        }
-
-        // Add footer
-        markdown_content.push_str(&format!(
-            "\n\n---\n*Batch {batch_num} of emails for {customer_name} - Generated by cs-transcript-cli*\n"
-        ));
-
-        markdown_content
+        TemplateRenderer::shared().render(
+            EMAIL_BATCH_TEMPLATE,
+            context! {
+                customer => customer_name,
+                batch => batch_num,
+                date_range,
+                emails => sorted_emails,
+                generated_at => generated_at(),
+            },
+        )
     }
 
     /// Save emails as markdown files in batches of 20
@@ -505,8 +333,7 @@ impl CallMarkdownFormatter {
                 // CLI mode: use Desktop with custom subdirectory
                 let desktop_path = home.join("Desktop");
                 if let Some(custom_name) = custom_dir_name {
-                    let sanitized_name = self.sanitize_filename(custom_name);
-                    desktop_path.join(format!("ct_{sanitized_name}"))
+                    desktop_path.join(self.customer_folder_name(custom_name))
                 } else {
                     let sanitized_name = self.sanitize_filename(customer_name);
                     desktop_path.join(format!("ct_{sanitized_name}"))
@@ -538,7 +365,7 @@ impl CallMarkdownFormatter {
                     .map(|e| &e.sent_at)
                     .filter(|date| {
                         // Exclude emails with dates very close to current time (likely fallback dates)
-                        let now = Zoned::now();
+                        let now = generated_at();
                         let time_diff = now.since(*date).unwrap_or(jiff::Span::default()).abs();
                         // If email date is within 1 minute of current time, it's likely a fallback
                         time_diff.get_seconds() > 60
@@ -603,40 +430,84 @@ impl CallMarkdownFormatter {
 
         Ok(saved_files)
     }
+}
 
-    /// Clean and format email body text for markdown
-    fn clean_email_body(&self, body_text: &str) -> String {
-        if body_text.trim().is_empty() {
-            return "*No content available*".to_string();
+/// Clean and format transcript text for markdown
+pub fn clean_transcript(transcript: &str) -> String {
+    if transcript.is_empty() {
+        return "No transcript available.".to_string();
+    }
+
+    let cleaned = transcript.trim();
+
+    // Check if transcript is already properly formatted (contains **Speaker:** patterns)
+    if cleaned.contains("**") && cleaned.contains(":**") {
+        // Already formatted by the API client, just return with minimal cleaning
+        // Remove excessive blank lines (more than 2 consecutive)
+        let regex = Regex::new(r"\n\n\n+").unwrap();
+        return regex.replace_all(cleaned, "\n\n").to_string();
+    }
+
+    // Legacy formatting for unformatted transcripts
+    let lines: Vec<&str> = cleaned.lines().collect();
+    let mut formatted_lines = Vec::new();
+
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() {
+            continue; // Skip empty lines, let natural spacing handle it
         }
 
-        let mut cleaned = body_text.trim().to_string();
+        // Check if line starts with a speaker name pattern
+        if line.contains(':') && line.split(':').next().unwrap_or("").len() < 50 {
+            let parts: Vec<&str> = line.splitn(2, ':').collect();
+            if parts.len() == 2 {
+                let speaker = parts[0].trim();
+                let text = parts[1].trim();
+                formatted_lines.push(format!("**{speaker}:** {text}"));
+            } else {
+                formatted_lines.push(line.to_string());
+            }
+        } else {
+            formatted_lines.push(line.to_string());
+        }
+    }
 
-        // Remove excessive whitespace but preserve paragraph breaks
-        let regex1 = Regex::new(r"\n\s*\n\s*\n+").unwrap();
-        cleaned = regex1.replace_all(&cleaned, "\n\n").to_string();
+    formatted_lines.join("\n\n")
+}
 
-        let regex2 = Regex::new(r"[ \t]+").unwrap();
-        cleaned = regex2.replace_all(&cleaned, " ").to_string();
+/// Clean and format email body text for markdown
+pub fn clean_email_body(body_text: &str) -> String {
+    if body_text.trim().is_empty() {
+        return "*No content available*".to_string();
+    }
 
-        // Handle common email artifacts
-        let regex3 = Regex::new(r"^[\s>]+").unwrap();
-        cleaned = regex3.replace_all(&cleaned, "").to_string();
+    let mut cleaned = body_text.trim().to_string();
 
-        let regex4 = Regex::new(r"(^|\n)On .* wrote:\s*$").unwrap();
-        cleaned = regex4
-            .replace_all(&cleaned, r"$1\n---\n\n**Previous conversation:**\n")
-            .to_string();
+    // Remove excessive whitespace but preserve paragraph breaks
+    let regex1 = Regex::new(r"\n\s*\n\s*\n+").unwrap();
+    cleaned = regex1.replace_all(&cleaned, "\n\n").to_string();
 
-        // Ensure reasonable length (truncate very long emails)
-        const MAX_LENGTH: usize = 5000;
-        if cleaned.len() > MAX_LENGTH {
-            cleaned.truncate(MAX_LENGTH);
-            cleaned.push_str("\n\n*[Email content truncated for readability]*");
-        }
+    let regex2 = Regex::new(r"[ \t]+").unwrap();
+    cleaned = regex2.replace_all(&cleaned, " ").to_string();
+
+    // Handle common email artifacts
+    let regex3 = Regex::new(r"^[\s>]+").unwrap();
+    cleaned = regex3.replace_all(&cleaned, "").to_string();
+
+    let regex4 = Regex::new(r"(^|\n)On .* wrote:\s*$").unwrap();
+    cleaned = regex4
+        .replace_all(&cleaned, r"$1\n---\n\n**Previous conversation:**\n")
+        .to_string();
 
-        cleaned
+    // Ensure reasonable length (truncate very long emails)
+    const MAX_LENGTH: usize = 5000;
+    if cleaned.len() > MAX_LENGTH {
+        cleaned.truncate(MAX_LENGTH);
+        cleaned.push_str("\n\n*[Email content truncated for readability]*");
     }
+
+    cleaned
 }
 
 /// Summary generation for calls
@@ -655,16 +526,8 @@ impl CallSummaryReporter {
         output_path: Option<&Path>,
         resolved_customer_name: Option<&str>,
     ) -> Result<String> {
-        let today = Zoned::now().strftime("%Y-%m-%d").to_string();
-        let generated_time = Zoned::now().strftime("%B %d, %Y at %I:%M %p").to_string();
-
-        let mut summary_content = format!(
-            "# Team Calls Summary - {}\n\nGenerated on {}\n\n## Overview\n\n- **Total Calls:** {}\n- **Date Range:** Last 7 days\n- **Extraction Date:** {}\n\n## Calls by Customer\n\n",
-            today, generated_time, calls_data.len(), today
-        );
-
         // Group calls by customer
-        let mut customer_calls = std::collections::HashMap::new();
+        let mut customer_calls = std::collections::BTreeMap::new();
 
         // If we have a resolved customer name, use it for all calls
         if let Some(resolved_name) = resolved_customer_name {
@@ -680,27 +543,14 @@ impl CallSummaryReporter {
             }
         }
 
-        // Add customer sections
-        let mut customers: Vec<_> = customer_calls.keys().collect();
-        customers.sort();
-
-        for customer in customers {
-            let calls = &customer_calls[customer];
-            summary_content.push_str(&format!("### {} ({} calls)\n\n", customer, calls.len()));
-
-            for call in calls {
-                let title = &call.title;
-                let date = self.extract_call_date(call);
-                let formatted_date = self.format_date_summary(date);
-                let call_id = &call.id;
-
-                summary_content.push_str(&format!(
-                    "- **{title}** - {formatted_date} (ID: `{call_id})\n"
-                ));
-            }
-
-            summary_content.push('\n');
-        }
+        let customers: Vec<_> = customer_calls
+            .into_iter()
+            .map(|(name, calls)| context! { name, calls })
+            .collect();
+        let summary_content = TemplateRenderer::shared().render(
+            SUMMARY_TEMPLATE,
+            context! { calls => calls_data, customers, generated_at => generated_at() },
+        );
 
         // Save if path provided
         if let Some(path) = output_path {
@@ -712,12 +562,6 @@ impl CallSummaryReporter {
         Ok(summary_content)
     }
 
-    /// Extract call date from multiple possible fields
-    fn extract_call_date<'a>(&self, call: &'a Call) -> &'a Zoned {
-        // Try actual_start first if available, otherwise use scheduled_start
-        call.actual_start.as_ref().unwrap_or(&call.scheduled_start)
-    }
-
     /// Extract customer name from call data with intelligent fallbacks
     fn extract_customer_name(&self, call: &Call) -> String {
         let title = &call.title;
@@ -755,11 +599,6 @@ impl CallSummaryReporter {
 
         "Unknown Customer".to_string()
     }
-
-    /// Format date for summary display
-    fn format_date_summary(&self, date: &Zoned) -> String {
-        date.strftime("%m/%d/%Y").to_string()
-    }
 }
 
 impl Default for CallSummaryReporter {
@@ -1,5 +1,31 @@
+pub mod activity_csv;
+pub mod docx;
+pub mod dossier;
 pub mod html;
+pub mod mailbox;
+pub mod manifest;
 pub mod markdown;
+pub mod parquet;
+pub mod pdf;
+pub mod report;
+pub mod slug;
+pub mod snapshot;
+pub mod sqlite;
+pub mod templates;
+pub mod vault;
 
+pub use activity_csv::*;
+pub use docx::*;
+pub use dossier::*;
 pub use html::*;
+pub use mailbox::*;
+pub use manifest::*;
 pub use markdown::*;
+pub use parquet::*;
+pub use pdf::*;
+pub use report::*;
+pub use slug::*;
+pub use snapshot::*;
+pub use sqlite::*;
+pub use templates::*;
+pub use vault::*;
@@ -0,0 +1,579 @@
+//! Parquet export for DuckDB, Polars and BI tools
+//!
+//! Writes four tables (`calls`, `participants`, `transcript_segments` and
+//! `emails`) as Hive-style partitions:
+//!
+//! ```text
+//! <root>/calls/customer=acme-corp/month=2025-01/data.parquet
+//! ```
+//!
+//! so `read_parquet('<root>/calls/**/*.parquet', hive_partitioning = true)`
+//! picks up every customer. Timestamps are UTC microsecond timestamps with
+//! the original time zone kept in a `timezone` column, and topics, action
+//! items and recipients are list columns.
+//!
+//! Re-running a customer rewrites only the months it touched, replacing rows
+//! with the same call or email ID and keeping the rest.
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Float64Array, Int32Array, Int64Array, ListBuilder, StringArray,
+    StringBuilder, TimestampMicrosecondArray,
+};
+use arrow::compute::{concat_batches, filter_record_batch};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use jiff::Zoned;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use super::report::transcript_turns;
+use super::slug::slug;
+use crate::gong::models::{Call, Email};
+use crate::{CsCliError, Result};
+
+/// `gong-parquet` on the Desktop, next to the customer folders
+pub fn default_parquet_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join("Desktop")
+        .join("gong-parquet")
+}
+
+/// Writes partitioned Parquet tables
+pub struct ParquetWriter {
+    root: PathBuf,
+}
+
+impl ParquetWriter {
+    /// Write the dataset under `root`
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Write a customer's calls and emails, returning the partition files
+    pub fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        let partition = format!("customer={}", slug(customer, "unknown"));
+        let mut saved_files = Vec::new();
+
+        for (month, calls) in by_month(calls, |call| &call.scheduled_start) {
+            let dir = |table: &str| self.root.join(table).join(&partition).join(&month);
+            saved_files.push(write_partition(
+                &dir("calls"),
+                "call_id",
+                call_batch(customer, &calls)?,
+            )?);
+            saved_files.push(write_partition(
+                &dir("participants"),
+                "call_id",
+                participant_batch(&calls)?,
+            )?);
+            saved_files.push(write_partition(
+                &dir("transcript_segments"),
+                "call_id",
+                segment_batch(&calls)?,
+            )?);
+        }
+
+        for (month, emails) in by_month(emails, |email| &email.sent_at) {
+            let dir = self.root.join("emails").join(&partition).join(&month);
+            saved_files.push(write_partition(
+                &dir,
+                "email_id",
+                email_batch(customer, &emails)?,
+            )?);
+        }
+
+        info!(
+            root = %self.root.display(),
+            calls = calls.len(),
+            emails = emails.len(),
+            files = saved_files.len(),
+            "Saved Parquet partitions"
+        );
+        Ok(saved_files)
+    }
+}
+
+/// Group items into `month=YYYY-MM` partitions by their local date
+fn by_month<'a, T>(items: &'a [T], date: impl Fn(&T) -> &Zoned) -> BTreeMap<String, Vec<&'a T>> {
+    let mut months: BTreeMap<String, Vec<&T>> = BTreeMap::new();
+    for item in items {
+        months
+            .entry(format!("month={}", date(item).strftime("%Y-%m")))
+            .or_default()
+            .push(item);
+    }
+    months
+}
+
+fn call_batch(customer: &str, calls: &[&Call]) -> Result<RecordBatch> {
+    let schema = Schema::new(vec![
+        Field::new("call_id", DataType::Utf8, false),
+        Field::new("account_id", DataType::Utf8, false),
+        Field::new("customer", DataType::Utf8, false),
+        Field::new("title", DataType::Utf8, false),
+        Field::new("direction", DataType::Utf8, false),
+        Field::new("duration_seconds", DataType::Int64, false),
+        Field::new("scheduled_start", timestamp_type(), false),
+        Field::new("actual_start", timestamp_type(), true),
+        Field::new("timezone", DataType::Utf8, true),
+        Field::new("status", DataType::Utf8, true),
+        Field::new("call_type", DataType::Utf8, true),
+        Field::new("host_name", DataType::Utf8, true),
+        Field::new("recording_url", DataType::Utf8, true),
+        Field::new("call_brief", DataType::Utf8, true),
+        Field::new("summary", DataType::Utf8, true),
+        Field::new("sentiment", DataType::Float64, true),
+        Field::new("talk_ratio", DataType::Float64, true),
+        Field::new("longest_monologue_seconds", DataType::Int64, true),
+        Field::new("interactivity", DataType::Float64, true),
+        Field::new("questions_asked", DataType::Int64, true),
+        list_field("topics"),
+        list_field("action_items"),
+        Field::new("participant_count", DataType::Int64, false),
+        list_field("external_emails"),
+        Field::new("has_transcript", DataType::Boolean, false),
+    ]);
+
+    let columns: Vec<ArrayRef> = vec![
+        strings(calls.iter().map(|c| Some(c.id.as_str()))),
+        strings(calls.iter().map(|c| Some(c.account_id.as_str()))),
+        strings(
+            calls
+                .iter()
+                .map(|c| Some(c.customer_name.as_deref().unwrap_or(customer))),
+        ),
+        strings(calls.iter().map(|c| Some(c.title.as_str()))),
+        strings(calls.iter().map(|c| Some(lowercase_debug(&c.direction)))),
+        Arc::new(Int64Array::from_iter(
+            calls.iter().map(|c| Some(i64::from(c.duration))),
+        )),
+        timestamps(calls.iter().map(|c| Some(&c.scheduled_start))),
+        timestamps(calls.iter().map(|c| c.actual_start.as_ref())),
+        strings(
+            calls
+                .iter()
+                .map(|c| c.scheduled_start.time_zone().iana_name()),
+        ),
+        strings(calls.iter().map(|c| c.status.as_deref())),
+        strings(calls.iter().map(|c| c.call_type.as_deref())),
+        strings(calls.iter().map(|c| c.host_name.as_deref())),
+        strings(calls.iter().map(|c| c.recording_url.as_deref())),
+        strings(calls.iter().map(|c| c.call_brief.as_deref())),
+        strings(calls.iter().map(|c| c.summary.as_deref())),
+        Arc::new(Float64Array::from_iter(calls.iter().map(|c| c.sentiment))),
+        Arc::new(Float64Array::from_iter(calls.iter().map(|c| c.talk_ratio))),
+        Arc::new(Int64Array::from_iter(
+            calls.iter().map(|c| c.longest_monologue.map(i64::from)),
+        )),
+        Arc::new(Float64Array::from_iter(
+            calls.iter().map(|c| c.interactivity),
+        )),
+        Arc::new(Int64Array::from_iter(
+            calls.iter().map(|c| c.questions_asked.map(i64::from)),
+        )),
+        string_lists(
+            calls
+                .iter()
+                .map(|c| c.topics.iter().map(String::as_str).collect()),
+        ),
+        string_lists(
+            calls
+                .iter()
+                .map(|c| c.action_items.iter().map(String::as_str).collect()),
+        ),
+        Arc::new(Int64Array::from_iter(
+            calls.iter().map(|c| Some(c.participants.len() as i64)),
+        )),
+        string_lists(calls.iter().map(|c| {
+            c.external_participants()
+                .into_iter()
+                .filter_map(|p| p.email.as_deref())
+                .collect()
+        })),
+        Arc::new(BooleanArray::from(
+            calls.iter().map(|c| c.has_transcript()).collect::<Vec<_>>(),
+        )),
+    ];
+    batch(schema, columns)
+}
+
+fn participant_batch(calls: &[&Call]) -> Result<RecordBatch> {
+    let rows: Vec<_> = calls
+        .iter()
+        .flat_map(|call| {
+            call.participants
+                .iter()
+                .enumerate()
+                .map(move |(position, p)| (*call, position, p))
+        })
+        .collect();
+
+    let schema = Schema::new(vec![
+        Field::new("call_id", DataType::Utf8, false),
+        Field::new("scheduled_start", timestamp_type(), false),
+        Field::new("position", DataType::Int32, false),
+        Field::new("name", DataType::Utf8, false),
+        Field::new("email", DataType::Utf8, true),
+        Field::new("title", DataType::Utf8, true),
+        Field::new("company", DataType::Utf8, true),
+        Field::new("is_internal", DataType::Boolean, false),
+        Field::new("speaking_time_seconds", DataType::Float64, true),
+        Field::new("talk_ratio", DataType::Float64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        strings(rows.iter().map(|(c, _, _)| Some(c.id.as_str()))),
+        timestamps(rows.iter().map(|(c, _, _)| Some(&c.scheduled_start))),
+        positions(rows.iter().map(|(_, position, _)| *position)),
+        strings(rows.iter().map(|(_, _, p)| Some(p.name.as_str()))),
+        strings(rows.iter().map(|(_, _, p)| p.email.as_deref())),
+        strings(rows.iter().map(|(_, _, p)| p.title.as_deref())),
+        strings(rows.iter().map(|(_, _, p)| p.company.as_deref())),
+        Arc::new(BooleanArray::from(
+            rows.iter()
+                .map(|(_, _, p)| p.is_internal)
+                .collect::<Vec<_>>(),
+        )),
+        Arc::new(Float64Array::from_iter(
+            rows.iter().map(|(_, _, p)| p.speaking_time),
+        )),
+        Arc::new(Float64Array::from_iter(
+            rows.iter().map(|(_, _, p)| p.talk_ratio),
+        )),
+    ];
+    batch(schema, columns)
+}
+
+fn segment_batch(calls: &[&Call]) -> Result<RecordBatch> {
+    let rows: Vec<_> = calls
+        .iter()
+        .flat_map(|call| {
+            transcript_turns(call.transcript.as_deref().unwrap_or_default())
+                .into_iter()
+                .enumerate()
+                .map(move |(position, (speaker, text))| (*call, position, speaker, text))
+        })
+        .collect();
+
+    let schema = Schema::new(vec![
+        Field::new("call_id", DataType::Utf8, false),
+        Field::new("scheduled_start", timestamp_type(), false),
+        Field::new("position", DataType::Int32, false),
+        Field::new("speaker", DataType::Utf8, true),
+        Field::new("text", DataType::Utf8, false),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        strings(rows.iter().map(|(c, _, _, _)| Some(c.id.as_str()))),
+        timestamps(rows.iter().map(|(c, _, _, _)| Some(&c.scheduled_start))),
+        positions(rows.iter().map(|(_, position, _, _)| *position)),
+        strings(rows.iter().map(|(_, _, speaker, _)| speaker.as_deref())),
+        strings(rows.iter().map(|(_, _, _, text)| Some(text.as_str()))),
+    ];
+    batch(schema, columns)
+}
+
+fn email_batch(customer: &str, emails: &[&Email]) -> Result<RecordBatch> {
+    let recipients = |email: &Email, kind: &str| -> Vec<String> {
+        email
+            .recipients
+            .iter()
+            .filter(|r| r.recipient_type.eq_ignore_ascii_case(kind))
+            .map(|r| r.email.clone())
+            .collect()
+    };
+
+    let schema = Schema::new(vec![
+        Field::new("email_id", DataType::Utf8, false),
+        Field::new("account_id", DataType::Utf8, false),
+        Field::new("customer", DataType::Utf8, false),
+        Field::new("thread_id", DataType::Utf8, true),
+        Field::new("subject", DataType::Utf8, false),
+        Field::new("direction", DataType::Utf8, false),
+        Field::new("sent_at", timestamp_type(), false),
+        Field::new("received_at", timestamp_type(), true),
+        Field::new("timezone", DataType::Utf8, true),
+        Field::new("sender_email", DataType::Utf8, false),
+        Field::new("sender_name", DataType::Utf8, true),
+        Field::new("sender_is_internal", DataType::Boolean, false),
+        list_field("to"),
+        list_field("cc"),
+        Field::new("snippet", DataType::Utf8, true),
+        Field::new("body_text", DataType::Utf8, true),
+        Field::new("has_attachments", DataType::Boolean, false),
+        Field::new("attachment_count", DataType::Int64, false),
+        Field::new("is_automated", DataType::Boolean, false),
+        Field::new("is_template", DataType::Boolean, false),
+    ]);
+    let bools = |value: fn(&Email) -> bool| -> ArrayRef {
+        Arc::new(BooleanArray::from(
+            emails.iter().map(|e| value(e)).collect::<Vec<_>>(),
+        ))
+    };
+    let to: Vec<Vec<String>> = emails.iter().map(|e| recipients(e, "to")).collect();
+    let cc: Vec<Vec<String>> = emails.iter().map(|e| recipients(e, "cc")).collect();
+
+    let columns: Vec<ArrayRef> = vec![
+        strings(emails.iter().map(|e| Some(e.id.as_str()))),
+        strings(emails.iter().map(|e| Some(e.account_id.as_str()))),
+        strings(emails.iter().map(|_| Some(customer))),
+        strings(emails.iter().map(|e| e.thread_id.as_deref())),
+        strings(emails.iter().map(|e| Some(e.subject.as_str()))),
+        strings(emails.iter().map(|e| Some(lowercase_debug(&e.direction)))),
+        timestamps(emails.iter().map(|e| Some(&e.sent_at))),
+        timestamps(emails.iter().map(|e| e.received_at.as_ref())),
+        strings(emails.iter().map(|e| e.sent_at.time_zone().iana_name())),
+        strings(emails.iter().map(|e| Some(e.sender.email.as_str()))),
+        strings(emails.iter().map(|e| e.sender.name.as_deref())),
+        bools(|e| e.sender.is_internal),
+        string_lists(
+            to.iter()
+                .map(|list| list.iter().map(String::as_str).collect()),
+        ),
+        string_lists(
+            cc.iter()
+                .map(|list| list.iter().map(String::as_str).collect()),
+        ),
+        strings(emails.iter().map(|e| e.snippet.as_deref())),
+        strings(emails.iter().map(|e| e.body_text.as_deref())),
+        bools(|e| e.has_attachments),
+        Arc::new(Int64Array::from_iter(
+            emails.iter().map(|e| Some(i64::from(e.attachment_count))),
+        )),
+        bools(|e| e.is_automated),
+        bools(|e| e.is_template),
+    ];
+    batch(schema, columns)
+}
+
+/// Merge `batch` into `<dir>/data.parquet`, replacing existing rows whose
+/// `key` matches a row in `batch`
+fn write_partition(dir: &Path, key: &str, batch: RecordBatch) -> Result<PathBuf> {
+    let path = dir.join("data.parquet");
+    let io_error = |e: &dyn std::fmt::Display| {
+        CsCliError::FileIo(format!("Failed to write {}: {e}", path.display()))
+    };
+
+    fs::create_dir_all(dir).map_err(|e| io_error(&e))?;
+
+    let schema = batch.schema();
+    let new_keys: HashSet<String> = key_values(&batch, key).into_iter().flatten().collect();
+    let mut batches = Vec::new();
+    if path.exists() {
+        match read_existing(&path, &schema, key, &new_keys) {
+            Ok(kept) => batches.extend(kept),
+            Err(e) => warn!(
+                path = %path.display(),
+                error = %e,
+                "Replacing unreadable Parquet partition"
+            ),
+        }
+    }
+    batches.push(batch);
+    let merged = concat_batches(&schema, &batches).map_err(|e| io_error(&e))?;
+
+    // Write beside the partition and rename, so readers never see half a file
+    let temp = dir.join("data.parquet.tmp");
+    let file = File::create(&temp).map_err(|e| io_error(&e))?;
+    let props = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = ArrowWriter::try_new(file, schema, Some(props)).map_err(|e| io_error(&e))?;
+    writer.write(&merged).map_err(|e| io_error(&e))?;
+    writer.close().map_err(|e| io_error(&e))?;
+    fs::rename(&temp, &path).map_err(|e| io_error(&e))?;
+
+    Ok(path)
+}
+
+/// Rows of an existing partition that aren't being replaced
+fn read_existing(
+    path: &Path,
+    schema: &SchemaRef,
+    key: &str,
+    replaced: &HashSet<String>,
+) -> std::result::Result<Vec<RecordBatch>, Box<dyn std::error::Error>> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?.build()?;
+    let mut kept = Vec::new();
+    for old in reader {
+        let old = old?;
+        if old.schema().fields() != schema.fields() {
+            return Err("schema changed since the partition was written".into());
+        }
+        let keep: BooleanArray = key_values(&old, key)
+            .into_iter()
+            .map(|value| Some(!value.is_some_and(|v| replaced.contains(&v))))
+            .collect();
+        let filtered = filter_record_batch(&old, &keep)?;
+        kept.push(RecordBatch::try_new(
+            schema.clone(),
+            filtered.columns().to_vec(),
+        )?);
+    }
+    Ok(kept)
+}
+
+fn key_values(batch: &RecordBatch, key: &str) -> Vec<Option<String>> {
+    let Some(column) = batch
+        .column_by_name(key)
+        .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+    else {
+        return Vec::new();
+    };
+    (0..column.len())
+        .map(|i| (!column.is_null(i)).then(|| column.value(i).to_string()))
+        .collect()
+}
+
+fn batch(schema: Schema, columns: Vec<ArrayRef>) -> Result<RecordBatch> {
+    RecordBatch::try_new(Arc::new(schema), columns)
+        .map_err(|e| CsCliError::Generic(format!("Failed to build Parquet table: {e}")))
+}
+
+/// UTC timestamps with microsecond precision
+fn timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+}
+
+fn list_field(name: &str) -> Field {
+    Field::new_list(name, Field::new("item", DataType::Utf8, true), false)
+}
+
+fn strings<S: AsRef<str>>(values: impl Iterator<Item = Option<S>>) -> ArrayRef {
+    Arc::new(StringArray::from_iter(values))
+}
+
+fn timestamps<'a>(values: impl Iterator<Item = Option<&'a Zoned>>) -> ArrayRef {
+    Arc::new(
+        TimestampMicrosecondArray::from_iter(
+            values.map(|date| date.map(|d| d.timestamp().as_microsecond())),
+        )
+        .with_timezone("UTC"),
+    )
+}
+
+fn positions(values: impl Iterator<Item = usize>) -> ArrayRef {
+    Arc::new(Int32Array::from_iter_values(
+        values.map(|position| i32::try_from(position).unwrap_or(i32::MAX)),
+    ))
+}
+
+fn string_lists<'a>(rows: impl Iterator<Item = Vec<&'a str>>) -> ArrayRef {
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for row in rows {
+        for value in row {
+            builder.values().append_value(value);
+        }
+        builder.append(true);
+    }
+    Arc::new(builder.finish())
+}
+
+fn lowercase_debug(value: &impl std::fmt::Debug) -> String {
+    format!("{value:?}").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{CallDirection, CallParticipant};
+
+    fn read_rows(path: &Path) -> Vec<RecordBatch> {
+        ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap()
+            .map(|batch| batch.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_partitions_and_merges_by_id() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-parquet-{}", std::process::id()));
+        let call = |id: &str, date: &str| {
+            let mut call = Call::new(
+                id.to_string(),
+                "acct".to_string(),
+                format!("Call {id}"),
+                CallDirection::Outbound,
+                900,
+                date.parse().unwrap(),
+            );
+            call.topics = vec!["Pricing".to_string()];
+            call.participants.push(CallParticipant {
+                id: None,
+                name: "Dana".to_string(),
+                email: Some("dana@acme.com".to_string()),
+                phone: None,
+                title: None,
+                company: None,
+                is_internal: false,
+                speaking_time: None,
+                talk_ratio: None,
+            });
+            call.transcript = Some("**Dana:** Hello\n**Sam:** Hi".to_string());
+            call
+        };
+        let writer = ParquetWriter::new(&dir);
+
+        let files = writer
+            .write(
+                "Acme Corp",
+                &[
+                    call("1", "2025-01-31T20:00:00-05:00[America/New_York]"),
+                    call("2", "2025-02-03T10:00:00+00:00[UTC]"),
+                ],
+                &[],
+            )
+            .unwrap();
+        assert_eq!(files.len(), 6);
+        let january = dir.join("calls/customer=acme-corp/month=2025-01/data.parquet");
+        assert!(files.contains(&january));
+
+        // Re-running replaces call 1 and adds call 3 in the same month
+        let mut renamed = call("1", "2025-01-31T20:00:00-05:00[America/New_York]");
+        renamed.title = "Renamed".to_string();
+        writer
+            .write(
+                "Acme Corp",
+                &[renamed, call("3", "2025-01-05T10:00:00+00:00[UTC]")],
+                &[],
+            )
+            .unwrap();
+
+        let batches = read_rows(&january);
+        let rows: usize = batches.iter().map(RecordBatch::num_rows).sum();
+        assert_eq!(rows, 2);
+        let merged = concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(
+            merged
+                .schema()
+                .field_with_name("scheduled_start")
+                .unwrap()
+                .data_type(),
+            &timestamp_type()
+        );
+        let titles = merged
+            .column_by_name("title")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let mut titles: Vec<&str> = titles.iter().flatten().collect();
+        titles.sort();
+        assert_eq!(titles, ["Call 3", "Renamed"]);
+
+        let segments =
+            dir.join("transcript_segments/customer=acme-corp/month=2025-01/data.parquet");
+        let rows: usize = read_rows(&segments).iter().map(RecordBatch::num_rows).sum();
+        assert_eq!(rows, 4);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
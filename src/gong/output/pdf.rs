@@ -0,0 +1,354 @@
+//! PDF customer brief
+//!
+//! Renders a [`Dossier`] with `printpdf` using the built-in Helvetica fonts,
+//! so nothing has to be installed. Those fonts only cover Latin text, so
+//! accents are folded and other characters are replaced before drawing.
+
+use printpdf::{
+    BuiltinFont, Color, IndirectFontRef, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference,
+    Rgb,
+};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use super::dossier::{ActivityKind, Dossier};
+use super::report::SPEAKER_COLORS;
+use crate::{CsCliError, Result};
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 20.0;
+/// Millimetres per point
+const PT: f32 = 0.3528;
+
+const ACCENT: &str = "#ff6c37";
+const TEXT: &str = "#1f2937";
+const MUTED: &str = "#6b7280";
+
+/// Writes customer briefs as `.pdf`
+pub struct PdfBriefWriter {
+    output_dir: PathBuf,
+}
+
+impl PdfBriefWriter {
+    /// Write into `output_dir`, usually the customer's `ct_<customer>` folder
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// Save the brief, returning its path
+    pub fn write(&self, dossier: &Dossier) -> Result<PathBuf> {
+        fs::create_dir_all(&self.output_dir)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create output directory: {e}")))?;
+
+        let path = dossier.file_path(&self.output_dir, "pdf");
+        let mut layout = Layout::new(&format!("{} customer brief", dossier.customer))?;
+
+        // Cover
+        layout.space(70.0);
+        layout.centered("Customer brief", 14.0, false, MUTED);
+        layout.space(4.0);
+        layout.centered(&dossier.customer, 32.0, true, ACCENT);
+        layout.space(4.0);
+        layout.centered(&dossier.period(), 14.0, false, MUTED);
+        layout.space(2.0);
+        layout.centered(
+            &format!(
+                "Prepared {} with cs-cli",
+                dossier.generated_at.strftime("%B %d, %Y")
+            ),
+            10.0,
+            false,
+            MUTED,
+        );
+
+        // Summary
+        layout.new_page();
+        layout.heading("Summary");
+        for (label, value) in dossier.stats() {
+            layout.row(label, &value, 11.0);
+        }
+
+        // Activity
+        layout.space(6.0);
+        layout.heading("Activity");
+        if dossier.activity.is_empty() {
+            layout.paragraph(None, "No calls or emails", 10.0, MUTED);
+        }
+        for item in &dossier.activity {
+            let kind = match item.kind {
+                ActivityKind::Call => "Call",
+                ActivityKind::Email => "Email",
+            };
+            let date = format!("{}  ·  {kind}  ·  ", item.date.strftime("%b %d, %Y"));
+            layout.paragraph(Some((&date, MUTED)), &item.title, 10.5, TEXT);
+            layout.paragraph(None, &item.detail, 9.0, MUTED);
+            layout.space(2.0);
+        }
+
+        // Transcripts
+        for excerpt in &dossier.transcripts {
+            layout.new_page();
+            layout.heading(&excerpt.title);
+            layout.paragraph(None, &excerpt.meta, 10.0, MUTED);
+            layout.space(3.0);
+
+            let mut speakers: Vec<&str> = Vec::new();
+            for (speaker, line) in &excerpt.turns {
+                let lead = speaker.as_deref().map(|speaker| {
+                    let index = match speakers.iter().position(|s| *s == speaker) {
+                        Some(index) => index,
+                        None => {
+                            speakers.push(speaker);
+                            speakers.len() - 1
+                        }
+                    };
+                    (
+                        format!("{speaker}: "),
+                        SPEAKER_COLORS[index % SPEAKER_COLORS.len()],
+                    )
+                });
+                layout.paragraph(
+                    lead.as_ref().map(|(name, color)| (name.as_str(), *color)),
+                    line,
+                    10.0,
+                    TEXT,
+                );
+                layout.space(1.5);
+            }
+        }
+
+        layout.save(&path)?;
+        info!(path = %path.display(), "Saved PDF brief");
+        Ok(path)
+    }
+}
+
+/// Page cursor: draws lines top to bottom and starts new pages as needed
+struct Layout {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    /// Top of the next line, in millimetres from the bottom of the page
+    y: f32,
+}
+
+impl Layout {
+    fn new(title: &str) -> Result<Self> {
+        let (doc, page, layer) =
+            PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        let font = |font| {
+            doc.add_builtin_font(font)
+                .map_err(|e| CsCliError::Generic(format!("Failed to load PDF font: {e}")))
+        };
+        let regular = font(BuiltinFont::Helvetica)?;
+        let bold = font(BuiltinFont::HelveticaBold)?;
+        let layer = doc.get_page(page).get_layer(layer);
+
+        Ok(Self {
+            doc,
+            layer,
+            regular,
+            bold,
+            y: PAGE_HEIGHT - MARGIN,
+        })
+    }
+
+    fn new_page(&mut self) {
+        let (page, layer) = self
+            .doc
+            .add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn space(&mut self, mm: f32) {
+        self.y -= mm;
+    }
+
+    /// Move to the baseline of a line of `size` points, breaking the page if
+    /// it doesn't fit
+    fn next_line(&mut self, size: f32) -> f32 {
+        let height = size * PT * 1.4;
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+        self.y -= height;
+        self.y + size * PT * 0.4
+    }
+
+    fn draw(&self, text: &str, size: f32, x: f32, baseline: f32, bold: bool, color: &str) {
+        self.layer.set_fill_color(rgb(color));
+        let font = if bold { &self.bold } else { &self.regular };
+        self.layer
+            .use_text(pdf_text(text), size, Mm(x), Mm(baseline), font);
+    }
+
+    fn centered(&mut self, text: &str, size: f32, bold: bool, color: &str) {
+        let baseline = self.next_line(size);
+        let x = ((PAGE_WIDTH - text_width(text, size, bold)) / 2.0).max(MARGIN);
+        self.draw(text, size, x, baseline, bold, color);
+    }
+
+    fn heading(&mut self, text: &str) {
+        self.space(2.0);
+        for line in wrap(text, PAGE_WIDTH - 2.0 * MARGIN, 16.0, true, 0.0) {
+            let baseline = self.next_line(16.0);
+            self.draw(&line, 16.0, MARGIN, baseline, true, ACCENT);
+        }
+        self.space(2.0);
+    }
+
+    /// Label and value on one line
+    fn row(&mut self, label: &str, value: &str, size: f32) {
+        let baseline = self.next_line(size);
+        self.draw(label, size, MARGIN, baseline, true, TEXT);
+        self.draw(value, size, MARGIN + 60.0, baseline, false, TEXT);
+    }
+
+    /// Wrapped text, optionally led by a bold colored run such as a speaker
+    fn paragraph(&mut self, lead: Option<(&str, &str)>, text: &str, size: f32, color: &str) {
+        let indent = lead.map_or(0.0, |(lead, _)| text_width(lead, size, true));
+        for (i, line) in wrap(text, PAGE_WIDTH - 2.0 * MARGIN, size, false, indent)
+            .iter()
+            .enumerate()
+        {
+            let baseline = self.next_line(size);
+            let mut x = MARGIN;
+            if let (0, Some((lead, lead_color))) = (i, lead) {
+                self.draw(lead, size, x, baseline, true, lead_color);
+                x += indent;
+            }
+            self.draw(line, size, x, baseline, false, color);
+        }
+    }
+
+    fn save(self, path: &Path) -> Result<()> {
+        let file = File::create(path)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create {}: {e}", path.display())))?;
+        self.doc
+            .save(&mut BufWriter::new(file))
+            .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))
+    }
+}
+
+/// Approximate Helvetica width in millimetres
+fn text_width(text: &str, size: f32, bold: bool) -> f32 {
+    let em = if bold { 0.56 } else { 0.52 };
+    text.chars().count() as f32 * size * PT * em
+}
+
+/// Greedy word wrap; the first line is `indent` millimetres shorter
+fn wrap(text: &str, width: f32, size: f32, bold: bool, indent: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let available = if lines.is_empty() {
+            width - indent
+        } else {
+            width
+        };
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+        if !line.is_empty() && text_width(&candidate, size, bold) > available {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        } else {
+            line = candidate;
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Text the built-in fonts can draw: typographic punctuation becomes ASCII,
+/// accents are dropped and anything else becomes `?`
+fn pdf_text(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' => c,
+            '‘' | '’' => '\'',
+            '“' | '”' => '"',
+            '–' | '—' | '·' => '-',
+            '•' => '*',
+            'à'..='å' => 'a',
+            'À'..='Å' => 'A',
+            'ç' => 'c',
+            'Ç' => 'C',
+            'è'..='ë' => 'e',
+            'È'..='Ë' => 'E',
+            'ì'..='ï' => 'i',
+            'Ì'..='Ï' => 'I',
+            'ñ' => 'n',
+            'Ñ' => 'N',
+            'ò'..='ö' | 'ø' => 'o',
+            'Ò'..='Ö' | 'Ø' => 'O',
+            'ù'..='ü' => 'u',
+            'Ù'..='Ü' => 'U',
+            'ý' | 'ÿ' => 'y',
+            'ß' => 's',
+            '\t' => ' ',
+            _ => '?',
+        })
+        .collect()
+}
+
+/// `#rrggbb` as a fill color
+fn rgb(hex: &str) -> Color {
+    let channel = |i: usize| {
+        u8::from_str_radix(
+            hex.trim_start_matches('#').get(i..i + 2).unwrap_or("00"),
+            16,
+        )
+        .unwrap_or(0) as f32
+            / 255.0
+    };
+    Color::Rgb(Rgb::new(channel(0), channel(2), channel(4), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{Call, CallDirection};
+
+    #[test]
+    fn test_wrap_and_text_cleanup() {
+        let lines = wrap(&"word ".repeat(60), 100.0, 10.0, false, 30.0);
+        assert!(lines.len() > 2);
+        assert!(text_width(&lines[0], 10.0, false) <= 70.0);
+        assert!(text_width(&lines[1], 10.0, false) <= 100.0);
+        assert_eq!(wrap("", 100.0, 10.0, false, 0.0), vec![String::new()]);
+
+        assert_eq!(pdf_text("José’s “café” – 東京"), "Jose's \"cafe\" - ??");
+    }
+
+    #[test]
+    fn test_write_pdf_brief() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-pdf-{}", std::process::id()));
+        let mut call = Call::new(
+            "c1".to_string(),
+            "acct".to_string(),
+            "Kickoff".to_string(),
+            CallDirection::Outbound,
+            600,
+            "2025-01-15T16:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        call.transcript = Some("**Dana:** Welcome\n".repeat(200));
+        let dossier = Dossier::new("Acme Corp", &[call], &[], 5);
+
+        let path = PdfBriefWriter::new(&dir).write(&dossier).unwrap();
+        assert_eq!(path, dir.join("acme-corp-brief.pdf"));
+        assert!(fs::read(&path).unwrap().starts_with(b"%PDF"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,579 @@
+//! Self-contained HTML report per customer
+//!
+//! `index.html` holds a searchable timeline of calls and emails plus the
+//! attendee roster; each call gets its own page with a collapsible,
+//! speaker-colored transcript. Styles and scripts are inlined so the folder
+//! can be zipped and opened offline.
+
+use jiff::Zoned;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use super::slug::title_slug;
+use super::snapshot::generated_at;
+use crate::gong::models::{Call, Email};
+use crate::{CsCliError, Result};
+
+/// Speaker colors, assigned in order of first appearance
+pub const SPEAKER_COLORS: &[&str] = &[
+    "#ff6c37", "#2e7dd7", "#1f9d55", "#8e44ad", "#c0392b", "#d68910", "#16a085", "#5d6d7e",
+];
+
+const STYLE: &str = r#"
+:root { --accent: #ff6c37; --text: #1f2328; --muted: #656d76; --line: #d0d7de; --bg: #f6f8fa; }
+* { box-sizing: border-box; }
+body { margin: 0; font: 15px/1.5 -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif; color: var(--text); }
+header { background: var(--bg); border-bottom: 1px solid var(--line); padding: 24px 32px; }
+header h1 { margin: 0 0 4px; }
+header p { margin: 0; color: var(--muted); }
+main { max-width: 980px; margin: 0 auto; padding: 16px 32px 48px; }
+a { color: #0969da; text-decoration: none; }
+a:hover { text-decoration: underline; }
+#search { width: 100%; margin-top: 16px; padding: 8px 12px; font-size: 15px; border: 1px solid var(--line); border-radius: 6px; }
+h2 { border-bottom: 1px solid var(--line); padding-bottom: 4px; }
+.month h3 { color: var(--muted); margin: 24px 0 8px; }
+.item { border: 1px solid var(--line); border-radius: 6px; padding: 10px 14px; margin: 8px 0; }
+.item time { color: var(--muted); font-size: 13px; margin-right: 8px; }
+.badge { display: inline-block; font-size: 12px; padding: 0 8px; border-radius: 10px; margin-right: 8px; color: #fff; }
+.badge.call { background: var(--accent); }
+.badge.email { background: #2e7dd7; }
+.meta { color: var(--muted); font-size: 13px; margin: 4px 0 0; }
+.body { white-space: pre-wrap; margin-top: 8px; }
+.roster { columns: 2; padding-left: 18px; }
+.roster .internal { color: var(--muted); }
+.person .meta { display: inline; }
+details summary { cursor: pointer; }
+.line { margin: 6px 0; padding-left: 10px; border-left: 3px solid var(--speaker); }
+.line .speaker { color: var(--speaker); font-weight: 600; }
+.hidden { display: none; }
+footer { color: var(--muted); font-size: 12px; margin-top: 32px; }
+"#;
+
+/// Hides entries, people and transcript lines that don't contain every search term
+const SCRIPT: &str = r#"
+const search = document.getElementById('search');
+search.addEventListener('input', () => {
+  const terms = search.value.toLowerCase().split(/\s+/).filter(Boolean);
+  document.querySelectorAll('.item, .person, .line').forEach(item => {
+    const text = item.textContent.toLowerCase();
+    item.classList.toggle('hidden', !terms.every(term => text.includes(term)));
+  });
+  document.querySelectorAll('.month').forEach(month => {
+    month.classList.toggle('hidden', !month.querySelector('.item:not(.hidden)'));
+  });
+  if (terms.length) document.querySelectorAll('details').forEach(d => { d.open = true; });
+});
+"#;
+
+/// Writes a customer's calls and emails as a static HTML report
+pub struct HtmlReportWriter {
+    output_dir: PathBuf,
+}
+
+impl HtmlReportWriter {
+    /// Write into `output_dir`, usually the customer's `ct_<customer>` folder
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// Write `index.html` and one page per call, returning the files created
+    pub fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        let calls_dir = self.output_dir.join("calls");
+        fs::create_dir_all(&calls_dir)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create output directory: {e}")))?;
+
+        let mut saved_files = Vec::new();
+        let mut call_pages = HashMap::new();
+        for call in calls {
+            let file_name = call_page_name(call);
+            let path = calls_dir.join(&file_name);
+            write_file(&path, &self.call_page(customer, call))?;
+            call_pages.insert(call.id.as_str(), format!("calls/{file_name}"));
+            saved_files.push(path);
+        }
+
+        let index_path = self.output_dir.join("index.html");
+        write_file(
+            &index_path,
+            &self.index_page(customer, calls, emails, &call_pages),
+        )?;
+        saved_files.insert(0, index_path);
+
+        info!(
+            files = saved_files.len(),
+            output_dir = %self.output_dir.display(),
+            "Saved HTML report"
+        );
+        Ok(saved_files)
+    }
+
+    /// Timeline, search and roster
+    fn index_page(
+        &self,
+        customer: &str,
+        calls: &[Call],
+        emails: &[Email],
+        call_pages: &HashMap<&str, String>,
+    ) -> String {
+        // Newest first, calls and emails interleaved
+        let mut entries: Vec<(&Zoned, String)> = calls
+            .iter()
+            .map(|call| {
+                (
+                    &call.scheduled_start,
+                    call_item(call, call_pages.get(call.id.as_str())),
+                )
+            })
+            .chain(
+                emails
+                    .iter()
+                    .map(|email| (&email.sent_at, email_item(email))),
+            )
+            .collect();
+        entries.sort_by(|a, b| b.0.cmp(a.0));
+
+        let mut timeline = String::new();
+        let mut current_month = String::new();
+        for (date, item) in entries {
+            let month = date.strftime("%B %Y").to_string();
+            if month != current_month {
+                if !current_month.is_empty() {
+                    timeline.push_str("</section>\n");
+                }
+                timeline.push_str(&format!(
+                    "<section class=\"month\"><h3>{}</h3>\n",
+                    escape(&month)
+                ));
+                current_month = month;
+            }
+            timeline.push_str(&item);
+        }
+        if !current_month.is_empty() {
+            timeline.push_str("</section>\n");
+        }
+        if timeline.is_empty() {
+            timeline.push_str("<p>No calls or emails in this period.</p>\n");
+        }
+
+        let summary = format!("{} calls · {} emails", calls.len(), emails.len());
+        let body = format!(
+            "<header>\n<h1>{customer}</h1>\n<p>{summary}</p>\n\
+             <input id=\"search\" type=\"search\" placeholder=\"Search calls, emails and people\" autofocus>\n</header>\n\
+             <main>\n<h2>Attendees</h2>\n{roster}\n<h2>Timeline</h2>\n{timeline}{footer}</main>\n",
+            customer = escape(customer),
+            roster = roster(calls, emails),
+            footer = footer(),
+        );
+        page(customer, &body)
+    }
+
+    /// One call with its attendees and transcript
+    fn call_page(&self, customer: &str, call: &Call) -> String {
+        let mut body = format!(
+            "<header>\n<p><a href=\"../index.html\">← {}</a></p>\n<h1>{}</h1>\n<p>{}</p>\n\
+             <input id=\"search\" type=\"search\" placeholder=\"Search the transcript\">\n</header>\n<main>\n",
+            escape(customer),
+            escape(&call.title),
+            escape(&call_meta(call)),
+        );
+
+        if let Some(url) = call.recording_url.as_deref().filter(|u| !u.is_empty()) {
+            body.push_str(&format!(
+                "<p><a href=\"{}\">Open in Gong</a></p>\n",
+                escape(url)
+            ));
+        }
+        if let Some(brief) = call.call_brief.as_deref().filter(|b| !b.trim().is_empty()) {
+            body.push_str(&format!(
+                "<h2>Brief</h2>\n<p class=\"body\">{}</p>\n",
+                escape(brief.trim())
+            ));
+        }
+
+        body.push_str("<h2>Attendees</h2>\n<ul class=\"roster\">\n");
+        if call.participants.is_empty() {
+            body.push_str("<li>No attendee information available.</li>\n");
+        }
+        for person in &call.participants {
+            let details: Vec<&str> = [
+                person.title.as_deref(),
+                person.company.as_deref(),
+                person.email.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|d| !d.is_empty())
+            .collect();
+            body.push_str(&format!(
+                "<li{}><strong>{}</strong>{}</li>\n",
+                if person.is_internal {
+                    " class=\"internal\""
+                } else {
+                    ""
+                },
+                escape(&person.name),
+                if details.is_empty() {
+                    String::new()
+                } else {
+                    format!(" · {}", escape(&details.join(" · ")))
+                }
+            ));
+        }
+        body.push_str("</ul>\n");
+
+        body.push_str(
+            "<details open>\n<summary><h2 style=\"display:inline\">Transcript</h2></summary>\n",
+        );
+        body.push_str(&transcript_html(
+            call.transcript.as_deref().unwrap_or_default(),
+        ));
+        body.push_str("</details>\n");
+        body.push_str(&footer());
+        body.push_str("</main>\n");
+
+        page(&call.title, &body)
+    }
+}
+
+/// Timeline entry for a call
+fn call_item(call: &Call, page: Option<&String>) -> String {
+    let title = match page {
+        Some(page) => format!("<a href=\"{}\">{}</a>", escape(page), escape(&call.title)),
+        None => escape(&call.title),
+    };
+    let brief = call
+        .call_brief
+        .as_deref()
+        .filter(|b| !b.trim().is_empty())
+        .map(|b| format!("<p class=\"body\">{}</p>", escape(b.trim())))
+        .unwrap_or_default();
+    let people: Vec<&str> = call.participants.iter().map(|p| p.name.as_str()).collect();
+
+    format!(
+        "<article class=\"item call\"><time>{}</time><span class=\"badge call\">Call</span>{title}\
+         <p class=\"meta\">{}</p>{brief}</article>\n",
+        call.scheduled_start.strftime("%b %d, %Y %H:%M"),
+        escape(&format!(
+            "{} · {}",
+            format_duration(call.duration),
+            people.join(", ")
+        )),
+    )
+}
+
+/// Timeline entry for an email, expandable to the full body
+fn email_item(email: &Email) -> String {
+    let sender = email.sender.name.as_deref().unwrap_or(&email.sender.email);
+    let recipients: Vec<&str> = email
+        .recipients
+        .iter()
+        .filter(|r| r.recipient_type != "bcc")
+        .map(|r| r.name.as_deref().unwrap_or(&r.email))
+        .collect();
+    let subject = if email.subject.is_empty() {
+        "No Subject"
+    } else {
+        &email.subject
+    };
+    let body = email
+        .body_text
+        .as_deref()
+        .or(email.snippet.as_deref())
+        .unwrap_or("No content available.");
+
+    format!(
+        "<article class=\"item email\"><details><summary><time>{}</time><span class=\"badge email\">Email</span>{}\
+         <p class=\"meta\">{} → {}</p></summary><div class=\"body\">{}</div></details></article>\n",
+        email.sent_at.strftime("%b %d, %Y %H:%M"),
+        escape(subject),
+        escape(sender),
+        escape(&recipients.join(", ")),
+        escape(body.trim()),
+    )
+}
+
+/// Everyone who appeared on a call or email, most frequent first
+fn roster(calls: &[Call], emails: &[Email]) -> String {
+    // Keyed by email when known so the same person isn't listed twice
+    let mut people: HashMap<String, (String, Option<String>, bool, usize)> = HashMap::new();
+    let mut add = |name: &str, email: Option<&str>, detail: Option<String>, internal: bool| {
+        let key = email.unwrap_or(name).to_lowercase();
+        let entry = people
+            .entry(key)
+            .or_insert_with(|| (name.to_string(), detail.clone(), internal, 0));
+        if entry.1.is_none() {
+            entry.1 = detail;
+        }
+        entry.3 += 1;
+    };
+
+    for person in calls.iter().flat_map(|call| &call.participants) {
+        let detail = [person.title.as_deref(), person.company.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|d| !d.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ");
+        add(
+            &person.name,
+            person.email.as_deref(),
+            Some(detail).filter(|d| !d.is_empty()),
+            person.is_internal,
+        );
+    }
+    for recipient in emails
+        .iter()
+        .flat_map(|email| std::iter::once(&email.sender).chain(&email.recipients))
+    {
+        add(
+            recipient.name.as_deref().unwrap_or(&recipient.email),
+            Some(&recipient.email),
+            recipient.company.clone(),
+            recipient.is_internal,
+        );
+    }
+
+    if people.is_empty() {
+        return "<p>No attendee information available.</p>".to_string();
+    }
+
+    let mut people: Vec<_> = people.into_values().collect();
+    people.sort_by(|a, b| b.3.cmp(&a.3).then_with(|| a.0.cmp(&b.0)));
+
+    let items: String = people
+        .iter()
+        .map(|(name, detail, internal, count)| {
+            format!(
+                "<li class=\"person{}\"><strong>{}</strong>{} <span class=\"meta\">({count})</span></li>\n",
+                if *internal { " internal" } else { "" },
+                escape(name),
+                detail
+                    .as_deref()
+                    .map(|d| format!(" · {}", escape(d)))
+                    .unwrap_or_default(),
+            )
+        })
+        .collect();
+    format!("<ul class=\"roster\">\n{items}</ul>")
+}
+
+/// Transcript lines with a color per speaker
+///
+/// Understands the `**Speaker:** text` lines the API client produces and
+/// plain `Speaker: text` lines; other lines continue the previous turn.
+fn transcript_html(transcript: &str) -> String {
+    if transcript.trim().is_empty() {
+        return "<p>No transcript available.</p>\n".to_string();
+    }
+
+    let mut colors: HashMap<String, &str> = HashMap::new();
+    let mut html = String::new();
+    for (speaker, text) in transcript_turns(transcript) {
+        match speaker {
+            Some(speaker) => {
+                let next = SPEAKER_COLORS[colors.len() % SPEAKER_COLORS.len()];
+                let color = *colors.entry(speaker.clone()).or_insert(next);
+                html.push_str(&format!(
+                    "<p class=\"line\" style=\"--speaker: {color}\"><span class=\"speaker\">{}:</span> {}</p>\n",
+                    escape(&speaker),
+                    escape(&text)
+                ));
+            }
+            None => html.push_str(&format!(
+                "<p class=\"line\" style=\"--speaker: var(--line)\">{}</p>\n",
+                escape(&text)
+            )),
+        }
+    }
+    html
+}
+
+/// Split a transcript into `(speaker, text)` turns
+///
+/// Handles both `**Speaker:** text` and plain `Speaker: text` lines; lines
+/// without a speaker continue the previous turn.
+pub fn transcript_turns(transcript: &str) -> Vec<(Option<String>, String)> {
+    let formatted = Regex::new(r"^\*\*(.+?):\*\*\s*(.*)$").unwrap();
+    let plain = Regex::new(r"^([^:]{1,50}):\s+(.*)$").unwrap();
+
+    let mut turns: Vec<(Option<String>, String)> = Vec::new();
+    for line in transcript.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let captures = formatted.captures(line).or_else(|| plain.captures(line));
+        match captures {
+            Some(captures) => turns.push((
+                Some(captures[1].trim().to_string()),
+                captures[2].to_string(),
+            )),
+            None => match turns.last_mut() {
+                Some((_, text)) => {
+                    text.push(' ');
+                    text.push_str(line);
+                }
+                None => turns.push((None, line.to_string())),
+            },
+        }
+    }
+    turns
+}
+
+/// Call date, duration and attendee count
+fn call_meta(call: &Call) -> String {
+    format!(
+        "{} · {} · {} attendees",
+        call.scheduled_start.strftime("%A, %B %d, %Y %H:%M"),
+        format_duration(call.duration),
+        call.participants.len()
+    )
+}
+
+/// Page file name: date, title and short call ID
+fn call_page_name(call: &Call) -> String {
+    let id: String = call.id.chars().take(8).collect();
+    format!(
+        "{}-{}-{id}.html",
+        call.scheduled_start.strftime("%Y-%m-%d"),
+        title_slug(
+            call.generated_title.as_deref().unwrap_or(&call.title),
+            "call"
+        )
+    )
+}
+
+/// Call length as `45 min` or `1h 05m`
+pub fn format_duration(seconds: i32) -> String {
+    let minutes = (seconds.max(0) + 30) / 60;
+    if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{minutes} min")
+    }
+}
+
+fn footer() -> String {
+    format!(
+        "<footer>Generated by cs-cli on {}</footer>\n",
+        generated_at().strftime("%Y-%m-%d %H:%M")
+    )
+}
+
+/// Complete document with the inline stylesheet and search script
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n{body}<script>{SCRIPT}</script>\n</body>\n</html>\n",
+        escape(title),
+    )
+}
+
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))
+}
+
+/// Escape text for HTML content and attribute values
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{CallDirection, CallParticipant, EmailDirection};
+
+    fn sample_call() -> Call {
+        let start: Zoned = "2025-01-15T16:00:00+00:00[UTC]".parse().unwrap();
+        let mut call = Call::new(
+            "7782342274025937895".to_string(),
+            "acct".to_string(),
+            "Quarterly review <Acme>".to_string(),
+            CallDirection::Outbound,
+            1800,
+            start,
+        );
+        call.participants.push(CallParticipant {
+            id: None,
+            name: "Dana Customer".to_string(),
+            email: Some("dana@acme.com".to_string()),
+            phone: None,
+            title: Some("VP Engineering".to_string()),
+            company: Some("Acme".to_string()),
+            is_internal: false,
+            speaking_time: None,
+            talk_ratio: None,
+        });
+        call.transcript = Some(
+            "**Sam:** Thanks for joining\n**Dana Customer:** Happy to\nand glad we met\n**Sam:** Great"
+                .to_string(),
+        );
+        call
+    }
+
+    #[test]
+    fn test_transcript_speakers_get_stable_colors() {
+        let html = transcript_html(sample_call().transcript.as_deref().unwrap());
+        let lines: Vec<&str> = html.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains(SPEAKER_COLORS[0]) && lines[0].contains("Sam:"));
+        assert!(
+            lines[1].contains(SPEAKER_COLORS[1]) && lines[1].contains("Happy to and glad we met")
+        );
+        assert!(lines[2].contains(SPEAKER_COLORS[0]));
+    }
+
+    #[test]
+    fn test_write_report() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-html-report-{}", std::process::id()));
+        let mut email = Email::new(
+            "e1".to_string(),
+            "acct".to_string(),
+            "Renewal & pricing".to_string(),
+            EmailDirection::Inbound,
+            "2025-01-20T09:00:00+00:00[UTC]".parse().unwrap(),
+            "dana@acme.com".to_string(),
+        );
+        email.body_text = Some("Can we talk <soon>?".to_string());
+
+        let files = HtmlReportWriter::new(&dir)
+            .write("Acme Corp", &[sample_call()], &[email])
+            .unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0], dir.join("index.html"));
+        assert_eq!(
+            files[1],
+            dir.join("calls/2025-01-15-quarterly-review-acme-77823422.html")
+        );
+
+        let index = fs::read_to_string(&files[0]).unwrap();
+        assert!(index.contains("<h1>Acme Corp</h1>"));
+        assert!(index.contains("href=\"calls/2025-01-15-quarterly-review-acme-77823422.html\""));
+        assert!(index.contains("Renewal &amp; pricing"));
+        assert!(index.contains("Can we talk &lt;soon&gt;?"));
+        // Email sender and call attendee are the same person
+        assert_eq!(index.matches("<strong>Dana Customer</strong>").count(), 1);
+        assert!(index.contains("<script>") && !index.contains("src=\"http"));
+
+        let call_page = fs::read_to_string(&files[1]).unwrap();
+        assert!(call_page.contains("Quarterly review &lt;Acme&gt;"));
+        assert!(call_page.contains("<details open>"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,49 @@
+//! File name parts derived from customer names, titles and channels
+
+/// Lowercase, hyphenated file name part, or `fallback` when `name` has no
+/// ASCII letters or digits
+pub fn slug(name: &str, fallback: &str) -> String {
+    let slug = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        fallback.to_string()
+    } else {
+        slug
+    }
+}
+
+/// [`slug`] cut to its first eight words, for titles in file names
+pub fn title_slug(title: &str, fallback: &str) -> String {
+    slug(title, fallback)
+        .split('-')
+        .take(8)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug("Acme Corp, Inc.", "customer"), "acme-corp-inc");
+        assert_eq!(slug("  ---  ", "customer"), "customer");
+        assert_eq!(
+            title_slug("One two three four five six seven eight nine", "call"),
+            "one-two-three-four-five-six-seven-eight"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Saved extractions for offline re-rendering
+//!
+//! Every customer extraction is saved as JSON so `cs-cli render` can rebuild
+//! the output folder after template, redaction or format changes without
+//! going back to Gong. Rendering a snapshot stamps files with the snapshot's
+//! extraction time instead of the clock, so the output is reproducible.
+
+use jiff::Zoned;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use super::manifest::ExtractionParameters;
+use super::slug::slug;
+use crate::gong::models::{Call, Email, EmailFilterDecision};
+use crate::{CsCliError, Result};
+
+/// Bumped when the snapshot layout changes incompatibly
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// `--from` value that reads the saved extractions folder
+pub const SNAPSHOT_CACHE: &str = "cache";
+
+thread_local! {
+    static GENERATED_AT: RefCell<Option<Zoned>> = const { RefCell::new(None) };
+}
+
+/// Time to stamp on generated files: the snapshot's extraction time while
+/// re-rendering, otherwise now
+pub fn generated_at() -> Zoned {
+    GENERATED_AT
+        .with(|at| at.borrow().clone())
+        .unwrap_or_else(Zoned::now)
+}
+
+/// Run `render` with [`generated_at`] pinned to `at`
+pub fn with_generated_at<T>(at: &Zoned, render: impl FnOnce() -> T) -> T {
+    let previous = GENERATED_AT.with(|cell| cell.replace(Some(at.clone())));
+    let result = render();
+    GENERATED_AT.with(|cell| *cell.borrow_mut() = previous);
+    result
+}
+
+/// Folder the CLI saves extractions in: `~/.cache/cs-cli/extractions`
+pub fn default_snapshot_dir() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("cs-cli").join("extractions"))
+}
+
+/// A customer's extracted calls and emails, as saved after extraction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractionSnapshot {
+    /// Layout version, see [`SNAPSHOT_VERSION`]
+    pub version: u32,
+    /// Customer name as resolved by Gong search
+    pub customer: String,
+    /// When the data was fetched from Gong
+    pub extracted_at: Zoned,
+    /// Only emails were requested, so calls are not rendered
+    #[serde(default)]
+    pub emails_only: bool,
+    /// What was asked of Gong, recorded in `manifest.json`
+    #[serde(default)]
+    pub parameters: ExtractionParameters,
+    pub calls: Vec<Call>,
+    pub emails: Vec<Email>,
+    /// Why each email was kept or dropped by the email filters
+    #[serde(default)]
+    pub filter_decisions: Vec<EmailFilterDecision>,
+}
+
+impl ExtractionSnapshot {
+    /// Snapshot of an extraction that just finished
+    pub fn new(customer: &str, calls: &[Call], emails: &[Email], emails_only: bool) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            customer: customer.to_string(),
+            extracted_at: Zoned::now(),
+            emails_only,
+            parameters: ExtractionParameters::default(),
+            calls: calls.to_vec(),
+            emails: emails.to_vec(),
+            filter_decisions: Vec::new(),
+        }
+    }
+
+    /// Record the email filter decisions
+    pub fn with_filter_decisions(mut self, filter_decisions: Vec<EmailFilterDecision>) -> Self {
+        self.filter_decisions = filter_decisions;
+        self
+    }
+
+    /// Record the extraction parameters
+    pub fn with_parameters(mut self, parameters: ExtractionParameters) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Save as `<customer>.json` in `dir`, replacing the previous snapshot
+    pub fn save(&self, dir: &Path) -> Result<PathBuf> {
+        fs::create_dir_all(dir)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create {}: {e}", dir.display())))?;
+
+        let path = dir.join(format!("{}.json", slug(&self.customer, "customer")));
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| CsCliError::Generic(format!("Failed to serialize snapshot: {e}")))?;
+        fs::write(&path, json)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))?;
+
+        info!(
+            path = %path.display(),
+            calls = self.calls.len(),
+            emails = self.emails.len(),
+            "Saved extraction snapshot"
+        );
+        Ok(path)
+    }
+
+    /// Read one snapshot file
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to read {}: {e}", path.display())))?;
+        let snapshot: Self = serde_json::from_str(&json).map_err(|e| {
+            CsCliError::Generic(format!("Invalid snapshot {}: {e}", path.display()))
+        })?;
+        if snapshot.version > SNAPSHOT_VERSION {
+            return Err(CsCliError::Generic(format!(
+                "{} was saved by a newer cs-cli (snapshot version {}); update to render it",
+                path.display(),
+                snapshot.version
+            )));
+        }
+        Ok(snapshot)
+    }
+
+    /// Read a snapshot file, or every `.json` snapshot in a folder by name
+    pub fn load_all(path: &Path) -> Result<Vec<Self>> {
+        if !path.is_dir() {
+            return Ok(vec![Self::load(path)?]);
+        }
+
+        let mut files: Vec<PathBuf> = fs::read_dir(path)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to read {}: {e}", path.display())))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|file| file.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        files.sort();
+
+        files.iter().map(|file| Self::load(file)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::CallDirection;
+    use crate::gong::output::markdown::CallMarkdownFormatter;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-snapshot-{}", std::process::id()));
+        let call = Call::new(
+            "c1".to_string(),
+            "acct".to_string(),
+            "Kickoff".to_string(),
+            CallDirection::Outbound,
+            600,
+            "2025-01-15T16:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        let snapshot = ExtractionSnapshot::new("Acme Corp", &[call], &[], false);
+
+        let path = snapshot.save(&dir).unwrap();
+        assert_eq!(path, dir.join("acme-corp.json"));
+
+        let loaded = ExtractionSnapshot::load_all(&dir).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].customer, "Acme Corp");
+        assert_eq!(loaded[0].calls[0].id, "c1");
+        assert_eq!(loaded[0].extracted_at, snapshot.extracted_at);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_render_is_deterministic() {
+        let call = Call::new(
+            "c1".to_string(),
+            "acct".to_string(),
+            "Kickoff".to_string(),
+            CallDirection::Outbound,
+            600,
+            "2025-01-15T16:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        let at: Zoned = "2025-02-01T09:30:00+00:00[UTC]".parse().unwrap();
+        let formatter = CallMarkdownFormatter::new(None);
+
+        let first = with_generated_at(&at, || formatter.format_call_to_markdown(&call));
+        let second = with_generated_at(&at, || formatter.format_call_to_markdown(&call));
+        assert_eq!(first, second);
+        assert!(first.contains("2025-02-01 09:30:00"));
+    }
+}
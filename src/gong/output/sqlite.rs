@@ -0,0 +1,701 @@
+//! SQLite database export
+//!
+//! Writes calls and emails into a normalized schema so they can be queried
+//! across customers with plain SQL. Rows are upserted by their Gong IDs, so
+//! one database accumulates every run instead of being rebuilt: a call seen
+//! again is updated in place, and a transcript or email body fetched on an
+//! earlier run is kept when a later run didn't fetch it.
+//!
+//! `filtering_decisions` records what the email filters did with every email
+//! they saw, including the ones they dropped, so the filtering can be audited.
+//!
+//! Timestamps are stored as UTC RFC 3339 text, which SQLite's date functions
+//! understand; lists such as topics are JSON arrays.
+
+use jiff::Zoned;
+use rusqlite::{params, Connection, Transaction};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::info;
+
+use super::report::transcript_turns;
+use super::snapshot::generated_at;
+use crate::gong::models::{Call, Email, EmailFilterDecision};
+use crate::{CsCliError, Result};
+
+/// File name used when `output.database_path` isn't set
+pub const DATABASE_FILE: &str = "gong.sqlite";
+
+/// `gong.sqlite` on the Desktop, next to the customer folders
+pub fn default_database_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join("Desktop")
+        .join(DATABASE_FILE)
+}
+
+/// Bumped when the schema changes incompatibly
+const SCHEMA_VERSION: i32 = 1;
+
+const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS accounts (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    first_extracted_at TEXT NOT NULL,
+    last_extracted_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS calls (
+    id TEXT PRIMARY KEY,
+    account_id TEXT NOT NULL REFERENCES accounts(id),
+    title TEXT NOT NULL,
+    generated_title TEXT,
+    customer_name TEXT,
+    direction TEXT NOT NULL,
+    duration_seconds INTEGER NOT NULL,
+    scheduled_start TEXT NOT NULL,
+    actual_start TEXT,
+    status TEXT,
+    call_type TEXT,
+    host_id TEXT,
+    host_name TEXT,
+    recording_url TEXT,
+    transcript_url TEXT,
+    call_brief TEXT,
+    summary TEXT,
+    sentiment REAL,
+    talk_ratio REAL,
+    longest_monologue_seconds INTEGER,
+    interactivity REAL,
+    questions_asked INTEGER,
+    topics TEXT NOT NULL DEFAULT '[]',
+    action_items TEXT NOT NULL DEFAULT '[]',
+    transcript TEXT,
+    extracted_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS calls_by_account ON calls (account_id, scheduled_start);
+
+CREATE TABLE IF NOT EXISTS participants (
+    call_id TEXT NOT NULL REFERENCES calls(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    participant_id TEXT,
+    name TEXT NOT NULL,
+    email TEXT,
+    phone TEXT,
+    title TEXT,
+    company TEXT,
+    is_internal INTEGER NOT NULL,
+    speaking_time_seconds REAL,
+    talk_ratio REAL,
+    PRIMARY KEY (call_id, position)
+);
+CREATE INDEX IF NOT EXISTS participants_by_email ON participants (email);
+
+CREATE TABLE IF NOT EXISTS transcript_segments (
+    call_id TEXT NOT NULL REFERENCES calls(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    speaker TEXT,
+    text TEXT NOT NULL,
+    PRIMARY KEY (call_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS emails (
+    id TEXT PRIMARY KEY,
+    account_id TEXT NOT NULL REFERENCES accounts(id),
+    thread_id TEXT,
+    subject TEXT NOT NULL,
+    direction TEXT NOT NULL,
+    sent_at TEXT NOT NULL,
+    received_at TEXT,
+    sender_email TEXT NOT NULL,
+    sender_name TEXT,
+    sender_title TEXT,
+    sender_company TEXT,
+    sender_is_internal INTEGER NOT NULL,
+    snippet TEXT,
+    body_text TEXT,
+    html_body TEXT,
+    has_attachments INTEGER NOT NULL,
+    attachment_count INTEGER NOT NULL,
+    open_count INTEGER NOT NULL,
+    click_count INTEGER NOT NULL,
+    reply_count INTEGER NOT NULL,
+    status TEXT,
+    extracted_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS emails_by_account ON emails (account_id, sent_at);
+CREATE INDEX IF NOT EXISTS emails_by_thread ON emails (thread_id);
+
+CREATE TABLE IF NOT EXISTS recipients (
+    email_id TEXT NOT NULL REFERENCES emails(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    email TEXT NOT NULL,
+    name TEXT,
+    recipient_type TEXT NOT NULL,
+    is_internal INTEGER NOT NULL,
+    title TEXT,
+    company TEXT,
+    PRIMARY KEY (email_id, position)
+);
+CREATE INDEX IF NOT EXISTS recipients_by_email ON recipients (email);
+
+CREATE TABLE IF NOT EXISTS filtering_decisions (
+    email_id TEXT PRIMARY KEY,
+    account_id TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    sender_email TEXT NOT NULL,
+    sent_at TEXT NOT NULL,
+    decision TEXT NOT NULL,
+    filter TEXT,
+    reason TEXT,
+    is_automated INTEGER NOT NULL,
+    is_template INTEGER NOT NULL,
+    similarity_score REAL,
+    decided_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS filtering_decisions_by_account
+    ON filtering_decisions (account_id, decision);
+"#;
+
+/// Rows written by one [`SqliteWriter::write`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SqliteCounts {
+    pub calls: usize,
+    pub participants: usize,
+    pub transcript_segments: usize,
+    pub emails: usize,
+    pub recipients: usize,
+}
+
+/// Upserts calls and emails into a SQLite database
+pub struct SqliteWriter {
+    path: PathBuf,
+}
+
+impl SqliteWriter {
+    /// Write to the database at `path`, creating it on first use
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Database file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Upsert a customer's calls and emails in one transaction
+    pub fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<SqliteCounts> {
+        let fail = |e| self.error(e);
+        let mut conn = self.connect()?;
+        let tx = conn.transaction().map_err(fail)?;
+        let extracted_at = timestamp(&generated_at());
+
+        upsert_accounts(&tx, customer, calls, emails, &extracted_at).map_err(fail)?;
+        let mut counts = SqliteCounts::default();
+        for call in calls {
+            upsert_call(&tx, call, customer, &extracted_at, &mut counts).map_err(fail)?;
+        }
+        for email in emails {
+            upsert_email(&tx, email, customer, &extracted_at, &mut counts).map_err(fail)?;
+        }
+        tx.commit().map_err(fail)?;
+
+        info!(
+            path = %self.path.display(),
+            calls = counts.calls,
+            emails = counts.emails,
+            "Upserted communications into SQLite"
+        );
+        Ok(counts)
+    }
+
+    /// Record why each of a customer's emails was kept or dropped, including
+    /// emails the filters removed before they reached [`SqliteWriter::write`]
+    pub fn write_filter_decisions(
+        &self,
+        customer: &str,
+        decisions: &[EmailFilterDecision],
+    ) -> Result<()> {
+        let fail = |e| self.error(e);
+        let mut conn = self.connect()?;
+        let tx = conn.transaction().map_err(fail)?;
+        let decided_at = timestamp(&generated_at());
+
+        for decision in decisions {
+            upsert_filter_decision(&tx, decision, customer, &decided_at).map_err(fail)?;
+        }
+        tx.commit().map_err(fail)?;
+
+        info!(
+            path = %self.path.display(),
+            decisions = decisions.len(),
+            dropped = decisions.iter().filter(|d| !d.kept).count(),
+            "Recorded email filtering decisions in SQLite"
+        );
+        Ok(())
+    }
+
+    /// Open the database, creating its folder on first use
+    fn connect(&self) -> Result<Connection> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| {
+                CsCliError::FileIo(format!("Failed to create {}: {e}", parent.display()))
+            })?;
+        }
+        open(&self.path).map_err(|e| self.error(e))
+    }
+
+    fn error(&self, e: rusqlite::Error) -> CsCliError {
+        CsCliError::FileIo(format!("SQLite error in {}: {e}", self.path.display()))
+    }
+}
+
+/// Open the database and bring the schema up to date
+fn open(path: &Path) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    // Batch extractions write from several tasks at once
+    conn.busy_timeout(Duration::from_secs(30))?;
+    conn.pragma_update(None, "foreign_keys", true)?;
+    conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get::<_, String>(0))?;
+    conn.execute_batch(SCHEMA)?;
+    conn.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+    Ok(conn)
+}
+
+/// Calls and emails without an account ID are filed under the customer name
+fn account_id<'a>(account_id: &'a str, customer: &'a str) -> &'a str {
+    if account_id.is_empty() {
+        customer
+    } else {
+        account_id
+    }
+}
+
+fn upsert_accounts(
+    tx: &Transaction,
+    customer: &str,
+    calls: &[Call],
+    emails: &[Email],
+    extracted_at: &str,
+) -> rusqlite::Result<()> {
+    let mut accounts = BTreeMap::new();
+    for call in calls {
+        let name = call.customer_name.as_deref().unwrap_or(customer);
+        accounts.insert(account_id(&call.account_id, customer), name);
+    }
+    for email in emails {
+        accounts
+            .entry(account_id(&email.account_id, customer))
+            .or_insert(customer);
+    }
+
+    let mut stmt = tx.prepare_cached(
+        "INSERT INTO accounts (id, name, first_extracted_at, last_extracted_at)
+         VALUES (?1, ?2, ?3, ?3)
+         ON CONFLICT (id) DO UPDATE SET
+             name = excluded.name,
+             last_extracted_at = excluded.last_extracted_at",
+    )?;
+    for (id, name) in accounts {
+        stmt.execute(params![id, name, extracted_at])?;
+    }
+    Ok(())
+}
+
+fn upsert_call(
+    tx: &Transaction,
+    call: &Call,
+    customer: &str,
+    extracted_at: &str,
+    counts: &mut SqliteCounts,
+) -> rusqlite::Result<()> {
+    tx.prepare_cached(
+        "INSERT INTO calls (
+             id, account_id, title, generated_title, customer_name, direction,
+             duration_seconds, scheduled_start, actual_start, status, call_type,
+             host_id, host_name, recording_url, transcript_url, call_brief, summary,
+             sentiment, talk_ratio, longest_monologue_seconds, interactivity,
+             questions_asked, topics, action_items, transcript, extracted_at
+         ) VALUES (
+             ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15,
+             ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26
+         )
+         ON CONFLICT (id) DO UPDATE SET
+             account_id = excluded.account_id,
+             title = excluded.title,
+             generated_title = COALESCE(excluded.generated_title, calls.generated_title),
+             customer_name = COALESCE(excluded.customer_name, calls.customer_name),
+             direction = excluded.direction,
+             duration_seconds = excluded.duration_seconds,
+             scheduled_start = excluded.scheduled_start,
+             actual_start = COALESCE(excluded.actual_start, calls.actual_start),
+             status = COALESCE(excluded.status, calls.status),
+             call_type = COALESCE(excluded.call_type, calls.call_type),
+             host_id = COALESCE(excluded.host_id, calls.host_id),
+             host_name = COALESCE(excluded.host_name, calls.host_name),
+             recording_url = COALESCE(excluded.recording_url, calls.recording_url),
+             transcript_url = COALESCE(excluded.transcript_url, calls.transcript_url),
+             call_brief = COALESCE(excluded.call_brief, calls.call_brief),
+             summary = COALESCE(excluded.summary, calls.summary),
+             sentiment = COALESCE(excluded.sentiment, calls.sentiment),
+             talk_ratio = COALESCE(excluded.talk_ratio, calls.talk_ratio),
+             longest_monologue_seconds =
+                 COALESCE(excluded.longest_monologue_seconds, calls.longest_monologue_seconds),
+             interactivity = COALESCE(excluded.interactivity, calls.interactivity),
+             questions_asked = COALESCE(excluded.questions_asked, calls.questions_asked),
+             topics = excluded.topics,
+             action_items = excluded.action_items,
+             transcript = COALESCE(excluded.transcript, calls.transcript),
+             extracted_at = excluded.extracted_at",
+    )?
+    .execute(params![
+        call.id,
+        account_id(&call.account_id, customer),
+        call.title,
+        call.generated_title,
+        call.customer_name,
+        lowercase_debug(&call.direction),
+        call.duration,
+        timestamp(&call.scheduled_start),
+        call.actual_start.as_ref().map(timestamp),
+        call.status,
+        call.call_type,
+        call.host_id,
+        call.host_name,
+        call.recording_url,
+        call.transcript_url,
+        call.call_brief,
+        call.summary,
+        call.sentiment,
+        call.talk_ratio,
+        call.longest_monologue,
+        call.interactivity,
+        call.questions_asked,
+        json_list(&call.topics),
+        json_list(&call.action_items),
+        call.transcript,
+        extracted_at,
+    ])?;
+    counts.calls += 1;
+
+    tx.prepare_cached("DELETE FROM participants WHERE call_id = ?1")?
+        .execute(params![call.id])?;
+    let mut stmt = tx.prepare_cached(
+        "INSERT INTO participants (
+             call_id, position, participant_id, name, email, phone, title, company,
+             is_internal, speaking_time_seconds, talk_ratio
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+    )?;
+    for (position, p) in call.participants.iter().enumerate() {
+        stmt.execute(params![
+            call.id,
+            position,
+            p.id,
+            p.name,
+            p.email,
+            p.phone,
+            p.title,
+            p.company,
+            p.is_internal,
+            p.speaking_time,
+            p.talk_ratio,
+        ])?;
+        counts.participants += 1;
+    }
+
+    // Without a transcript this run, keep the segments from an earlier one
+    let Some(transcript) = call.transcript.as_deref().filter(|t| !t.trim().is_empty()) else {
+        return Ok(());
+    };
+    tx.prepare_cached("DELETE FROM transcript_segments WHERE call_id = ?1")?
+        .execute(params![call.id])?;
+    let mut stmt = tx.prepare_cached(
+        "INSERT INTO transcript_segments (call_id, position, speaker, text)
+         VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for (position, (speaker, text)) in transcript_turns(transcript).iter().enumerate() {
+        stmt.execute(params![call.id, position, speaker, text])?;
+        counts.transcript_segments += 1;
+    }
+    Ok(())
+}
+
+fn upsert_email(
+    tx: &Transaction,
+    email: &Email,
+    customer: &str,
+    extracted_at: &str,
+    counts: &mut SqliteCounts,
+) -> rusqlite::Result<()> {
+    tx.prepare_cached(
+        "INSERT INTO emails (
+             id, account_id, thread_id, subject, direction, sent_at, received_at,
+             sender_email, sender_name, sender_title, sender_company, sender_is_internal,
+             snippet, body_text, html_body, has_attachments, attachment_count,
+             open_count, click_count, reply_count, status, extracted_at
+         ) VALUES (
+             ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15,
+             ?16, ?17, ?18, ?19, ?20, ?21, ?22
+         )
+         ON CONFLICT (id) DO UPDATE SET
+             account_id = excluded.account_id,
+             thread_id = COALESCE(excluded.thread_id, emails.thread_id),
+             subject = excluded.subject,
+             direction = excluded.direction,
+             sent_at = excluded.sent_at,
+             received_at = COALESCE(excluded.received_at, emails.received_at),
+             sender_email = excluded.sender_email,
+             sender_name = COALESCE(excluded.sender_name, emails.sender_name),
+             sender_title = COALESCE(excluded.sender_title, emails.sender_title),
+             sender_company = COALESCE(excluded.sender_company, emails.sender_company),
+             sender_is_internal = excluded.sender_is_internal,
+             snippet = COALESCE(excluded.snippet, emails.snippet),
+             body_text = COALESCE(excluded.body_text, emails.body_text),
+             html_body = COALESCE(excluded.html_body, emails.html_body),
+             has_attachments = excluded.has_attachments,
+             attachment_count = excluded.attachment_count,
+             open_count = MAX(excluded.open_count, emails.open_count),
+             click_count = MAX(excluded.click_count, emails.click_count),
+             reply_count = MAX(excluded.reply_count, emails.reply_count),
+             status = COALESCE(excluded.status, emails.status),
+             extracted_at = excluded.extracted_at",
+    )?
+    .execute(params![
+        email.id,
+        account_id(&email.account_id, customer),
+        email.thread_id,
+        email.subject,
+        lowercase_debug(&email.direction),
+        timestamp(&email.sent_at),
+        email.received_at.as_ref().map(timestamp),
+        email.sender.email,
+        email.sender.name,
+        email.sender.title,
+        email.sender.company,
+        email.sender.is_internal,
+        email.snippet,
+        email.body_text,
+        email.html_body,
+        email.has_attachments,
+        email.attachment_count,
+        email.open_count,
+        email.click_count,
+        email.reply_count,
+        email.status,
+        extracted_at,
+    ])?;
+    counts.emails += 1;
+
+    tx.prepare_cached("DELETE FROM recipients WHERE email_id = ?1")?
+        .execute(params![email.id])?;
+    let mut stmt = tx.prepare_cached(
+        "INSERT INTO recipients (
+             email_id, position, email, name, recipient_type, is_internal, title, company
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )?;
+    for (position, r) in email.recipients.iter().enumerate() {
+        stmt.execute(params![
+            email.id,
+            position,
+            r.email,
+            r.name,
+            r.recipient_type,
+            r.is_internal,
+            r.title,
+            r.company,
+        ])?;
+        counts.recipients += 1;
+    }
+    Ok(())
+}
+
+fn upsert_filter_decision(
+    tx: &Transaction,
+    decision: &EmailFilterDecision,
+    customer: &str,
+    decided_at: &str,
+) -> rusqlite::Result<()> {
+    tx.prepare_cached(
+        "INSERT INTO filtering_decisions (
+             email_id, account_id, subject, sender_email, sent_at, decision, filter,
+             reason, is_automated, is_template, similarity_score, decided_at
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+         ON CONFLICT (email_id) DO UPDATE SET
+             account_id = excluded.account_id,
+             subject = excluded.subject,
+             sender_email = excluded.sender_email,
+             sent_at = excluded.sent_at,
+             decision = excluded.decision,
+             filter = excluded.filter,
+             reason = excluded.reason,
+             is_automated = excluded.is_automated,
+             is_template = excluded.is_template,
+             similarity_score = excluded.similarity_score,
+             decided_at = excluded.decided_at",
+    )?
+    .execute(params![
+        decision.email_id,
+        account_id(&decision.account_id, customer),
+        decision.subject,
+        decision.sender_email,
+        timestamp(&decision.sent_at),
+        if decision.kept { "kept" } else { "dropped" },
+        decision.filter,
+        decision.reason,
+        decision.is_automated,
+        decision.is_template,
+        decision.similarity_score,
+        decided_at,
+    ])?;
+    Ok(())
+}
+
+/// UTC RFC 3339, e.g. `2025-01-15T16:00:00Z`
+fn timestamp(date: &Zoned) -> String {
+    date.timestamp().to_string()
+}
+
+fn lowercase_debug(value: &impl std::fmt::Debug) -> String {
+    format!("{value:?}").to_lowercase()
+}
+
+fn json_list(items: &[String]) -> String {
+    serde_json::to_string(items).unwrap_or_else(|_| "[]".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{
+        CallDirection, CallParticipant, EmailDirection, AUTOMATED_EMAIL_FILTER,
+    };
+
+    #[test]
+    fn test_upserts_accumulate_across_runs() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-sqlite-{}", std::process::id()));
+        let writer = SqliteWriter::new(dir.join(DATABASE_FILE));
+
+        let mut call = Call::new(
+            "c1".to_string(),
+            "acct-1".to_string(),
+            "Kickoff".to_string(),
+            CallDirection::Outbound,
+            1800,
+            "2025-01-15T11:00:00-05:00[America/New_York]"
+                .parse()
+                .unwrap(),
+        );
+        call.participants.push(CallParticipant {
+            id: None,
+            name: "Dana Customer".to_string(),
+            email: Some("dana@acme.com".to_string()),
+            phone: None,
+            title: None,
+            company: Some("Acme".to_string()),
+            is_internal: false,
+            speaking_time: Some(320.5),
+            talk_ratio: None,
+        });
+        call.transcript = Some("**Dana Customer:** Hello\n**Sam:** Hi Dana".to_string());
+        let email = Email::new(
+            "e1".to_string(),
+            String::new(),
+            "Follow-up".to_string(),
+            EmailDirection::Outbound,
+            "2025-01-16T09:00:00+00:00[UTC]".parse().unwrap(),
+            "sam@postman.com".to_string(),
+        );
+
+        let counts = writer
+            .write("Acme", &[call.clone()], &[email.clone()])
+            .unwrap();
+        assert_eq!(counts.transcript_segments, 2);
+
+        // A later run without transcripts or bodies keeps what was stored
+        call.transcript = None;
+        call.title = "Kickoff (renamed)".to_string();
+        writer.write("Acme", &[call], &[email]).unwrap();
+
+        let conn = Connection::open(writer.path()).unwrap();
+        let count = |table: &str| -> i64 {
+            conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+        };
+        assert_eq!(count("accounts"), 2);
+        assert_eq!(count("calls"), 1);
+        assert_eq!(count("participants"), 1);
+        assert_eq!(count("transcript_segments"), 2);
+        assert_eq!(count("emails"), 1);
+
+        let (title, start, transcript): (String, String, Option<String>) = conn
+            .query_row(
+                "SELECT title, scheduled_start, transcript FROM calls WHERE id = 'c1'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(title, "Kickoff (renamed)");
+        assert_eq!(start, "2025-01-15T16:00:00Z");
+        assert!(transcript.is_some());
+
+        let account: String = conn
+            .query_row("SELECT account_id FROM emails", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(account, "Acme");
+
+        drop(conn);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_filtering_decisions_include_dropped_emails() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-sqlite-filter-{}", std::process::id()));
+        let writer = SqliteWriter::new(dir.join("gong.sqlite"));
+        let email = |id: &str, subject: &str| {
+            Email::new(
+                id.to_string(),
+                "acct".to_string(),
+                subject.to_string(),
+                EmailDirection::Inbound,
+                "2025-01-16T09:00:00+00:00[UTC]".parse().unwrap(),
+                "dana@acme.com".to_string(),
+            )
+        };
+        let decisions = [
+            EmailFilterDecision::kept(&email("e1", "Renewal")),
+            EmailFilterDecision::dropped(
+                &email("e2", "Automatic reply"),
+                AUTOMATED_EMAIL_FILTER,
+                "automated, out-of-office or BDR template content",
+            ),
+        ];
+
+        writer.write_filter_decisions("Acme", &decisions).unwrap();
+
+        let conn = Connection::open(writer.path()).unwrap();
+        let (decision, filter): (String, Option<String>) = conn
+            .query_row(
+                "SELECT decision, filter FROM filtering_decisions WHERE email_id = 'e2'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(decision, "dropped");
+        assert_eq!(filter.as_deref(), Some(AUTOMATED_EMAIL_FILTER));
+        let kept: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM filtering_decisions WHERE decision = 'kept'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(kept, 1);
+
+        drop(conn);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
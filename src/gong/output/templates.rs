@@ -0,0 +1,334 @@
+//! Templates for markdown output
+//!
+//! Calls, emails, email batches and the team summary are rendered with
+//! [minijinja](https://docs.rs/minijinja) templates. The built-in layouts are
+//! compiled into the binary; a file with the same name in
+//! `~/.config/cs-cli/templates/` replaces one, and other files in that folder
+//! can be pulled in with `{% include %}`. `cs-cli templates dump` writes the
+//! built-ins out as a starting point.
+//!
+//! Templates see every `Call`/`Email` field plus these filters:
+//! - `date(format)`: strftime formatting of a date field
+//! - `duration`: seconds as `1h 05m`
+//! - `truncate(length, end)`: shorten text to `length` characters
+//! - `clean_transcript`, `clean_email_body`: the cleanup the built-in layouts use
+//! - `contact`: `Name (email)` for a participant or recipient
+
+use jiff::tz::TimeZone;
+use jiff::{Timestamp, Zoned};
+use minijinja::{AutoEscape, Environment, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tracing::{debug, error, warn};
+
+use super::markdown::{clean_email_body, clean_transcript};
+use crate::common::config::user_config_path;
+use crate::{CsCliError, Result};
+
+/// One call (`call`, `generated_at`)
+pub const CALL_TEMPLATE: &str = "call.md.j2";
+/// One email (`email`), also included by the batch template
+pub const EMAIL_TEMPLATE: &str = "email.md.j2";
+/// A batch file of emails (`customer`, `batch`, `date_range`, `emails`, `generated_at`)
+pub const EMAIL_BATCH_TEMPLATE: &str = "email_batch.md.j2";
+/// Team summary (`calls`, `customers` with `name` and `calls`, `generated_at`)
+pub const SUMMARY_TEMPLATE: &str = "summary.md.j2";
+
+/// Built-in templates by name
+pub const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    (CALL_TEMPLATE, include_str!("templates/call.md.j2")),
+    (EMAIL_TEMPLATE, include_str!("templates/email.md.j2")),
+    (
+        EMAIL_BATCH_TEMPLATE,
+        include_str!("templates/email_batch.md.j2"),
+    ),
+    (SUMMARY_TEMPLATE, include_str!("templates/summary.md.j2")),
+];
+
+/// Renders markdown from the built-in templates and any user overrides
+pub struct TemplateRenderer {
+    env: Environment<'static>,
+    /// Built-ins only, used when an override fails to render
+    builtin: Environment<'static>,
+    overrides: Vec<PathBuf>,
+}
+
+impl TemplateRenderer {
+    /// Built-in templates, replaced by same-named files in `dir` if given
+    ///
+    /// Overrides that don't compile are skipped with a warning so a typo in a
+    /// template never stops an extraction.
+    pub fn load(dir: Option<&Path>) -> Self {
+        let mut env = environment();
+        let mut overrides = Vec::new();
+
+        if let Some(dir) = dir.filter(|dir| dir.is_dir()) {
+            env.set_loader(minijinja::path_loader(dir));
+            for (name, _) in BUILTIN_TEMPLATES {
+                let path = dir.join(name);
+                let Ok(source) = fs::read_to_string(&path) else {
+                    continue;
+                };
+                match env.add_template_owned(name.to_string(), source) {
+                    Ok(()) => {
+                        debug!(template = %path.display(), "Using custom template");
+                        overrides.push(path);
+                    }
+                    Err(e) => warn!(
+                        template = %path.display(),
+                        error = %e,
+                        "Ignoring invalid template; using the built-in one"
+                    ),
+                }
+            }
+        }
+
+        Self {
+            env,
+            builtin: environment(),
+            overrides,
+        }
+    }
+
+    /// Templates from the user config directory, loaded once per process
+    pub fn shared() -> &'static Self {
+        static SHARED: OnceLock<TemplateRenderer> = OnceLock::new();
+        SHARED.get_or_init(|| Self::load(user_templates_dir().as_deref()))
+    }
+
+    /// Override files in use
+    pub fn overrides(&self) -> &[PathBuf] {
+        &self.overrides
+    }
+
+    /// Render a template, falling back to the built-in one if an override fails
+    pub fn render(&self, name: &str, context: Value) -> String {
+        match self.env.get_template(name).and_then(|t| t.render(&context)) {
+            Ok(rendered) => rendered,
+            Err(e) => {
+                error!(template = name, error = %e, "Template failed to render; using the built-in one");
+                self.builtin
+                    .get_template(name)
+                    .and_then(|t| t.render(&context))
+                    .unwrap_or_else(|e| format!("Failed to render {name}: {e}\n"))
+            }
+        }
+    }
+}
+
+/// `templates/` next to the user config file
+pub fn user_templates_dir() -> Option<PathBuf> {
+    Some(user_config_path()?.parent()?.join("templates"))
+}
+
+/// Write the built-in templates to `dir`, keeping existing files unless
+/// `force` is set; returns the files written
+pub fn dump_templates(dir: &Path, force: bool) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to create {}: {e}", dir.display())))?;
+
+    let mut written = Vec::new();
+    for (name, source) in BUILTIN_TEMPLATES {
+        let path = dir.join(name);
+        if path.exists() && !force {
+            continue;
+        }
+        fs::write(&path, source)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.set_auto_escape_callback(|_| AutoEscape::None);
+
+    env.add_filter("date", date);
+    env.add_filter("duration", duration);
+    env.add_filter("truncate", truncate);
+    env.add_filter("clean_transcript", |text: String| clean_transcript(&text));
+    env.add_filter("clean_email_body", |text: String| clean_email_body(&text));
+    env.add_filter("contact", contact);
+
+    for (name, source) in BUILTIN_TEMPLATES {
+        env.add_template(name, source)
+            .expect("built-in templates are valid");
+    }
+    env
+}
+
+/// Format a serialized date; values that aren't dates are passed through
+fn date(value: Value, format: Option<String>) -> String {
+    let Some(text) = value.as_str() else {
+        return if value.is_none() || value.is_undefined() {
+            String::new()
+        } else {
+            value.to_string()
+        };
+    };
+    let format = format.as_deref().unwrap_or("%Y-%m-%d %H:%M");
+
+    text.parse::<Zoned>()
+        .or_else(|_| text.parse::<Timestamp>().map(|t| t.to_zoned(TimeZone::UTC)))
+        .map(|date| date.strftime(format).to_string())
+        .unwrap_or_else(|_| text.to_string())
+}
+
+/// Seconds as `45s`, `30m` or `1h 05m`
+fn duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (hours, minutes) = (seconds / 3600, (seconds % 3600) / 60);
+    match (hours, minutes) {
+        (0, 0) => format!("{seconds}s"),
+        (0, minutes) => format!("{minutes}m"),
+        (hours, minutes) => format!("{hours}h {minutes:02}m"),
+    }
+}
+
+/// First `length` characters (default 255) followed by `end` (default `...`)
+fn truncate(text: String, length: Option<usize>, end: Option<String>) -> String {
+    let length = length.unwrap_or(255);
+    if text.chars().count() <= length {
+        return text;
+    }
+    let mut truncated: String = text.chars().take(length).collect();
+    truncated.push_str(end.as_deref().unwrap_or("..."));
+    truncated
+}
+
+/// `Name (email)`, falling back to the mailbox name when there is no name
+fn contact(value: Value) -> String {
+    let field = |key: &str| {
+        value
+            .get_attr(key)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .filter(|v| !v.is_empty())
+    };
+    let email = field("email");
+    let name = field("name")
+        .or_else(|| email.as_deref()?.split('@').next().map(str::to_string))
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    match email {
+        Some(email) => format!("{name} ({email})"),
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{
+        Call, CallDirection, CallParticipant, Email, EmailDirection, EmailRecipient,
+    };
+    use minijinja::context;
+
+    fn sample_call() -> Call {
+        let mut call = Call::new(
+            "7782342274025937895".to_string(),
+            "acct".to_string(),
+            "Acme + Postman - QBR".to_string(),
+            CallDirection::Outbound,
+            3900,
+            "2025-01-15T16:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        call.customer_name = Some("Acme".to_string());
+        call.participants.push(CallParticipant {
+            id: None,
+            name: "Dana Customer".to_string(),
+            email: Some("dana@acme.com".to_string()),
+            phone: None,
+            title: None,
+            company: Some("Acme".to_string()),
+            is_internal: false,
+            speaking_time: None,
+            talk_ratio: None,
+        });
+        call.transcript = Some("**Dana Customer:** Hello".to_string());
+        call
+    }
+
+    #[test]
+    fn test_builtin_call_layout() {
+        let renderer = TemplateRenderer::load(None);
+        let call = sample_call();
+        let md = renderer.render(
+            CALL_TEMPLATE,
+            context! { call => &call, generated_at => &call.scheduled_start },
+        );
+
+        assert!(md.starts_with(
+            "# Acme + Postman - QBR\n\n**Customer:** Acme\n**Date:** 2025-01-15T16:00:00\n**Call ID:** `7782342274025937895`\n\n## Attendees\n\n"
+        ));
+        assert!(md.contains("- **Dana Customer** (Acme) - dana@acme.com\n\n\n## Transcript\n\n**Dana Customer:** Hello\n"));
+        assert!(md.ends_with("---\n*Generated on 2025-01-15 16:00:00*\n"));
+    }
+
+    #[test]
+    fn test_user_override_and_helpers() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-templates-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(CALL_TEMPLATE),
+            "{{ call.title | truncate(4, \"~\") }} {{ call.duration | duration }} {{ call.scheduled_start | date(\"%d/%m\") }} {{ call.participants[0] | contact }}",
+        )
+        .unwrap();
+        fs::write(dir.join(EMAIL_TEMPLATE), "{% if %}").unwrap();
+
+        let renderer = TemplateRenderer::load(Some(&dir));
+        assert_eq!(renderer.overrides(), &[dir.join(CALL_TEMPLATE)]);
+        assert_eq!(
+            renderer.render(CALL_TEMPLATE, context! { call => sample_call() }),
+            "Acme~ 1h 05m 15/01 Dana Customer (dana@acme.com)"
+        );
+
+        // Dumping keeps the user's edits unless forced
+        let written = dump_templates(&dir, false).unwrap();
+        assert_eq!(written.len(), BUILTIN_TEMPLATES.len() - 2);
+        assert!(fs::read_to_string(dir.join(CALL_TEMPLATE))
+            .unwrap()
+            .starts_with("{{ call.title"));
+        assert_eq!(
+            dump_templates(&dir, true).unwrap().len(),
+            BUILTIN_TEMPLATES.len()
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_email_recipients_hide_bcc() {
+        let mut email = Email::new(
+            "e1".to_string(),
+            "acct".to_string(),
+            "Renewal".to_string(),
+            EmailDirection::Outbound,
+            "2025-01-16T09:00:00+00:00[UTC]".parse().unwrap(),
+            "sam@postman.com".to_string(),
+        );
+        for (address, kind) in [
+            ("dana@acme.com", "to"),
+            ("lee@acme.com", "cc"),
+            ("boss@postman.com", "bcc"),
+        ] {
+            email.recipients.push(EmailRecipient {
+                email: address.to_string(),
+                name: None,
+                recipient_type: kind.to_string(),
+                is_internal: false,
+                title: None,
+                company: None,
+            });
+        }
+
+        let md = TemplateRenderer::load(None).render(EMAIL_TEMPLATE, context! { email });
+        assert!(md.contains("**To:** dana (dana@acme.com)\n**Cc:** lee (lee@acme.com)\n"));
+        assert!(!md.contains("boss@postman.com"));
+    }
+}
@@ -0,0 +1,521 @@
+//! Knowledge-base output for Obsidian and Logseq vaults
+//!
+//! Every call and email becomes a note with YAML front matter (`call_id`,
+//! `account_id`, `date`, `duration` in minutes, `participants`, `customer`,
+//! `direction`, `tags`) so vault queries can filter on it. People and
+//! companies are `[[wikilinks]]`, and each person and customer gets an index
+//! note listing their calls and emails.
+//!
+//! File names only depend on the date, subject and Gong ID, so re-running an
+//! extraction rewrites the same notes. Anything written below
+//! [`NOTES_MARKER`] in a note is kept, and index notes merge with the links
+//! they already had.
+
+use jiff::Zoned;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use super::markdown::CallMarkdownFormatter;
+use super::slug::slug;
+use crate::gong::models::{Call, Email};
+use crate::{CsCliError, Result};
+
+/// Everything after this line is the user's and survives re-runs
+pub const NOTES_MARKER: &str = "%% Notes below this line are kept when cs-cli updates this note %%";
+
+/// Writes call, email, person and customer notes into a vault folder
+pub struct VaultWriter {
+    root: PathBuf,
+    formatter: CallMarkdownFormatter,
+}
+
+/// Calls and emails an index note links to, keyed by date for sorting
+#[derive(Default)]
+struct IndexEntries {
+    /// Front matter fields for a newly created index note
+    properties: Vec<(&'static str, String)>,
+    /// `(date, link line)`
+    links: BTreeSet<(String, String)>,
+    /// People linked from a customer note
+    people: BTreeSet<String>,
+}
+
+impl VaultWriter {
+    /// Write notes under `root` (`calls/`, `emails/`, `people/`, `customers/`)
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        Self {
+            formatter: CallMarkdownFormatter::new(Some(root.clone())),
+            root,
+        }
+    }
+
+    /// Write every note, returning the files created or updated
+    pub fn write(&self, customer: &str, calls: &[Call], emails: &[Email]) -> Result<Vec<PathBuf>> {
+        let customer_note = note_name(customer);
+        let mut people: BTreeMap<String, IndexEntries> = BTreeMap::new();
+        let mut customer_index = IndexEntries {
+            properties: vec![("type", "customer".to_string())],
+            ..IndexEntries::default()
+        };
+        let mut saved_files = Vec::new();
+
+        for call in calls {
+            let name = call_note_name(call);
+            let path = self.root.join("calls").join(format!("{name}.md"));
+            write_note(&path, &self.call_note(&customer_note, call))?;
+            saved_files.push(path);
+
+            let date = call.scheduled_start.strftime("%Y-%m-%d").to_string();
+            let line = format!("- {date} · Call · [[{name}]]");
+            customer_index.links.insert((date.clone(), line.clone()));
+            for person in &call.participants {
+                let person_note = note_name(&person.name);
+                let entry = people.entry(person_note.clone()).or_insert_with(|| {
+                    person_index(
+                        person.email.as_deref(),
+                        person.company.as_deref(),
+                        person.is_internal,
+                    )
+                });
+                entry.links.insert((date.clone(), line.clone()));
+                if !person.is_internal {
+                    customer_index.people.insert(person_note);
+                }
+            }
+        }
+
+        for email in emails {
+            let name = email_note_name(email);
+            let path = self.root.join("emails").join(format!("{name}.md"));
+            write_note(&path, &self.email_note(&customer_note, email))?;
+            saved_files.push(path);
+
+            let date = email.sent_at.strftime("%Y-%m-%d").to_string();
+            let line = format!("- {date} · Email · [[{name}]]");
+            customer_index.links.insert((date.clone(), line.clone()));
+            for contact in std::iter::once(&email.sender).chain(&email.recipients) {
+                let Some(person_note) = contact.name.as_deref().map(note_name) else {
+                    continue;
+                };
+                let entry = people.entry(person_note.clone()).or_insert_with(|| {
+                    person_index(
+                        Some(contact.email.as_str()),
+                        contact.company.as_deref(),
+                        contact.is_internal,
+                    )
+                });
+                entry.links.insert((date.clone(), line.clone()));
+                if !contact.is_internal {
+                    customer_index.people.insert(person_note);
+                }
+            }
+        }
+
+        for (person, entries) in &people {
+            let path = self.root.join("people").join(format!("{person}.md"));
+            write_index_note(&path, person, entries, &["gong/person"])?;
+            saved_files.push(path);
+        }
+
+        let path = self
+            .root
+            .join("customers")
+            .join(format!("{customer_note}.md"));
+        write_index_note(&path, &customer_note, &customer_index, &["gong/customer"])?;
+        saved_files.push(path);
+
+        info!(
+            notes = saved_files.len(),
+            vault = %self.root.display(),
+            "Saved vault notes"
+        );
+        Ok(saved_files)
+    }
+
+    /// A call note with front matter, attendees, brief and transcript
+    fn call_note(&self, customer_note: &str, call: &Call) -> String {
+        let participants: Vec<String> = call
+            .participants
+            .iter()
+            .map(|p| format!("[[{}]]", note_name(&p.name)))
+            .collect();
+        let mut md = front_matter(&[
+            ("type", yaml_string("call")),
+            ("call_id", yaml_string(&call.id)),
+            ("account_id", yaml_string(&call.account_id)),
+            ("customer", yaml_string(&format!("[[{customer_note}]]"))),
+            ("date", yaml_date(&call.scheduled_start)),
+            ("duration", ((call.duration.max(0) + 30) / 60).to_string()),
+            ("direction", format!("{:?}", call.direction).to_lowercase()),
+            ("participants", yaml_list(&participants)),
+            ("tags", yaml_list(&tags("gong/call", customer_note))),
+        ]);
+
+        md.push_str(&format!("# {}\n\n", call.title));
+        md.push_str(&format!("Customer: [[{customer_note}]]\n"));
+        if let Some(url) = call.recording_url.as_deref().filter(|u| !u.is_empty()) {
+            md.push_str(&format!("Recording: {url}\n"));
+        }
+
+        md.push_str("\n## Attendees\n\n");
+        if call.participants.is_empty() {
+            md.push_str("No attendee information available.\n");
+        }
+        for person in &call.participants {
+            md.push_str(&format!("- [[{}]]", note_name(&person.name)));
+            if let Some(title) = person.title.as_deref().filter(|t| !t.is_empty()) {
+                md.push_str(&format!(" - {title}"));
+            }
+            if let Some(company) = person.company.as_deref().filter(|c| !c.is_empty()) {
+                md.push_str(&format!(" ([[{}]])", note_name(company)));
+            }
+            md.push('\n');
+        }
+
+        if let Some(brief) = call.call_brief.as_deref().filter(|b| !b.trim().is_empty()) {
+            md.push_str(&format!("\n## Brief\n\n{}\n", brief.trim()));
+        }
+
+        let transcript = self
+            .formatter
+            .clean_transcript(call.transcript.as_deref().unwrap_or_default());
+        md.push_str(&format!("\n## Transcript\n\n{transcript}\n"));
+        md
+    }
+
+    /// An email note with front matter and the body
+    fn email_note(&self, customer_note: &str, email: &Email) -> String {
+        let link = |name: Option<&str>, address: &str| match name {
+            Some(name) => format!("[[{}]]", note_name(name)),
+            None => address.to_string(),
+        };
+        let from = link(email.sender.name.as_deref(), &email.sender.email);
+        let to: Vec<String> = email
+            .recipients
+            .iter()
+            .filter(|r| r.recipient_type != "bcc")
+            .map(|r| link(r.name.as_deref(), &r.email))
+            .collect();
+        let participants: Vec<String> = std::iter::once(from.clone())
+            .chain(to.iter().cloned())
+            .collect();
+
+        let mut md = front_matter(&[
+            ("type", yaml_string("email")),
+            ("email_id", yaml_string(&email.id)),
+            ("account_id", yaml_string(&email.account_id)),
+            ("customer", yaml_string(&format!("[[{customer_note}]]"))),
+            ("date", yaml_date(&email.sent_at)),
+            ("direction", format!("{:?}", email.direction).to_lowercase()),
+            ("participants", yaml_list(&participants)),
+            ("tags", yaml_list(&tags("gong/email", customer_note))),
+        ]);
+
+        let subject = if email.subject.is_empty() {
+            "No Subject"
+        } else {
+            &email.subject
+        };
+        md.push_str(&format!("# {subject}\n\n"));
+        md.push_str(&format!("From: {from}\n"));
+        if !to.is_empty() {
+            md.push_str(&format!("To: {}\n", to.join(", ")));
+        }
+        md.push_str(&format!("Customer: [[{customer_note}]]\n\n"));
+
+        let body = email
+            .body_text
+            .as_deref()
+            .or(email.snippet.as_deref())
+            .filter(|b| !b.trim().is_empty())
+            .unwrap_or("No content available.");
+        md.push_str(body.trim());
+        md.push('\n');
+        md
+    }
+}
+
+/// Index entries for a person seen for the first time in this run
+fn person_index(email: Option<&str>, company: Option<&str>, internal: bool) -> IndexEntries {
+    let mut properties = vec![("type", "person".to_string())];
+    if let Some(email) = email.filter(|e| !e.is_empty()) {
+        properties.push(("email", email.to_string()));
+    }
+    if let Some(company) = company.filter(|c| !c.is_empty()) {
+        properties.push(("company", format!("[[{}]]", note_name(company))));
+    }
+    properties.push(("internal", internal.to_string()));
+
+    IndexEntries {
+        properties,
+        ..IndexEntries::default()
+    }
+}
+
+/// Write or update an index note, keeping links from earlier runs
+fn write_index_note(path: &Path, title: &str, entries: &IndexEntries, tags: &[&str]) -> Result<()> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+    let (managed, _) = split_user_notes(&existing);
+
+    let mut links = entries.links.clone();
+    let mut people = entries.people.clone();
+    let mut section = "";
+    for line in managed.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            section = heading;
+        } else if let Some(link) = line.strip_prefix("- [[") {
+            people.insert(link.trim_end_matches("]]").to_string());
+        } else if let Some(entry) = line.strip_prefix("- ") {
+            if section == "Communications" {
+                let date = entry.split(' ').next().unwrap_or_default().to_string();
+                links.insert((date, line.to_string()));
+            }
+        }
+    }
+
+    let mut properties: Vec<(&str, String)> = entries
+        .properties
+        .iter()
+        .map(|(key, value)| {
+            let value = match *key {
+                "internal" => value.clone(),
+                _ => yaml_string(value),
+            };
+            (*key, value)
+        })
+        .collect();
+    let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+    properties.push(("tags", yaml_list(&tags)));
+
+    let mut md = front_matter(&properties);
+    md.push_str(&format!("# {title}\n"));
+    if !people.is_empty() {
+        md.push_str("\n## People\n\n");
+        for person in &people {
+            md.push_str(&format!("- [[{person}]]\n"));
+        }
+    }
+    md.push_str("\n## Communications\n\n");
+    for (_, line) in links.iter().rev() {
+        md.push_str(line);
+        md.push('\n');
+    }
+
+    write_note(path, &md)
+}
+
+/// Write a note, keeping whatever the user added below [`NOTES_MARKER`]
+fn write_note(path: &Path, content: &str) -> Result<()> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+    let (_, user_notes) = split_user_notes(&existing);
+
+    let mut note = content.to_string();
+    note.push_str(&format!("\n{NOTES_MARKER}\n"));
+    if let Some(user_notes) = user_notes {
+        note.push_str(user_notes);
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create {}: {e}", dir.display())))?;
+    }
+    fs::write(path, note)
+        .map_err(|e| CsCliError::FileIo(format!("Failed to write {}: {e}", path.display())))
+}
+
+/// Split a note into the generated part and the user's notes after the marker
+fn split_user_notes(note: &str) -> (&str, Option<&str>) {
+    match note.split_once(NOTES_MARKER) {
+        Some((managed, user)) => (managed, Some(user.strip_prefix('\n').unwrap_or(user))),
+        None => (note, None),
+    }
+}
+
+/// Stable call note name: date, title and short Gong ID
+fn call_note_name(call: &Call) -> String {
+    let id: String = call.id.chars().take(8).collect();
+    format!(
+        "{} {} ({id})",
+        call.scheduled_start.strftime("%Y-%m-%d"),
+        note_name(&call.title)
+    )
+}
+
+/// Stable email note name: date, subject and short Gong ID
+fn email_note_name(email: &Email) -> String {
+    let id: String = email.id.chars().take(8).collect();
+    let subject = if email.subject.is_empty() {
+        "No Subject"
+    } else {
+        &email.subject
+    };
+    format!(
+        "{} {} ({id})",
+        email.sent_at.strftime("%Y-%m-%d"),
+        note_name(subject)
+    )
+}
+
+/// Note name safe for file names and wikilinks
+pub fn note_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '[' | ']' | '|' | '#' | '^' | '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' => ' ',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .collect();
+    let cleaned = cleaned.split_whitespace().collect::<Vec<_>>().join(" ");
+    let cleaned: String = cleaned.trim_matches('.').chars().take(80).collect();
+    if cleaned.trim().is_empty() {
+        "Unnamed".to_string()
+    } else {
+        cleaned.trim().to_string()
+    }
+}
+
+/// `gong/<type>` plus a `customer/<slug>` tag
+fn tags(kind: &str, customer_note: &str) -> Vec<String> {
+    vec![
+        kind.to_string(),
+        format!("customer/{}", slug(customer_note, "customer")),
+    ]
+}
+
+fn front_matter(properties: &[(&str, String)]) -> String {
+    let mut yaml = String::from("---\n");
+    for (key, value) in properties {
+        if value.starts_with('\n') {
+            yaml.push_str(&format!("{key}:{value}\n"));
+        } else {
+            yaml.push_str(&format!("{key}: {value}\n"));
+        }
+    }
+    yaml.push_str("---\n\n");
+    yaml
+}
+
+/// Double-quoted YAML scalar
+fn yaml_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c.is_control() => {}
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Block list of quoted strings (`[]` when empty)
+fn yaml_list(items: &[String]) -> String {
+    if items.is_empty() {
+        return "[]".to_string();
+    }
+    items
+        .iter()
+        .map(|item| format!("\n  - {}", yaml_string(item)))
+        .collect()
+}
+
+fn yaml_date(date: &Zoned) -> String {
+    date.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gong::models::{CallDirection, CallParticipant, EmailDirection};
+
+    fn sample_call() -> Call {
+        let mut call = Call::new(
+            "7782342274025937895".to_string(),
+            "0013600000ABC".to_string(),
+            "Acme / Quarterly review".to_string(),
+            CallDirection::Outbound,
+            1800,
+            "2025-01-15T16:00:00+00:00[UTC]".parse().unwrap(),
+        );
+        call.participants.push(CallParticipant {
+            id: None,
+            name: "Dana Customer".to_string(),
+            email: Some("dana@acme.com".to_string()),
+            phone: None,
+            title: Some("VP Engineering".to_string()),
+            company: Some("Acme Corp".to_string()),
+            is_internal: false,
+            speaking_time: None,
+            talk_ratio: None,
+        });
+        call.transcript = Some("**Dana Customer:** Hello".to_string());
+        call
+    }
+
+    #[test]
+    fn test_call_note_front_matter_and_links() {
+        let vault = VaultWriter::new(std::env::temp_dir());
+        let note = vault.call_note("Acme Corp", &sample_call());
+
+        assert!(note.starts_with("---\ntype: \"call\"\ncall_id: \"7782342274025937895\"\n"));
+        assert!(note.contains("customer: \"[[Acme Corp]]\"\n"));
+        assert!(
+            note.contains("date: 2025-01-15T16:00:00+00:00\nduration: 30\ndirection: outbound\n")
+        );
+        assert!(note.contains("participants:\n  - \"[[Dana Customer]]\"\n"));
+        assert!(note.contains("tags:\n  - \"gong/call\"\n  - \"customer/acme-corp\"\n---"));
+        assert!(note.contains("- [[Dana Customer]] - VP Engineering ([[Acme Corp]])"));
+        assert_eq!(
+            call_note_name(&sample_call()),
+            "2025-01-15 Acme Quarterly review (77823422)"
+        );
+    }
+
+    #[test]
+    fn test_rerun_updates_notes_in_place() {
+        let root = std::env::temp_dir().join(format!("cs-cli-vault-{}", std::process::id()));
+        let vault = VaultWriter::new(&root);
+
+        let files = vault.write("Acme Corp", &[sample_call()], &[]).unwrap();
+        let call_path = root.join("calls/2025-01-15 Acme Quarterly review (77823422).md");
+        assert!(files.contains(&call_path));
+        assert!(files.contains(&root.join("people/Dana Customer.md")));
+
+        // User notes survive a re-run, and index notes keep earlier links
+        let mut note = fs::read_to_string(&call_path).unwrap();
+        note.push_str("Follow up on pricing\n");
+        fs::write(&call_path, note).unwrap();
+
+        let email = Email::new(
+            "e-12345678".to_string(),
+            "0013600000ABC".to_string(),
+            "Renewal".to_string(),
+            EmailDirection::Inbound,
+            "2025-02-01T09:00:00+00:00[UTC]".parse().unwrap(),
+            "dana@acme.com".to_string(),
+        );
+        let rerun = vault
+            .write("Acme Corp", &[sample_call()], &[email])
+            .unwrap();
+        assert_eq!(rerun.len(), files.len() + 1);
+
+        let note = fs::read_to_string(&call_path).unwrap();
+        assert_eq!(note.matches(NOTES_MARKER).count(), 1);
+        assert!(note.ends_with("Follow up on pricing\n"));
+
+        let customer = fs::read_to_string(root.join("customers/Acme Corp.md")).unwrap();
+        assert!(customer.contains("- [[Dana Customer]]"));
+        let calls = customer.find("· Call ·").unwrap();
+        let emails = customer.find("· Email ·").unwrap();
+        assert!(emails < calls, "newest first");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
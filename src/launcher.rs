@@ -31,7 +31,21 @@ pub fn ensure_terminal() {
         launch_in_powershell_windows(&exe_path, &args);
     }
 
-    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    #[cfg(target_os = "linux")]
+    {
+        let stdin = LinuxStream::probe(0, std::io::stdin().is_terminal());
+        let stdout = LinuxStream::probe(1, false);
+        let has_display =
+            env::var_os("DISPLAY").is_some() || env::var_os("WAYLAND_DISPLAY").is_some();
+        if !should_relaunch_linux(stdin, stdout, has_display) {
+            eprintln!("This application requires a terminal to run.");
+            eprintln!("Please run it from your terminal emulator.");
+            exit(1);
+        }
+        launch_in_terminal_linux(&exe_path, &args);
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
     {
         eprintln!("This application requires a terminal to run.");
         eprintln!("Please run it from your terminal emulator.");
@@ -167,4 +181,209 @@ fn show_windows_error_dialog() {
             );
         }
     }
-}
\ No newline at end of file
+}
+
+/// Terminal emulators to try, in order, with the flag that precedes the command
+#[cfg(target_os = "linux")]
+const LINUX_TERMINALS: &[(&str, &[&str])] = &[
+    ("x-terminal-emulator", &["-e"]),
+    ("gnome-terminal", &["--"]),
+    ("konsole", &["-e"]),
+    ("kitty", &[]),
+    ("alacritty", &["-e"]),
+    ("xterm", &["-e"]),
+];
+
+/// Where a standard stream of this process points
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LinuxStream {
+    Terminal,
+    /// A pipe or regular file, e.g. `> out.md` or `| jq`
+    Redirected,
+    /// /dev/null, the journal socket or a closed descriptor, as a desktop launcher leaves it
+    Detached,
+}
+
+#[cfg(target_os = "linux")]
+impl LinuxStream {
+    fn probe(fd: u32, is_terminal: bool) -> Self {
+        use std::os::unix::fs::FileTypeExt;
+
+        if is_terminal {
+            return LinuxStream::Terminal;
+        }
+        match std::fs::metadata(format!("/proc/self/fd/{fd}")) {
+            Ok(meta) if meta.file_type().is_fifo() || meta.file_type().is_file() => {
+                LinuxStream::Redirected
+            }
+            _ => LinuxStream::Detached,
+        }
+    }
+}
+
+/// Only a launch with no terminal and nothing redirected (e.g. from a .desktop
+/// file) opens a terminal window; redirected runs fail loudly instead
+#[cfg(target_os = "linux")]
+fn should_relaunch_linux(stdin: LinuxStream, stdout: LinuxStream, has_display: bool) -> bool {
+    has_display && stdin == LinuxStream::Detached && stdout == LinuxStream::Detached
+}
+
+#[cfg(target_os = "linux")]
+fn launch_in_terminal_linux(exe_path: &std::path::Path, args: &[String]) {
+    // Keep the window open after cs-cli exits so the output can be read
+    let mut cmd = shell_quote(&exe_path.to_string_lossy());
+    for arg in args {
+        cmd.push(' ');
+        cmd.push_str(&shell_quote(arg));
+    }
+    let script = format!("{cmd}; echo; printf 'Press Enter to exit...'; read _");
+
+    // $TERMINAL may carry its own flags, e.g. "kitty --single-instance"
+    let mut candidates: Vec<(String, Vec<String>)> = Vec::new();
+    if let Ok(terminal) = env::var("TERMINAL") {
+        let mut parts = terminal.split_whitespace().map(String::from);
+        if let Some(program) = parts.next() {
+            let mut flags: Vec<String> = parts.collect();
+            flags.extend(
+                terminal_command_flag(&program)
+                    .iter()
+                    .map(|f| f.to_string()),
+            );
+            candidates.push((program, flags));
+        }
+    }
+    for (program, flags) in LINUX_TERMINALS {
+        candidates.push((
+            program.to_string(),
+            flags.iter().map(|f| f.to_string()).collect(),
+        ));
+    }
+
+    for (program, flags) in candidates {
+        if find_in_path(&program).is_none() {
+            continue;
+        }
+
+        let result = Command::new(&program)
+            .args(&flags)
+            .args(["sh", "-c", &script])
+            .spawn();
+
+        match result {
+            Ok(_) => {
+                println!("Launching in {}...", program);
+                return;
+            }
+            Err(e) => {
+                eprintln!("Failed to launch {}: {}", program, e);
+            }
+        }
+    }
+
+    report_missing_terminal_linux();
+    exit(1);
+}
+
+/// Flag a known terminal expects before the command to run
+#[cfg(target_os = "linux")]
+fn terminal_command_flag(program: &str) -> &'static [&'static str] {
+    let name = std::path::Path::new(program)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(program);
+
+    LINUX_TERMINALS
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, flags)| *flags)
+        .unwrap_or(&["-e"])
+}
+
+/// Locate an executable on PATH (or accept an explicit path)
+#[cfg(target_os = "linux")]
+fn find_in_path(program: &str) -> Option<std::path::PathBuf> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let is_executable = |path: &std::path::Path| {
+        path.metadata()
+            .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    };
+
+    if program.contains('/') {
+        let path = std::path::PathBuf::from(program);
+        return is_executable(&path).then_some(path);
+    }
+
+    env::var_os("PATH").and_then(|paths| {
+        env::split_paths(&paths)
+            .map(|dir| dir.join(program))
+            .find(|path| is_executable(path))
+    })
+}
+
+/// Single-quote an argument for `sh -c`
+#[cfg(target_os = "linux")]
+fn shell_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+/// Tell the user no terminal was found: a desktop notification when possible,
+/// otherwise an error log they can find later
+#[cfg(target_os = "linux")]
+fn report_missing_terminal_linux() {
+    let message = "No terminal emulator found. Set $TERMINAL or install one \
+        (gnome-terminal, konsole, kitty, alacritty or xterm), then run cs-cli from it.";
+    eprintln!("{}", message);
+
+    let notified = Command::new("notify-send")
+        .args(["--urgency=critical", "--app-name=CS-CLI", "CS-CLI", message])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+    if notified {
+        return;
+    }
+
+    let Some(log_dir) = dirs::state_dir()
+        .or_else(dirs::cache_dir)
+        .map(|dir| dir.join("cs-cli"))
+    else {
+        return;
+    };
+    let log_path = log_dir.join("launch-error.log");
+    let entry = format!("[{}] {}\n", jiff::Timestamp::now(), message);
+    let written = std::fs::create_dir_all(&log_dir).and_then(|_| {
+        use std::io::Write;
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)?
+            .write_all(entry.as_bytes())
+    });
+    if written.is_ok() {
+        eprintln!("Details written to {}", log_path.display());
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relaunch_only_for_detached_desktop_launch() {
+        use LinuxStream::*;
+
+        // Double-clicked from a desktop file
+        assert!(should_relaunch_linux(Detached, Detached, true));
+        // No desktop session to open a window in
+        assert!(!should_relaunch_linux(Detached, Detached, false));
+        // `cs-cli > out.md` or `cs-cli | tee` from a terminal
+        assert!(!should_relaunch_linux(Terminal, Redirected, true));
+        // `cs-cli --events=json | jq` from a script
+        assert!(!should_relaunch_linux(Detached, Redirected, true));
+        // `echo | cs-cli > /dev/null`
+        assert!(!should_relaunch_linux(Redirected, Detached, true));
+    }
+}
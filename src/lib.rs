@@ -13,3 +13,4 @@ pub mod slack;
 
 // Re-export core types for convenience
 pub use common::{CsCliError, Result};
+pub use gong::extractor::{ExtractRequest, Extractor};
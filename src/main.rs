@@ -1,27 +1,69 @@
 mod launcher;
 
-use cs_cli::gong::cli::{run_cli, CliArgs};
+use cs_cli::common::events::{
+    emit, fancy_output, init_events, status, strip_ansi, Event, EventFormat,
+};
+use cs_cli::gong::config::AppConfig;
+use cs_cli::gong::cli::{run_cli, CliArgs, Command};
 use cs_cli::Result;
 use owo_colors::OwoColorize;
 use clap::Parser;
+use std::io::IsTerminal;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Parse CLI arguments first
     let cli = CliArgs::parse();
+    init_events(cli.events, cli.quiet);
 
-    // Check for updates first (silent fail if network issues)
-    let _ = cs_cli::updater::check_and_update().await;
+    // Check for updates first (silent fail if network issues); installing
+    // always asks first, so only offer it when someone can answer
+    if cli.events == EventFormat::Human && !matches!(cli.command, Some(Command::Update(_))) {
+        let interactive = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+        let settings = AppConfig::load().map(|config| config.update).unwrap_or_default();
+        let _ = cs_cli::updater::check_at_startup(&settings, interactive).await;
+    }
 
     // Check if we're in a terminal, self-launch if not
-    if !cli.no_terminal {
+    // (event streams are read by another program, never a terminal)
+    if !cli.no_terminal && cli.events == EventFormat::Human {
         launcher::ensure_terminal();
     }
 
     // Initialize tracing for comprehensive async debugging
-    let _ = tracing_subscriber::fmt::try_init();
+    // (stderr keeps stdout clean for --events=json)
+    let _ = tracing_subscriber::fmt().with_writer(std::io::stderr).try_init();
 
     // Run Gong CLI
-    println!("{}", "CS-CLI - Initializing...".truecolor(255, 108, 55));
-    run_cli().await
+    status("CS-CLI - Initializing...".truecolor(255, 108, 55));
+    if let Err(e) = run_cli().await {
+        emit(Event::from_error(&e));
+        report_error(&e);
+        std::process::exit(e.exit_code());
+    }
+
+    Ok(())
+}
+
+/// Print an error with its source chain and a hint, on stderr
+fn report_error(error: &cs_cli::CsCliError) {
+    let plain = |text: String| {
+        if fancy_output() {
+            text
+        } else {
+            strip_ansi(&text)
+        }
+    };
+
+    eprintln!("{}", plain(format!("{} {error}", "Error:".red().bold())));
+
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        eprintln!("  caused by: {cause}");
+        source = cause.source();
+    }
+
+    if let Some(hint) = error.hint() {
+        eprintln!("{}", plain(hint.yellow().to_string()));
+    }
 }
@@ -0,0 +1,570 @@
+//! Slack Web API client
+//!
+//! Lists the workspace's Slack Connect channels, pages through their history
+//! with cursors and expands threads with `conversations.replies`. Messages
+//! are tagged inbound or outbound by comparing the author's team with ours.
+
+use jiff::Timestamp;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::time::Duration;
+use tokio::sync::{Mutex, OnceCell};
+use tracing::{debug, info, warn};
+
+use super::auth::SlackCredentials;
+use super::config::SlackSettings;
+use super::models::{timestamp_to_ts, SlackChannel, SlackConversation, SlackMessage};
+use crate::common::models::MessageDirection;
+use crate::{CsCliError, Result};
+
+/// Maximum attempts when Slack answers 429 Too Many Requests
+const MAX_RATE_LIMIT_ATTEMPTS: u32 = 3;
+
+/// Items requested per page
+const PAGE_SIZE: &str = "200";
+
+/// Message subtypes that are channel housekeeping rather than conversation
+const IGNORED_SUBTYPES: &[&str] = &[
+    "channel_join",
+    "channel_leave",
+    "channel_topic",
+    "channel_purpose",
+    "channel_name",
+    "channel_archive",
+    "channel_unarchive",
+    "group_join",
+    "group_leave",
+];
+
+/// Slack Web API client
+pub struct SlackClient {
+    http: reqwest::Client,
+    api_base: String,
+    credentials: SlackCredentials,
+    /// Our own team ID, from `auth.test`
+    team_id: OnceCell<String>,
+    /// User ID to display name
+    users: Mutex<HashMap<String, String>>,
+}
+
+impl SlackClient {
+    /// Create a client for the configured API base
+    pub fn new(settings: &SlackSettings, credentials: SlackCredentials) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("cs-cli/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| CsCliError::ApiRequest(format!("Failed to create Slack client: {e}")))?;
+
+        Ok(Self {
+            http,
+            api_base: settings.api_base.trim_end_matches('/').to_string(),
+            credentials,
+            team_id: OnceCell::new(),
+            users: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Call a Web API method, retrying on rate limits
+    async fn call(&self, api_method: &str, query: &[(&str, String)]) -> Result<Value> {
+        let url = format!("{}/{api_method}", self.api_base);
+
+        for attempt in 1..=MAX_RATE_LIMIT_ATTEMPTS {
+            let mut request = self
+                .http
+                .get(&url)
+                .query(query)
+                .bearer_auth(&self.credentials.token);
+            if let Some(cookie) = &self.credentials.cookie {
+                request = request.header("Cookie", format!("d={cookie}"));
+            }
+
+            let response = request.send().await.map_err(|e| {
+                CsCliError::http("GET", &url, None, "request failed")
+                    .with_retries(attempt - 1)
+                    .with_source(e)
+            })?;
+            let status = response.status();
+
+            if status.as_u16() == 429 && attempt < MAX_RATE_LIMIT_ATTEMPTS {
+                let wait = response
+                    .headers()
+                    .get("retry-after")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(attempt as u64 * 2);
+                warn!(method = %api_method, wait_seconds = wait, "Slack rate limit hit, retrying");
+                tokio::time::sleep(Duration::from_secs(wait)).await;
+                continue;
+            }
+
+            let text = response.text().await.map_err(|e| {
+                CsCliError::http(
+                    "GET",
+                    &url,
+                    Some(status.as_u16()),
+                    "failed to read response",
+                )
+                .with_source(e)
+            })?;
+            if !status.is_success() {
+                return Err(CsCliError::http_status("GET", &url, status.as_u16(), &text)
+                    .with_retries(attempt - 1));
+            }
+
+            let body: Value = serde_json::from_str(&text).map_err(|e| {
+                CsCliError::http("GET", &url, Some(status.as_u16()), "invalid JSON").with_source(e)
+            })?;
+
+            // Slack reports failures as 200 with ok=false
+            if !body.get("ok").and_then(Value::as_bool).unwrap_or(false) {
+                let error = body
+                    .get("error")
+                    .and_then(Value::as_str)
+                    .unwrap_or("unknown_error");
+                return Err(match error {
+                    "not_authed" | "invalid_auth" | "token_revoked" | "token_expired"
+                    | "account_inactive" => CsCliError::Authentication(format!(
+                        "Slack rejected the session ({error}). Sign in again or refresh SLACK_TOKEN."
+                    )),
+                    _ => CsCliError::ApiRequest(format!("Slack {api_method} failed: {error}")),
+                });
+            }
+            return Ok(body);
+        }
+
+        Err(CsCliError::http(
+            "GET",
+            &url,
+            Some(429),
+            "kept returning 429 Too Many Requests",
+        )
+        .with_retries(MAX_RATE_LIMIT_ATTEMPTS - 1))
+    }
+
+    /// Collect `key` from every page of a cursor-paginated method
+    async fn paginate(
+        &self,
+        api_method: &str,
+        query: &[(&str, String)],
+        key: &str,
+    ) -> Result<Vec<Value>> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut page_query = query.to_vec();
+            page_query.push(("limit", PAGE_SIZE.to_string()));
+            if let Some(cursor) = &cursor {
+                page_query.push(("cursor", cursor.clone()));
+            }
+
+            let page = self.call(api_method, &page_query).await?;
+            if let Some(page_items) = page.get(key).and_then(Value::as_array) {
+                items.extend(page_items.iter().cloned());
+            }
+
+            cursor = page
+                .pointer("/response_metadata/next_cursor")
+                .and_then(Value::as_str)
+                .filter(|c| !c.is_empty())
+                .map(String::from);
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        debug!(method = %api_method, items = items.len(), "Paginated Slack results");
+        Ok(items)
+    }
+
+    /// Our own team ID
+    pub async fn team_id(&self) -> Result<&str> {
+        self.team_id
+            .get_or_try_init(|| async {
+                let auth = self.call("auth.test", &[]).await?;
+                Ok::<_, CsCliError>(
+                    auth.get("team_id")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                )
+            })
+            .await
+            .map(String::as_str)
+    }
+
+    /// Channels the signed-in user belongs to
+    pub async fn list_channels(&self) -> Result<Vec<SlackChannel>> {
+        let query = [
+            ("types", "public_channel,private_channel".to_string()),
+            ("exclude_archived", "true".to_string()),
+        ];
+        let channels = self
+            .paginate("users.conversations", &query, "channels")
+            .await?;
+
+        Ok(channels
+            .into_iter()
+            .filter_map(|channel| {
+                Some(SlackChannel {
+                    id: channel.get("id")?.as_str()?.to_string(),
+                    name: channel.get("name")?.as_str()?.to_string(),
+                    is_ext_shared: channel
+                        .get("is_ext_shared")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                    is_private: channel
+                        .get("is_private")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                    topic: channel
+                        .pointer("/topic/value")
+                        .and_then(Value::as_str)
+                        .filter(|t| !t.is_empty())
+                        .map(String::from),
+                })
+            })
+            .collect())
+    }
+
+    /// Slack Connect channels whose name contains the customer name
+    pub async fn find_customer_channels(&self, customer: &str) -> Result<Vec<SlackChannel>> {
+        let channels = self.list_channels().await?;
+        let matches: Vec<SlackChannel> = channels
+            .into_iter()
+            .filter(|channel| channel.is_ext_shared && channel_matches(&channel.name, customer))
+            .collect();
+
+        info!(
+            customer = %customer,
+            channels = matches.len(),
+            "Found shared Slack channels"
+        );
+        Ok(matches)
+    }
+
+    /// A channel's messages between `oldest` and `latest`, threads expanded
+    pub async fn conversation(
+        &self,
+        channel: SlackChannel,
+        oldest: Timestamp,
+        latest: Timestamp,
+    ) -> Result<SlackConversation> {
+        let query = [
+            ("channel", channel.id.clone()),
+            ("oldest", timestamp_to_ts(oldest)),
+            ("latest", timestamp_to_ts(latest)),
+            ("inclusive", "true".to_string()),
+        ];
+        let mut history = self
+            .paginate("conversations.history", &query, "messages")
+            .await?;
+        // History arrives newest first
+        history.reverse();
+
+        let mut messages = Vec::new();
+        for raw in history.iter().filter(|m| is_conversation_message(m)) {
+            let mut message = self.to_message(raw).await?;
+
+            let starts_thread = raw.get("reply_count").and_then(Value::as_u64).unwrap_or(0) > 0
+                && raw.get("thread_ts").and_then(Value::as_str) == Some(message.ts.as_str());
+            if starts_thread {
+                for reply in self.replies(&channel.id, &message.ts).await? {
+                    message.replies.push(self.to_message(&reply).await?);
+                }
+            }
+            messages.push(message);
+        }
+
+        debug!(channel = %channel.name, messages = messages.len(), "Fetched Slack channel history");
+        Ok(SlackConversation { channel, messages })
+    }
+
+    /// Replies in a thread, without the parent message
+    async fn replies(&self, channel_id: &str, thread_ts: &str) -> Result<Vec<Value>> {
+        let query = [
+            ("channel", channel_id.to_string()),
+            ("ts", thread_ts.to_string()),
+        ];
+        let replies = self
+            .paginate("conversations.replies", &query, "messages")
+            .await?;
+
+        Ok(replies
+            .into_iter()
+            .filter(|reply| reply.get("ts").and_then(Value::as_str) != Some(thread_ts))
+            .filter(|reply| is_conversation_message(reply))
+            .collect())
+    }
+
+    /// Convert a raw message, resolving author and mention names
+    async fn to_message(&self, raw: &Value) -> Result<SlackMessage> {
+        let user_id = raw.get("user").and_then(Value::as_str).map(String::from);
+        let user_name = match &user_id {
+            Some(id) => self.user_name(id).await,
+            None => raw
+                .get("username")
+                .or_else(|| raw.pointer("/bot_profile/name"))
+                .and_then(Value::as_str)
+                .unwrap_or("Unknown")
+                .to_string(),
+        };
+
+        let team = raw
+            .get("user_team")
+            .or_else(|| raw.get("team"))
+            .or_else(|| raw.pointer("/bot_profile/team_id"))
+            .and_then(Value::as_str);
+        let our_team = self.team_id().await?;
+        let direction = match team {
+            Some(team) if team == our_team => MessageDirection::Outbound,
+            Some(_) => MessageDirection::Inbound,
+            None => MessageDirection::Internal,
+        };
+
+        let text = raw.get("text").and_then(Value::as_str).unwrap_or_default();
+
+        Ok(SlackMessage {
+            ts: raw
+                .get("ts")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            user_id,
+            user_name,
+            text: self.resolve_mentions(text).await,
+            direction,
+            replies: Vec::new(),
+        })
+    }
+
+    /// Replace `<@U123>` mentions with `@Name`
+    async fn resolve_mentions(&self, text: &str) -> String {
+        static MENTION: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"<@([A-Z0-9_]+)(?:\|[^>]*)?>").unwrap());
+
+        let ids: Vec<String> = MENTION
+            .captures_iter(text)
+            .map(|captures| captures[1].to_string())
+            .collect();
+        if ids.is_empty() {
+            return text.to_string();
+        }
+
+        let mut names: HashMap<String, String> = HashMap::new();
+        for id in ids {
+            if !names.contains_key(&id) {
+                let name = self.user_name(&id).await;
+                names.insert(id, name);
+            }
+        }
+
+        MENTION
+            .replace_all(text, |captures: &regex::Captures| {
+                format!("@{}", names[&captures[1]])
+            })
+            .into_owned()
+    }
+
+    /// Display name for a user, looked up once per run
+    async fn user_name(&self, user_id: &str) -> String {
+        if let Some(name) = self.users.lock().await.get(user_id) {
+            return name.clone();
+        }
+
+        let name = match self
+            .call("users.info", &[("user", user_id.to_string())])
+            .await
+        {
+            Ok(info) => [
+                "/user/profile/display_name",
+                "/user/real_name",
+                "/user/name",
+            ]
+            .iter()
+            .filter_map(|pointer| info.pointer(pointer).and_then(Value::as_str))
+            .find(|name| !name.is_empty())
+            .unwrap_or(user_id)
+            .to_string(),
+            Err(e) => {
+                debug!(user = %user_id, error = %e, "Could not look up Slack user");
+                user_id.to_string()
+            }
+        };
+
+        self.users
+            .lock()
+            .await
+            .insert(user_id.to_string(), name.clone());
+        name
+    }
+}
+
+/// Whether a channel name refers to the customer, ignoring case and punctuation
+/// (`ext-acme-corp` matches "Acme Corp")
+pub fn channel_matches(channel_name: &str, customer: &str) -> bool {
+    let normalize = |s: &str| -> String {
+        s.chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let customer = normalize(customer);
+    !customer.is_empty() && normalize(channel_name).contains(&customer)
+}
+
+/// Real messages, skipping joins, topic changes and similar notices
+fn is_conversation_message(message: &Value) -> bool {
+    match message.get("subtype").and_then(Value::as_str) {
+        Some(subtype) => !IGNORED_SUBTYPES.contains(&subtype),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mock_slack() -> MockServer {
+        let server = MockServer::start().await;
+        let ok = |body: Value| ResponseTemplate::new(200).set_body_json(body);
+
+        Mock::given(method("GET"))
+            .and(path("/auth.test"))
+            .and(header("authorization", "Bearer xoxc-test"))
+            .and(header("cookie", "d=cookie"))
+            .respond_with(ok(json!({"ok": true, "team_id": "T_OURS"})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users.conversations"))
+            .respond_with(ok(json!({
+                "ok": true,
+                "channels": [
+                    {"id": "C1", "name": "ext-acme-corp", "is_ext_shared": true},
+                    {"id": "C2", "name": "acme-internal", "is_ext_shared": false},
+                    {"id": "C3", "name": "ext-globex", "is_ext_shared": true}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        // Two history pages, newest first
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("cursor", "page2"))
+            .respond_with(ok(json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "1700000000.000100", "user": "U_THEM", "user_team": "T_ACME", "text": "Hi <@U_US>, quick question", "thread_ts": "1700000000.000100", "reply_count": 1},
+                    {"ts": "1699999999.000000", "subtype": "channel_join", "user": "U_THEM", "text": "joined"}
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .respond_with(ok(json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "1700000500.000000", "user": "U_US", "user_team": "T_OURS", "text": "Following up"}
+                ],
+                "response_metadata": {"next_cursor": "page2"}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.replies"))
+            .respond_with(ok(json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "1700000000.000100", "user": "U_THEM", "user_team": "T_ACME", "text": "Hi"},
+                    {"ts": "1700000100.000000", "user": "U_US", "user_team": "T_OURS", "text": "Happy to help"}
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users.info"))
+            .and(query_param("user", "U_US"))
+            .respond_with(ok(
+                json!({"ok": true, "user": {"name": "sam", "profile": {"display_name": "Sam"}}}),
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users.info"))
+            .and(query_param("user", "U_THEM"))
+            .respond_with(ok(
+                json!({"ok": true, "user": {"name": "dana", "real_name": "Dana Customer"}}),
+            ))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    fn client(server: &MockServer) -> SlackClient {
+        let settings = SlackSettings {
+            api_base: server.uri(),
+            ..SlackSettings::default()
+        };
+        SlackClient::new(
+            &settings,
+            SlackCredentials::new("xoxc-test", Some("cookie".to_string())),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_customer_channel_history_with_threads() {
+        let server = mock_slack().await;
+        let client = client(&server);
+
+        let channels = client.find_customer_channels("Acme Corp").await.unwrap();
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].name, "ext-acme-corp");
+
+        let now = Timestamp::from_second(1_700_001_000).unwrap();
+        let oldest = Timestamp::from_second(1_699_000_000).unwrap();
+        let conversation = client
+            .conversation(channels[0].clone(), oldest, now)
+            .await
+            .unwrap();
+
+        // Oldest first, join notice dropped, thread expanded
+        assert_eq!(conversation.messages.len(), 2);
+        assert_eq!(conversation.message_count(), 3);
+        let first = &conversation.messages[0];
+        assert_eq!(first.user_name, "Dana Customer");
+        assert_eq!(first.text, "Hi @Sam, quick question");
+        assert_eq!(first.direction, MessageDirection::Inbound);
+        assert_eq!(first.replies.len(), 1);
+        assert_eq!(first.replies[0].direction, MessageDirection::Outbound);
+        assert_eq!(conversation.messages[1].text, "Following up");
+    }
+
+    #[tokio::test]
+    async fn test_slack_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/auth.test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": false, "error": "invalid_auth"})),
+            )
+            .mount(&server)
+            .await;
+
+        let error = client(&server).team_id().await.unwrap_err();
+        assert!(matches!(error, CsCliError::Authentication(_)));
+        assert!(channel_matches("ext-acme_corp", "ACME Corp"));
+        assert!(!channel_matches("ext-globex", "Acme"));
+    }
+}
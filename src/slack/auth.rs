@@ -0,0 +1,249 @@
+//! Slack authentication
+//!
+//! A configured token is used as-is. Otherwise the browser's `d` session
+//! cookie is read for slack.com and exchanged for the `xoxc-` token the
+//! workspace page embeds, the same way the Slack web client signs in.
+
+use async_trait::async_trait;
+use regex::Regex;
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+use super::config::SlackSettings;
+use crate::common::auth::{AuthToken, BrowserAuth, BrowserSessionExtractor, SessionData};
+use crate::{CsCliError, Result};
+
+/// Cookie domain holding the Slack session
+const SLACK_COOKIE_DOMAIN: &str = "slack.com";
+
+/// Token (and cookie, for browser tokens) used to call the Web API
+#[derive(Debug, Clone)]
+pub struct SlackCredentials {
+    pub token: String,
+    /// `d` cookie value, required alongside `xoxc-` tokens
+    pub cookie: Option<String>,
+    /// Workspace host the credentials belong to, if known
+    pub workspace: Option<String>,
+}
+
+impl SlackCredentials {
+    /// Credentials from a token, plus the `d` cookie for browser tokens
+    pub fn new(token: impl Into<String>, cookie: Option<String>) -> Self {
+        Self {
+            token: token.into(),
+            cookie,
+            workspace: None,
+        }
+    }
+
+    /// Whether this is a browser session token that needs the `d` cookie
+    pub fn is_browser_token(&self) -> bool {
+        self.token.starts_with("xoxc-")
+    }
+}
+
+impl AuthToken for SlackCredentials {
+    fn is_expired(&self) -> bool {
+        // Slack doesn't expose expiry; a revoked token fails with invalid_auth
+        false
+    }
+
+    fn get_token(&self) -> &str {
+        &self.token
+    }
+
+    fn get_token_type(&self) -> &str {
+        self.token.split('-').next().unwrap_or("Bearer")
+    }
+}
+
+impl SessionData for SlackCredentials {
+    fn session_id(&self) -> &str {
+        &self.token
+    }
+
+    fn workspace_id(&self) -> &str {
+        self.workspace.as_deref().unwrap_or("")
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.token.is_empty() && (!self.is_browser_token() || self.cookie.is_some())
+    }
+}
+
+/// Reads Slack credentials from a logged-in browser
+pub struct SlackBrowserAuth {
+    extractor: BrowserSessionExtractor,
+    http: reqwest::Client,
+}
+
+impl SlackBrowserAuth {
+    /// Create an extractor for the slack.com session cookie
+    pub fn new() -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(concat!("cs-cli/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| CsCliError::ApiRequest(format!("Failed to create Slack client: {e}")))?;
+
+        Ok(Self {
+            extractor: BrowserSessionExtractor::new(vec![SLACK_COOKIE_DOMAIN.to_string()]),
+            http,
+        })
+    }
+
+    /// Fetch the workspace page with the session cookie and read its token
+    pub async fn token_from_session(&self, workspace: &str, cookie: &str) -> Result<String> {
+        let url = workspace_url(workspace);
+        let response = self
+            .http
+            .get(&url)
+            .header("Cookie", format!("d={cookie}"))
+            .send()
+            .await
+            .map_err(|e| CsCliError::http("GET", &url, None, "request failed").with_source(e))?;
+
+        let status = response.status();
+        let page = response.text().await.map_err(|e| {
+            CsCliError::http("GET", &url, Some(status.as_u16()), "failed to read page")
+                .with_source(e)
+        })?;
+
+        parse_api_token(&page).ok_or_else(|| {
+            CsCliError::Authentication(format!(
+                "No Slack session found for {workspace}. Sign in to Slack in your browser or set SLACK_TOKEN."
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl BrowserAuth for SlackBrowserAuth {
+    type AuthData = SlackCredentials;
+
+    async fn extract_browser_auth(&mut self, domain: &str) -> Result<SlackCredentials> {
+        let cookie = self
+            .extractor
+            .extract_cookie_by_name(SLACK_COOKIE_DOMAIN, "d")?
+            .value;
+        let token = self.token_from_session(domain, &cookie).await?;
+        info!(workspace = %domain, "Read Slack session from browser");
+
+        Ok(SlackCredentials {
+            token,
+            cookie: Some(cookie),
+            workspace: Some(domain.to_string()),
+        })
+    }
+
+    fn validate_auth_data(&self, auth_data: &SlackCredentials) -> bool {
+        auth_data.is_valid()
+    }
+
+    fn auth_data_to_headers(&self, auth_data: &SlackCredentials) -> HashMap<String, String> {
+        let mut headers = HashMap::new();
+        headers.insert(
+            "Authorization".to_string(),
+            format!("Bearer {}", auth_data.token),
+        );
+        if let Some(cookie) = &auth_data.cookie {
+            headers.insert("Cookie".to_string(), format!("d={cookie}"));
+        }
+        headers
+    }
+}
+
+/// Credentials from settings, falling back to the browser session
+pub async fn resolve_credentials(settings: &SlackSettings) -> Result<SlackCredentials> {
+    if let Some(token) = &settings.token {
+        debug!("Using configured Slack token");
+        let credentials = SlackCredentials {
+            workspace: settings.workspace.clone(),
+            ..SlackCredentials::new(token.clone(), settings.cookie.clone())
+        };
+        if !credentials.is_valid() {
+            return Err(CsCliError::Configuration(
+                "xoxc- Slack tokens need the browser's d cookie in SLACK_COOKIE".to_string(),
+            ));
+        }
+        return Ok(credentials);
+    }
+
+    let Some(workspace) = &settings.workspace else {
+        return Err(CsCliError::Configuration(
+            "Set SLACK_TOKEN, or SLACK_WORKSPACE (e.g. acme.slack.com) to use your browser session"
+                .to_string(),
+        ));
+    };
+
+    let mut auth = SlackBrowserAuth::new()?;
+    if let Some(cookie) = &settings.cookie {
+        let token = auth.token_from_session(workspace, cookie).await?;
+        return Ok(SlackCredentials {
+            token,
+            cookie: Some(cookie.clone()),
+            workspace: Some(workspace.clone()),
+        });
+    }
+    auth.extract_browser_auth(workspace).await
+}
+
+/// Workspace page URL for a host like `acme.slack.com`
+fn workspace_url(workspace: &str) -> String {
+    if workspace.starts_with("http://") || workspace.starts_with("https://") {
+        workspace.to_string()
+    } else {
+        format!("https://{}/", workspace.trim_end_matches('/'))
+    }
+}
+
+/// The `api_token` the Slack web client embeds in the workspace page
+pub fn parse_api_token(page: &str) -> Option<String> {
+    let regex = Regex::new(r#""api_token"\s*:\s*"(xoxc-[A-Za-z0-9-]+)""#).unwrap();
+    regex
+        .captures(page)
+        .and_then(|captures| captures.get(1))
+        .map(|token| token.as_str().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_parse_api_token() {
+        let page =
+            r#"<script>var boot_data = {"team_id":"T1","api_token":"xoxc-123-456-abc"};</script>"#;
+        assert_eq!(parse_api_token(page).as_deref(), Some("xoxc-123-456-abc"));
+        assert_eq!(parse_api_token("<html>Sign in</html>"), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_credentials_from_cookie() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .and(header("cookie", "d=session-cookie"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"api_token":"xoxc-1-2-3"}"#),
+            )
+            .mount(&server)
+            .await;
+
+        let settings = SlackSettings {
+            cookie: Some("session-cookie".to_string()),
+            workspace: Some(format!("{}/", server.uri())),
+            ..SlackSettings::default()
+        };
+        let credentials = resolve_credentials(&settings).await.unwrap();
+        assert_eq!(credentials.token, "xoxc-1-2-3");
+        assert!(credentials.is_valid());
+
+        let missing_cookie = SlackSettings {
+            token: Some("xoxc-1-2-3".to_string()),
+            ..SlackSettings::default()
+        };
+        assert!(resolve_credentials(&missing_cookie).await.is_err());
+    }
+}
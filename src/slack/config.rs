@@ -0,0 +1,36 @@
+//! Slack connection settings
+//!
+//! Read from the environment so the Slack feature stays out of the shared
+//! config schema in customer-facing builds.
+
+/// Default Slack Web API base URL
+pub const DEFAULT_SLACK_API_BASE: &str = "https://slack.com/api";
+
+/// Where and how to reach Slack
+#[derive(Debug, Clone, Default)]
+pub struct SlackSettings {
+    /// API token (`xoxb-`, `xoxp-` or a browser `xoxc-` token)
+    pub token: Option<String>,
+    /// Value of the browser `d` cookie, needed with `xoxc-` tokens
+    pub cookie: Option<String>,
+    /// Workspace host such as `acme.slack.com`, used to read a token from the
+    /// browser session when no token is configured
+    pub workspace: Option<String>,
+    /// Web API base URL (tests point this at a mock server)
+    pub api_base: String,
+}
+
+impl SlackSettings {
+    /// Settings from `SLACK_TOKEN`, `SLACK_COOKIE`, `SLACK_WORKSPACE` and
+    /// `SLACK_API_BASE`
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+
+        Self {
+            token: var("SLACK_TOKEN"),
+            cookie: var("SLACK_COOKIE"),
+            workspace: var("SLACK_WORKSPACE"),
+            api_base: var("SLACK_API_BASE").unwrap_or_else(|| DEFAULT_SLACK_API_BASE.to_string()),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Slack integration for CS-CLI
+//!
+//! Pulls the Slack Connect channels shared with a customer and saves them as
+//! markdown alongside the customer's Gong calls and emails. Only built with
+//! the `slack` feature.
+
+pub mod api;
+pub mod auth;
+pub mod config;
+pub mod models;
+pub mod output;
+
+pub use api::*;
+pub use auth::*;
+pub use config::*;
+pub use models::*;
+pub use output::*;
+
+use jiff::{Timestamp, ToSpan};
+use std::path::PathBuf;
+use tracing::info;
+
+use crate::Result;
+
+/// Slack conversations found for a customer and the files written
+#[derive(Debug, Clone)]
+pub struct SlackExtraction {
+    pub conversations: Vec<SlackConversation>,
+    pub saved_files: Vec<PathBuf>,
+}
+
+impl SlackExtraction {
+    /// Messages across all channels, including thread replies
+    pub fn message_count(&self) -> usize {
+        self.conversations
+            .iter()
+            .map(SlackConversation::message_count)
+            .sum()
+    }
+}
+
+/// Extract the customer's shared channels from the last `days` days and save
+/// them to `output_dir`
+pub async fn extract_customer_slack(
+    settings: &SlackSettings,
+    customer: &str,
+    days: u32,
+    output_dir: PathBuf,
+) -> Result<SlackExtraction> {
+    let credentials = resolve_credentials(settings).await?;
+    let client = SlackClient::new(settings, credentials)?;
+
+    let latest = Timestamp::now();
+    let oldest = latest - (i64::from(days) * 24).hours();
+
+    let mut conversations = Vec::new();
+    for channel in client.find_customer_channels(customer).await? {
+        conversations.push(client.conversation(channel, oldest, latest).await?);
+    }
+
+    let saved_files = SlackMarkdownWriter::new(output_dir).save(customer, &conversations)?;
+    let extraction = SlackExtraction {
+        conversations,
+        saved_files,
+    };
+    info!(
+        customer = %customer,
+        channels = extraction.conversations.len(),
+        messages = extraction.message_count(),
+        "Extracted Slack conversations"
+    );
+    Ok(extraction)
+}
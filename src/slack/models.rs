@@ -0,0 +1,78 @@
+//! Slack conversation models
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+
+use crate::common::models::{CommunicationType, MessageDirection};
+
+/// A Slack channel shared with a customer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackChannel {
+    pub id: String,
+    pub name: String,
+    /// Slack Connect channel shared with another organization
+    #[serde(default)]
+    pub is_ext_shared: bool,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub topic: Option<String>,
+}
+
+/// A message, with its thread replies when it started one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackMessage {
+    /// Slack timestamp, which doubles as the message ID
+    pub ts: String,
+    pub user_id: Option<String>,
+    pub user_name: String,
+    pub text: String,
+    pub direction: MessageDirection,
+    #[serde(default)]
+    pub replies: Vec<SlackMessage>,
+}
+
+impl SlackMessage {
+    /// Communication type shared with the other integrations
+    pub fn communication_type(&self) -> CommunicationType {
+        CommunicationType::Chat
+    }
+
+    /// When the message was posted, from its Slack timestamp
+    pub fn posted_at(&self) -> Option<Timestamp> {
+        ts_to_timestamp(&self.ts)
+    }
+
+    /// This message plus all of its replies
+    pub fn message_count(&self) -> usize {
+        1 + self.replies.len()
+    }
+}
+
+/// A channel's messages for one extraction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackConversation {
+    pub channel: SlackChannel,
+    /// Top-level messages, oldest first
+    pub messages: Vec<SlackMessage>,
+}
+
+impl SlackConversation {
+    /// Messages including thread replies
+    pub fn message_count(&self) -> usize {
+        self.messages.iter().map(SlackMessage::message_count).sum()
+    }
+}
+
+/// Parse a Slack `ts` such as `1712345678.000200`
+pub fn ts_to_timestamp(ts: &str) -> Option<Timestamp> {
+    let (seconds, micros) = ts.split_once('.').unwrap_or((ts, "0"));
+    let seconds: i64 = seconds.parse().ok()?;
+    let micros: i32 = format!("{micros:0<6}").get(..6)?.parse().ok()?;
+    Timestamp::new(seconds, micros * 1000).ok()
+}
+
+/// Format a timestamp as a Slack `ts` for the `oldest`/`latest` parameters
+pub fn timestamp_to_ts(timestamp: Timestamp) -> String {
+    format!("{}.000000", timestamp.as_second())
+}
@@ -0,0 +1,213 @@
+//! Markdown output for Slack conversations
+//!
+//! One file per channel, written next to the customer's Gong calls and
+//! emails. Days get their own headings and thread replies are quoted under
+//! the message that started them.
+
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use super::models::{SlackConversation, SlackMessage};
+use crate::common::models::MessageDirection;
+use crate::gong::output::slug;
+use crate::{CsCliError, Result};
+
+/// Writes Slack conversations as markdown
+pub struct SlackMarkdownWriter {
+    output_dir: PathBuf,
+}
+
+impl SlackMarkdownWriter {
+    /// Write into `output_dir`, usually the customer's `ct_<customer>` folder
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// Directory files are written to
+    pub fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    /// Render one channel
+    pub fn format_conversation(&self, customer: &str, conversation: &SlackConversation) -> String {
+        let channel = &conversation.channel;
+        let mut md = format!("# Slack: #{}\n\n", channel.name);
+        md.push_str(&format!("**Customer:** {customer}\n"));
+        md.push_str(&format!(
+            "**Channel type:** {}\n",
+            if channel.is_ext_shared {
+                "Slack Connect"
+            } else if channel.is_private {
+                "Private"
+            } else {
+                "Public"
+            }
+        ));
+        if let Some(topic) = &channel.topic {
+            md.push_str(&format!("**Topic:** {}\n", format_slack_text(topic)));
+        }
+        md.push_str(&format!(
+            "**Messages:** {}\n\n---\n",
+            conversation.message_count()
+        ));
+
+        let mut current_day = String::new();
+        for message in &conversation.messages {
+            let day = message
+                .posted_at()
+                .map(|t| t.strftime("%A, %B %d, %Y").to_string())
+                .unwrap_or_else(|| "Unknown date".to_string());
+            if day != current_day {
+                md.push_str(&format!("\n## {day}\n"));
+                current_day = day;
+            }
+
+            md.push('\n');
+            md.push_str(&format_message(message, ""));
+            for reply in &message.replies {
+                md.push_str(">\n");
+                md.push_str(&format_message(reply, "> "));
+            }
+        }
+
+        md
+    }
+
+    /// Save one file per channel, skipping channels with no messages
+    pub fn save(
+        &self,
+        customer: &str,
+        conversations: &[SlackConversation],
+    ) -> Result<Vec<PathBuf>> {
+        fs::create_dir_all(&self.output_dir)
+            .map_err(|e| CsCliError::FileIo(format!("Failed to create output directory: {e}")))?;
+
+        let customer_slug = slug(customer, "unnamed");
+        let mut saved_files = Vec::new();
+        for conversation in conversations.iter().filter(|c| !c.messages.is_empty()) {
+            let path = self.output_dir.join(format!(
+                "{customer_slug}-slack-{}.md",
+                slug(&conversation.channel.name, "unnamed")
+            ));
+            fs::write(&path, self.format_conversation(customer, conversation)).map_err(|e| {
+                CsCliError::FileIo(format!("Failed to save {}: {e}", path.display()))
+            })?;
+            saved_files.push(path);
+        }
+
+        info!(
+            files = saved_files.len(),
+            output_dir = %self.output_dir.display(),
+            "Saved Slack conversations"
+        );
+        Ok(saved_files)
+    }
+}
+
+/// One message as markdown, each line prefixed for quoting replies
+fn format_message(message: &SlackMessage, prefix: &str) -> String {
+    let time = message
+        .posted_at()
+        .map(|t| t.strftime("%H:%M UTC").to_string())
+        .unwrap_or_default();
+    let marker = match message.direction {
+        MessageDirection::Inbound => " (customer)",
+        _ => "",
+    };
+
+    let mut md = format!("{prefix}**{}**{marker} · {time}\n", message.user_name);
+    for line in format_slack_text(&message.text).lines() {
+        md.push_str(&format!("{prefix}{line}\n"));
+    }
+    md
+}
+
+/// Convert Slack markup to markdown: links, channel references, broadcasts
+/// and HTML entities
+pub fn format_slack_text(text: &str) -> String {
+    let links = Regex::new(r"<(https?://[^|>]+)\|([^>]+)>").unwrap();
+    let text = links.replace_all(text, "[$2]($1)");
+    let bare_links = Regex::new(r"<(https?://[^>]+)>").unwrap();
+    let text = bare_links.replace_all(&text, "$1");
+    let channels = Regex::new(r"<#[A-Z0-9]+\|([^>]+)>").unwrap();
+    let text = channels.replace_all(&text, "#$1");
+    let broadcasts = Regex::new(r"<!(here|channel|everyone)(?:\|[^>]*)?>").unwrap();
+    let text = broadcasts.replace_all(&text, "@$1");
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slack::models::SlackChannel;
+
+    fn message(ts: &str, name: &str, text: &str, direction: MessageDirection) -> SlackMessage {
+        SlackMessage {
+            ts: ts.to_string(),
+            user_id: None,
+            user_name: name.to_string(),
+            text: text.to_string(),
+            direction,
+            replies: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_format_slack_text() {
+        assert_eq!(
+            format_slack_text("See <https://example.com|the doc> &amp; <#C1|general>, <!here>"),
+            "See [the doc](https://example.com) & #general, @here"
+        );
+        assert_eq!(
+            format_slack_text("<https://example.com>"),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_save_conversation_with_thread() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-slack-test-{}", std::process::id()));
+        let mut question = message(
+            "1700000000.000100",
+            "Dana Customer",
+            "Can we get a demo?",
+            MessageDirection::Inbound,
+        );
+        question.replies.push(message(
+            "1700000100.000000",
+            "Sam",
+            "Sure, Tuesday?",
+            MessageDirection::Outbound,
+        ));
+        let conversation = SlackConversation {
+            channel: SlackChannel {
+                id: "C1".to_string(),
+                name: "ext-acme".to_string(),
+                is_ext_shared: true,
+                is_private: false,
+                topic: None,
+            },
+            messages: vec![question],
+        };
+
+        let files = SlackMarkdownWriter::new(&dir)
+            .save("Acme Corp", &[conversation])
+            .unwrap();
+        assert_eq!(files, vec![dir.join("acme-corp-slack-ext-acme.md")]);
+
+        let content = fs::read_to_string(&files[0]).unwrap();
+        assert!(content.contains("# Slack: #ext-acme"));
+        assert!(content.contains("**Messages:** 2"));
+        assert!(content.contains("**Dana Customer** (customer) · 22:13 UTC\nCan we get a demo?"));
+        assert!(content.contains("> **Sam** · 22:15 UTC\n> Sure, Tuesday?"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
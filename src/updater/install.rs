@@ -0,0 +1,110 @@
+//! Atomic binary replacement and rollback
+//!
+//! The new binary is written next to the installed one and renamed over it,
+//! so the install path always holds a complete executable. The replaced binary
+//! is kept alongside as `<name>.previous`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{CsCliError, Result};
+
+/// Where the replaced binary is kept
+pub fn previous_binary_path(install_path: &Path) -> PathBuf {
+    sibling(install_path, "previous")
+}
+
+fn sibling(install_path: &Path, suffix: &str) -> PathBuf {
+    let mut name = install_path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{suffix}"));
+    install_path.with_file_name(name)
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> CsCliError {
+    let hint = if e.kind() == std::io::ErrorKind::PermissionDenied {
+        " (try again with sudo, or reinstall to a folder you own)"
+    } else {
+        ""
+    };
+    CsCliError::FileIo(format!("Failed to {action} {}: {e}{hint}", path.display()))
+}
+
+/// Replace the binary at `install_path` with `binary`, keeping the old one
+pub fn install_binary(install_path: &Path, binary: &[u8]) -> Result<()> {
+    let staged = sibling(install_path, "new");
+    fs::write(&staged, binary).map_err(|e| io_error("write", &staged, e))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))
+            .map_err(|e| io_error("make executable", &staged, e))?;
+    }
+
+    let previous = previous_binary_path(install_path);
+    if install_path.exists() {
+        // Copy rather than move so the install path is never empty
+        fs::copy(install_path, &previous).map_err(|e| io_error("back up", install_path, e))?;
+    }
+
+    fs::rename(&staged, install_path).map_err(|e| {
+        let _ = fs::remove_file(&staged);
+        io_error("replace", install_path, e)
+    })
+}
+
+/// Swap the installed binary with the one kept by the last update
+///
+/// Running it again swaps back.
+pub fn rollback_binary(install_path: &Path) -> Result<()> {
+    let previous = previous_binary_path(install_path);
+    if !previous.is_file() {
+        return Err(CsCliError::UpdateError(
+            "No previous version to roll back to".to_string(),
+        ));
+    }
+
+    let current = fs::read(install_path).map_err(|e| io_error("read", install_path, e))?;
+    fs::rename(&previous, install_path).map_err(|e| io_error("restore", install_path, e))?;
+    fs::write(&previous, current).map_err(|e| io_error("write", &previous, e))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(&previous, fs::Permissions::from_mode(0o755));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_and_rollback_swap_binaries() {
+        let dir = std::env::temp_dir().join(format!("cs-cli-install-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let install_path = dir.join("cs-cli");
+        fs::write(&install_path, b"v1").unwrap();
+
+        assert!(rollback_binary(&install_path).is_err());
+
+        install_binary(&install_path, b"v2").unwrap();
+        assert_eq!(fs::read(&install_path).unwrap(), b"v2");
+        assert_eq!(
+            fs::read(previous_binary_path(&install_path)).unwrap(),
+            b"v1"
+        );
+        assert!(!dir.join("cs-cli.new").exists());
+
+        rollback_binary(&install_path).unwrap();
+        assert_eq!(fs::read(&install_path).unwrap(), b"v1");
+        assert_eq!(
+            fs::read(previous_binary_path(&install_path)).unwrap(),
+            b"v2"
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,551 @@
+//! Verified self-update from GitHub releases
+//!
+//! Every release publishes one binary per platform, a `SHA256SUMS` manifest and
+//! `SHA256SUMS.sig`, an Ed25519 signature of the manifest. An update downloads
+//! the binary for this platform, checks the manifest signature against the
+//! release key built into this binary, checks the binary against the manifest,
+//! and only then swaps it into place. The replaced binary is kept for
+//! `cs-cli update --rollback`.
+//!
+//! Nothing is installed without consent: the startup check asks first on a
+//! terminal and only prints a notice otherwise. `update.channel`,
+//! `update.pin` and `update.check` control which releases are offered and
+//! whether startup checks happen at all.
+
+pub mod install;
+pub mod policy;
+pub mod release;
+pub mod verify;
+
+pub use install::*;
+pub use policy::*;
+pub use release::*;
+pub use verify::*;
+
+use owo_colors::OwoColorize;
+use semver::VersionReq;
+use std::path::PathBuf;
+use tokio::time::{timeout, Duration};
+use tracing::debug;
+
+use crate::common::config::{UpdateChannel, UpdateCheck, UpdateSettings};
+use crate::common::events::status;
+use crate::{CsCliError, Result};
+
+/// GitHub repository releases are published from
+pub const DEFAULT_REPOSITORY: &str = "postman-cs/cs-cli";
+
+/// GitHub REST API base URL
+pub const DEFAULT_API_BASE: &str = "https://api.github.com";
+
+/// Release signing public key (base64 Ed25519), embedded at build time from
+/// `CS_CLI_UPDATE_PUBLIC_KEY`
+pub fn embedded_public_key() -> Option<[u8; 32]> {
+    option_env!("CS_CLI_UPDATE_PUBLIC_KEY").and_then(decode_public_key)
+}
+
+/// Checks for, verifies and installs releases
+pub struct Updater {
+    http: reqwest::Client,
+    api_base: String,
+    repository: String,
+    public_key: Option<[u8; 32]>,
+    install_path: PathBuf,
+    current_version: String,
+    channel: UpdateChannel,
+    pin: Option<VersionReq>,
+}
+
+impl Updater {
+    /// Updater for the running binary
+    pub fn new() -> Result<Self> {
+        let install_path = std::env::current_exe()
+            .and_then(|path| path.canonicalize())
+            .map_err(|e| {
+                CsCliError::UpdateError(format!("Could not locate the cs-cli binary: {e}"))
+            })?;
+
+        let http = reqwest::Client::builder()
+            .user_agent("cs-cli")
+            .timeout(Duration::from_secs(120))
+            .build()
+            .map_err(|e| CsCliError::UpdateError(format!("HTTP client error: {e}")))?;
+
+        Ok(Self {
+            http,
+            api_base: DEFAULT_API_BASE.to_string(),
+            repository: DEFAULT_REPOSITORY.to_string(),
+            public_key: embedded_public_key(),
+            install_path,
+            current_version: env!("CARGO_PKG_VERSION").to_string(),
+            channel: UpdateChannel::default(),
+            pin: None,
+        })
+    }
+
+    /// Follow the channel and pin from the `update` settings
+    pub fn with_settings(mut self, settings: &UpdateSettings) -> Result<Self> {
+        self.channel = settings.channel;
+        self.pin = settings.pin.as_deref().map(parse_pin).transpose()?;
+        Ok(self)
+    }
+
+    /// Use another release API (e.g. a mirror or a test server)
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+
+    /// Check releases of another `owner/name` repository
+    pub fn with_repository(mut self, repository: impl Into<String>) -> Self {
+        self.repository = repository.into();
+        self
+    }
+
+    /// Verify releases with this Ed25519 public key
+    pub fn with_public_key(mut self, public_key: Option<[u8; 32]>) -> Self {
+        self.public_key = public_key;
+        self
+    }
+
+    /// Replace the binary at this path instead of the running one
+    pub fn with_install_path(mut self, install_path: impl Into<PathBuf>) -> Self {
+        self.install_path = install_path.into();
+        self
+    }
+
+    /// Compare releases against this version instead of the built-in one
+    pub fn with_current_version(mut self, version: impl Into<String>) -> Self {
+        self.current_version = version.into();
+        self
+    }
+
+    pub fn current_version(&self) -> &str {
+        &self.current_version
+    }
+
+    pub fn install_path(&self) -> &std::path::Path {
+        &self.install_path
+    }
+
+    /// Newest release on the channel that matches the pin
+    pub async fn latest(&self) -> Result<Option<Release>> {
+        let releases = fetch_releases(&self.http, &self.api_base, &self.repository).await?;
+        let latest = select_release(releases, self.channel, self.pin.as_ref());
+        debug!(
+            latest = latest.as_ref().map(|r| r.version()).unwrap_or("none"),
+            current = %self.current_version,
+            channel = ?self.channel,
+            "Checked for updates"
+        );
+        Ok(latest)
+    }
+
+    /// Newest matching release, if it is newer than the current version
+    pub async fn check(&self) -> Result<Option<Release>> {
+        Ok(self
+            .latest()
+            .await?
+            .filter(|release| version_is_newer(release.version(), &self.current_version)))
+    }
+
+    /// Download, verify and install `release`
+    ///
+    /// The installed binary is untouched unless every check passes.
+    pub async fn install(&self, release: &Release) -> Result<()> {
+        let public_key = self.public_key.ok_or_else(|| {
+            CsCliError::UpdateError(
+                "This build has no release signing key, so updates can't be verified. Download the new version from the releases page".to_string(),
+            )
+        })?;
+        let asset_name = platform_asset_name().ok_or_else(|| {
+            CsCliError::UpdateError(format!(
+                "No release binary for {}/{}",
+                std::env::consts::OS,
+                std::env::consts::ARCH
+            ))
+        })?;
+
+        let manifest = download_asset(&self.http, release.asset(MANIFEST_ASSET)?).await?;
+        let signature = download_asset(&self.http, release.asset(SIGNATURE_ASSET)?).await?;
+        verify_manifest_signature(&manifest, &signature, &public_key)?;
+
+        let binary = download_asset(&self.http, release.asset(asset_name)?).await?;
+        verify_asset(&String::from_utf8_lossy(&manifest), asset_name, &binary)?;
+
+        install_binary(&self.install_path, &binary)?;
+        debug!(version = %release.version(), path = %self.install_path.display(), "Installed update");
+        Ok(())
+    }
+
+    /// Swap back to the binary replaced by the last update
+    pub fn rollback(&self) -> Result<()> {
+        rollback_binary(&self.install_path)
+    }
+}
+
+/// Ask before installing
+fn confirm_install(version: &str) -> bool {
+    dialoguer::Confirm::new()
+        .with_prompt(format!("Install v{version} now?"))
+        .default(true)
+        .interact()
+        .unwrap_or(false)
+}
+
+/// Check now and remember the result for the startup check
+async fn check_and_cache(updater: &Updater, settings: &UpdateSettings) -> Result<Option<Release>> {
+    let latest = updater.latest().await?;
+    UpdateCheckCache::new(settings, latest.as_ref().map(|r| r.version())).save();
+    Ok(latest.filter(|release| version_is_newer(release.version(), updater.current_version())))
+}
+
+/// Startup update check
+///
+/// Hits GitHub at most once a day. With `update.check = auto` a terminal user
+/// is offered the install; otherwise only a notice is printed. Network
+/// problems are ignored so the CLI always starts.
+pub async fn check_at_startup(settings: &UpdateSettings, interactive: bool) -> Result<()> {
+    if updates_disabled_by_env() || settings.check == UpdateCheck::Off {
+        return Ok(());
+    }
+    let updater = Updater::new()?.with_settings(settings)?;
+
+    // A recent check answers without touching the network
+    let cached = UpdateCheckCache::load().filter(|c| c.is_fresh(settings, jiff::Timestamp::now()));
+    let latest = match cached {
+        Some(cache) => cache.latest,
+        None => match timeout(Duration::from_secs(5), check_and_cache(&updater, settings)).await {
+            Ok(Ok(release)) => release.map(|r| r.version().to_string()),
+            Ok(Err(e)) => {
+                debug!(error = %e, "Update check failed");
+                return Ok(());
+            }
+            Err(_) => return Ok(()), // Timeout, skip update
+        },
+    };
+    let Some(latest) = latest.filter(|v| version_is_newer(v, updater.current_version())) else {
+        return Ok(());
+    };
+
+    status(format!(
+        "🔄 Update available: v{} → v{latest}",
+        updater.current_version()
+    ));
+
+    if !interactive || settings.check == UpdateCheck::Notify {
+        status("Run `cs-cli update apply` to install it.");
+        return Ok(());
+    }
+    if !confirm_install(&latest) {
+        return Ok(());
+    }
+
+    status("📦 Installing update...");
+    let installed = match updater.check().await {
+        Ok(Some(release)) => updater.install(&release).await.map(|_| release),
+        Ok(None) => Err(CsCliError::UpdateError(format!(
+            "v{latest} is no longer available"
+        ))),
+        Err(e) => Err(e),
+    };
+    match installed {
+        Ok(release) => status(
+            format!(
+                "✅ Updated to v{}. The new version starts next time you run cs-cli.",
+                release.version()
+            )
+            .green(),
+        ),
+        Err(e) => {
+            status(format!("⚠️  Update failed ({e}). Continuing with current version.").yellow())
+        }
+    }
+
+    Ok(())
+}
+
+/// `cs-cli update check`: look for a newer release now
+pub async fn run_update_check(settings: &UpdateSettings) -> Result<()> {
+    let updater = Updater::new()?.with_settings(settings)?;
+
+    match check_and_cache(&updater, settings).await? {
+        Some(release) => status(format!(
+            "🔄 Update available: v{} → v{} (run `cs-cli update apply`)",
+            updater.current_version(),
+            release.version()
+        )),
+        None => status(format!("✅ cs-cli v{} is up to date", updater.current_version()).green()),
+    }
+    Ok(())
+}
+
+/// `cs-cli update apply`
+///
+/// Without `yes`, installing needs a confirmation on a terminal; non-interactive
+/// runs fail instead of updating silently.
+pub async fn run_update_apply(
+    settings: &UpdateSettings,
+    yes: bool,
+    interactive: bool,
+) -> Result<()> {
+    let updater = Updater::new()?.with_settings(settings)?;
+
+    let Some(release) = check_and_cache(&updater, settings).await? else {
+        status(format!("✅ cs-cli v{} is up to date", updater.current_version()).green());
+        return Ok(());
+    };
+
+    status(format!(
+        "🔄 Update available: v{} → v{}",
+        updater.current_version(),
+        release.version()
+    ));
+
+    if !yes {
+        if !interactive {
+            return Err(CsCliError::InvalidArguments {
+                message:
+                    "Not updating without confirmation; run `cs-cli update apply --yes` to install"
+                        .to_string(),
+            });
+        }
+        if !confirm_install(release.version()) {
+            return Ok(());
+        }
+    }
+
+    status("📦 Installing update...");
+    updater.install(&release).await?;
+    status(format!("✅ Updated to v{}", release.version()).green());
+    Ok(())
+}
+
+/// `cs-cli update rollback`
+pub fn run_update_rollback() -> Result<()> {
+    let updater = Updater::new()?;
+    updater.rollback()?;
+    status(
+        format!(
+            "✅ Restored the previous version of {}",
+            updater.install_path().display()
+        )
+        .green(),
+    );
+    Ok(())
+}
+
+/// `cs-cli update status`: version, policy and the last check
+pub fn run_update_status(settings: &UpdateSettings) -> Result<()> {
+    let updater = Updater::new()?.with_settings(settings)?;
+
+    let check = if updates_disabled_by_env() {
+        format!("off ({NO_UPDATE_ENV} is set)")
+    } else {
+        format!("{:?}", settings.check).to_lowercase()
+    };
+    let last_check = match UpdateCheckCache::load() {
+        Some(cache) => format!(
+            "{} (latest: {})",
+            cache.checked_at.strftime("%Y-%m-%d %H:%M UTC"),
+            cache
+                .latest
+                .as_deref()
+                .map_or("none".to_string(), |v| format!("v{v}"))
+        ),
+        None => "never".to_string(),
+    };
+    let rollback = if previous_binary_path(updater.install_path()).is_file() {
+        "available"
+    } else {
+        "none"
+    };
+
+    println!("version:       v{}", updater.current_version());
+    println!("binary:        {}", updater.install_path().display());
+    println!(
+        "channel:       {}",
+        format!("{:?}", settings.channel).to_lowercase()
+    );
+    println!(
+        "pin:           {}",
+        settings.pin.as_deref().unwrap_or("none")
+    );
+    println!("startup check: {check}");
+    println!("last check:    {last_check}");
+    println!("rollback:      {rollback}");
+    println!(
+        "signing key:   {}",
+        if embedded_public_key().is_some() {
+            "embedded"
+        } else {
+            "missing (updates can't be verified)"
+        }
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::hash::sha256_hex;
+    use ed25519_dalek::{Signer, SigningKey};
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Mock GitHub serving a v9.9.9 release; `tamper` corrupts the binary
+    async fn release_server(signing_key: &SigningKey, tamper: bool) -> MockServer {
+        let server = MockServer::start().await;
+        let asset_name = platform_asset_name().unwrap();
+        let binary = b"cs-cli v9.9.9".to_vec();
+
+        let manifest = format!("{}  {asset_name}\n", sha256_hex(&binary));
+        let signature = signing_key.sign(manifest.as_bytes()).to_bytes();
+        let served = if tamper {
+            b"malicious".to_vec()
+        } else {
+            binary
+        };
+
+        let url = |name: &str| format!("{}/download/{name}", server.uri());
+        Mock::given(method("GET"))
+            .and(path("/repos/postman-cs/cs-cli/releases"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "tag_name": "v10.0.0-beta.1",
+                    "prerelease": true,
+                    "assets": []
+                },
+                {
+                    "tag_name": "v9.9.9",
+                    "assets": [
+                        {"name": asset_name, "browser_download_url": url(asset_name)},
+                        {"name": MANIFEST_ASSET, "browser_download_url": url(MANIFEST_ASSET)},
+                        {"name": SIGNATURE_ASSET, "browser_download_url": url(SIGNATURE_ASSET)},
+                    ]
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        for (name, body) in [
+            (asset_name.to_string(), served),
+            (MANIFEST_ASSET.to_string(), manifest.into_bytes()),
+            (SIGNATURE_ASSET.to_string(), signature.to_vec()),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(format!("/download/{name}")))
+                .respond_with(ResponseTemplate::new(200).set_body_bytes(body))
+                .mount(&server)
+                .await;
+        }
+
+        server
+    }
+
+    fn test_updater(server: &MockServer, public_key: [u8; 32], install_path: PathBuf) -> Updater {
+        Updater::new()
+            .unwrap()
+            .with_api_base(server.uri())
+            .with_public_key(Some(public_key))
+            .with_install_path(install_path)
+            .with_current_version("1.0.0")
+    }
+
+    fn temp_binary(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cs-cli-update-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let install_path = dir.join("cs-cli");
+        std::fs::write(&install_path, b"cs-cli v1.0.0").unwrap();
+        install_path
+    }
+
+    #[tokio::test]
+    async fn test_update_installs_verified_release_and_rolls_back() {
+        if platform_asset_name().is_none() {
+            return;
+        }
+        let signing_key = SigningKey::from_bytes(&[3u8; 32]);
+        let server = release_server(&signing_key, false).await;
+        let install_path = temp_binary("ok");
+        let updater = test_updater(
+            &server,
+            signing_key.verifying_key().to_bytes(),
+            install_path.clone(),
+        );
+
+        let release = updater.check().await.unwrap().expect("newer release");
+        assert_eq!(release.version(), "9.9.9");
+        updater.install(&release).await.unwrap();
+        assert_eq!(std::fs::read(&install_path).unwrap(), b"cs-cli v9.9.9");
+
+        updater.rollback().unwrap();
+        assert_eq!(std::fs::read(&install_path).unwrap(), b"cs-cli v1.0.0");
+
+        let _ = std::fs::remove_dir_all(install_path.parent().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_tampered_binary_and_wrong_key() {
+        if platform_asset_name().is_none() {
+            return;
+        }
+        let signing_key = SigningKey::from_bytes(&[3u8; 32]);
+        let install_path = temp_binary("bad");
+
+        let tampered = release_server(&signing_key, true).await;
+        let updater = test_updater(
+            &tampered,
+            signing_key.verifying_key().to_bytes(),
+            install_path.clone(),
+        );
+        let release = updater.check().await.unwrap().unwrap();
+        assert!(updater.install(&release).await.is_err());
+
+        let honest = release_server(&signing_key, false).await;
+        let wrong_key = SigningKey::from_bytes(&[4u8; 32])
+            .verifying_key()
+            .to_bytes();
+        let updater = test_updater(&honest, wrong_key, install_path.clone());
+        let release = updater.check().await.unwrap().unwrap();
+        assert!(updater.install(&release).await.is_err());
+
+        assert_eq!(std::fs::read(&install_path).unwrap(), b"cs-cli v1.0.0");
+        let _ = std::fs::remove_dir_all(install_path.parent().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_update_follows_channel_and_pin() {
+        let signing_key = SigningKey::from_bytes(&[3u8; 32]);
+        let server = release_server(&signing_key, false).await;
+        let updater = |settings: UpdateSettings| {
+            Updater::new()
+                .unwrap()
+                .with_api_base(server.uri())
+                .with_current_version("9.0.0")
+                .with_settings(&settings)
+                .unwrap()
+        };
+
+        let beta = updater(UpdateSettings {
+            channel: UpdateChannel::Beta,
+            ..UpdateSettings::default()
+        });
+        let release = beta.check().await.unwrap().unwrap();
+        assert_eq!(release.version(), "10.0.0-beta.1");
+
+        let pinned = updater(UpdateSettings {
+            channel: UpdateChannel::Beta,
+            pin: Some("9.x".to_string()),
+            ..UpdateSettings::default()
+        });
+        assert_eq!(pinned.check().await.unwrap().unwrap().version(), "9.9.9");
+
+        let pinned_old = updater(UpdateSettings {
+            pin: Some("8.x".to_string()),
+            ..UpdateSettings::default()
+        });
+        assert!(pinned_old.check().await.unwrap().is_none());
+    }
+}
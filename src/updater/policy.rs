@@ -0,0 +1,106 @@
+//! When to look for updates
+//!
+//! The startup check talks to GitHub at most once a day: the result is cached
+//! and reused until it is a day old or the channel or pin changes.
+//! `CS_CLI_NO_UPDATE` turns the startup check off for scripted runs.
+
+use jiff::{Timestamp, ToSpan};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::debug;
+
+use crate::common::config::{UpdateChannel, UpdateSettings};
+
+/// Environment variable that disables the startup update check
+pub const NO_UPDATE_ENV: &str = "CS_CLI_NO_UPDATE";
+
+/// Whether `CS_CLI_NO_UPDATE` is set to anything but empty, `0` or `false`
+pub fn updates_disabled_by_env() -> bool {
+    std::env::var(NO_UPDATE_ENV).is_ok_and(|value| {
+        !matches!(
+            value.trim().to_lowercase().as_str(),
+            "" | "0" | "false" | "no"
+        )
+    })
+}
+
+/// Result of the last update check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCheckCache {
+    pub checked_at: Timestamp,
+    pub channel: UpdateChannel,
+    #[serde(default)]
+    pub pin: Option<String>,
+    /// Newest version on the channel, if any release matched
+    #[serde(default)]
+    pub latest: Option<String>,
+}
+
+impl UpdateCheckCache {
+    /// Record a check made now
+    pub fn new(settings: &UpdateSettings, latest: Option<&str>) -> Self {
+        Self {
+            checked_at: Timestamp::now(),
+            channel: settings.channel,
+            pin: settings.pin.clone(),
+            latest: latest.map(str::to_string),
+        }
+    }
+
+    /// Cache file location
+    pub fn path() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("cs-cli").join("update-check.json"))
+    }
+
+    /// Last saved check, if any
+    pub fn load() -> Option<Self> {
+        let contents = std::fs::read_to_string(Self::path()?).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Save this check; failures only mean the next run checks again
+    pub fn save(&self) {
+        let Some(path) = Self::path() else {
+            return;
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| {
+                let json = serde_json::to_string_pretty(self).unwrap_or_default();
+                std::fs::write(&path, json)
+            });
+        if let Err(e) = result {
+            debug!(error = %e, path = %path.display(), "Failed to save update check");
+        }
+    }
+
+    /// Whether this check can stand in for a new one
+    pub fn is_fresh(&self, settings: &UpdateSettings, now: Timestamp) -> bool {
+        self.channel == settings.channel
+            && self.pin == settings.pin
+            && now < self.checked_at + 24.hours()
+            && self.checked_at <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_freshness() {
+        let settings = UpdateSettings::default();
+        let cache = UpdateCheckCache::new(&settings, Some("1.2.0"));
+        let now = cache.checked_at;
+
+        assert!(cache.is_fresh(&settings, now + 23.hours()));
+        assert!(!cache.is_fresh(&settings, now + 25.hours()));
+
+        let beta = UpdateSettings {
+            channel: UpdateChannel::Beta,
+            ..UpdateSettings::default()
+        };
+        assert!(!cache.is_fresh(&beta, now));
+    }
+}
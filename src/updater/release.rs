@@ -0,0 +1,221 @@
+//! GitHub release lookup, version ordering and downloads
+
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+
+use crate::common::config::UpdateChannel;
+use crate::{CsCliError, Result};
+
+/// Checksum manifest published with every release
+pub const MANIFEST_ASSET: &str = "SHA256SUMS";
+
+/// Ed25519 signature of [`MANIFEST_ASSET`]
+pub const SIGNATURE_ASSET: &str = "SHA256SUMS.sig";
+
+/// A published release
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+impl Release {
+    /// Version without the `v` prefix
+    pub fn version(&self) -> &str {
+        self.tag_name.strip_prefix('v').unwrap_or(&self.tag_name)
+    }
+
+    /// Asset by file name
+    pub fn asset(&self, name: &str) -> Result<&ReleaseAsset> {
+        self.assets
+            .iter()
+            .find(|asset| asset.name == name)
+            .ok_or_else(|| {
+                CsCliError::UpdateError(format!("Release {} has no {name} asset", self.tag_name))
+            })
+    }
+}
+
+/// A downloadable file attached to a release
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub browser_download_url: String,
+}
+
+/// Release asset name for the running platform, matching `build.sh`
+pub fn platform_asset_name() -> Option<&'static str> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("macos", "aarch64") => Some("cs-cli-aarch64-apple-darwin"),
+        ("macos", "x86_64") => Some("cs-cli-x86_64-apple-darwin"),
+        ("linux", "x86_64") => Some("cs-cli-x86_64-unknown-linux-gnu"),
+        ("linux", "aarch64") => Some("cs-cli-aarch64-unknown-linux-gnu"),
+        ("windows", "x86_64") => Some("cs-cli-x86_64-pc-windows-msvc.exe"),
+        _ => None,
+    }
+}
+
+/// Recent releases of `repository` (`owner/name`), newest first
+pub async fn fetch_releases(
+    http: &reqwest::Client,
+    api_base: &str,
+    repository: &str,
+) -> Result<Vec<Release>> {
+    let url = format!(
+        "{}/repos/{repository}/releases?per_page=30",
+        api_base.trim_end_matches('/')
+    );
+    let body = get(http, &url).await?;
+
+    serde_json::from_slice(&body).map_err(|e| {
+        CsCliError::http("GET", &url, Some(200), "invalid release JSON").with_source(e)
+    })
+}
+
+/// Newest release on `channel` that matches `pin`
+///
+/// Stable skips pre-releases (by flag or by a `-beta.1`-style version); beta
+/// considers everything that isn't a draft.
+pub fn select_release(
+    releases: Vec<Release>,
+    channel: UpdateChannel,
+    pin: Option<&VersionReq>,
+) -> Option<Release> {
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .filter_map(|release| Some((parse_version(release.version())?, release)))
+        .filter(|(version, release)| {
+            channel == UpdateChannel::Beta || (!release.prerelease && version.pre.is_empty())
+        })
+        .filter(|(version, _)| match pin {
+            Some(pin) => matches_pin(pin, version),
+            None => true,
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+/// Download a release asset
+pub async fn download_asset(http: &reqwest::Client, asset: &ReleaseAsset) -> Result<Vec<u8>> {
+    get(http, &asset.browser_download_url).await
+}
+
+async fn get(http: &reqwest::Client, url: &str) -> Result<Vec<u8>> {
+    let response = http
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| CsCliError::http("GET", url, None, "request failed").with_source(e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(CsCliError::http_status("GET", url, status.as_u16(), &body));
+    }
+
+    response
+        .bytes()
+        .await
+        .map(|bytes| bytes.to_vec())
+        .map_err(|e| {
+            CsCliError::http("GET", url, Some(status.as_u16()), "download interrupted")
+                .with_source(e)
+        })
+}
+
+/// Parse a release version leniently: `v1.2` is read as `1.2.0`
+pub fn parse_version(version: &str) -> Option<Version> {
+    let version = version.trim().trim_start_matches('v');
+    if let Ok(parsed) = Version::parse(version) {
+        return Some(parsed);
+    }
+
+    // Pad missing minor/patch numbers, keeping any pre-release or build suffix
+    let split = version.find(['-', '+']).unwrap_or(version.len());
+    let (core, suffix) = version.split_at(split);
+    let mut parts: Vec<&str> = core.split('.').collect();
+    while parts.len() < 3 {
+        parts.push("0");
+    }
+    Version::parse(&format!("{}{suffix}", parts.join("."))).ok()
+}
+
+/// Parse an `update.pin` requirement such as `1.x`, `~1.4` or `>=1.2, <2`
+pub fn parse_pin(pin: &str) -> Result<VersionReq> {
+    VersionReq::parse(pin.trim())
+        .map_err(|e| CsCliError::Configuration(format!("Invalid update pin '{pin}': {e}")))
+}
+
+/// Whether `version` satisfies `pin`
+///
+/// Pre-releases are matched by their release number, so a `1.x` pin accepts
+/// `1.3.0-beta.1` on the beta channel.
+pub fn matches_pin(pin: &VersionReq, version: &Version) -> bool {
+    let mut release = version.clone();
+    release.pre = semver::Prerelease::EMPTY;
+    release.build = semver::BuildMetadata::EMPTY;
+    pin.matches(&release)
+}
+
+/// Semantic version comparison, where `1.3.0-beta.1` is older than `1.3.0`
+pub fn version_is_newer(latest: &str, current: &str) -> bool {
+    match (parse_version(latest), parse_version(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(tag: &str, prerelease: bool) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            prerelease,
+            draft: false,
+            assets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_version_ordering_with_prereleases() {
+        assert!(version_is_newer("1.2.0", "1.1.9"));
+        assert!(!version_is_newer("1.0", "1.0.0"));
+        assert!(version_is_newer("v1.3.0", "1.3.0-beta.2"));
+        assert!(version_is_newer("1.3.0-beta.2", "1.3.0-beta.1"));
+        assert!(version_is_newer("1.3.0-rc.1", "1.3.0-beta.9"));
+        assert!(!version_is_newer("1.3.0-beta.1", "1.3.0"));
+        assert!(!version_is_newer("not-a-version", "1.0.0"));
+    }
+
+    #[test]
+    fn test_select_release_by_channel_and_pin() {
+        let releases = || {
+            vec![
+                release("v2.0.0", false),
+                release("v1.5.0-beta.1", true),
+                release("v1.4.2", false),
+                release("v1.4.3-rc.1", false),
+            ]
+        };
+
+        let stable = select_release(releases(), UpdateChannel::Stable, None).unwrap();
+        assert_eq!(stable.version(), "2.0.0");
+
+        let pin = parse_pin("1.x").unwrap();
+        let pinned = select_release(releases(), UpdateChannel::Stable, Some(&pin)).unwrap();
+        assert_eq!(pinned.version(), "1.4.2");
+
+        let beta = select_release(releases(), UpdateChannel::Beta, Some(&pin)).unwrap();
+        assert_eq!(beta.version(), "1.5.0-beta.1");
+
+        assert!(parse_pin("one point x").is_err());
+    }
+}
@@ -0,0 +1,122 @@
+//! Release integrity checks
+//!
+//! A release's `SHA256SUMS` manifest lists the SHA-256 of every asset, and
+//! `SHA256SUMS.sig` is an Ed25519 signature of the manifest made with the
+//! release key. Checking the signature first means a tampered manifest can't
+//! vouch for a tampered binary.
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use std::collections::HashMap;
+
+use crate::common::hash::sha256_hex;
+use crate::{CsCliError, Result};
+
+/// Parse a `sha256sum`-style manifest into asset name → hash
+///
+/// Accepts both `<hash>  <name>` and the binary-mode `<hash> *<name>`.
+pub fn parse_manifest(manifest: &str) -> HashMap<String, String> {
+    manifest
+        .lines()
+        .filter_map(|line| {
+            let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+            let name = name.trim_start().trim_start_matches('*');
+            if hash.len() != 64 || name.is_empty() {
+                return None;
+            }
+            Some((name.to_string(), hash.to_lowercase()))
+        })
+        .collect()
+}
+
+/// Decode a base64 Ed25519 public key
+pub fn decode_public_key(encoded: &str) -> Option<[u8; 32]> {
+    base64_simd::STANDARD
+        .decode_to_vec(encoded.trim().as_bytes())
+        .ok()?
+        .try_into()
+        .ok()
+}
+
+/// Check the manifest's Ed25519 signature (raw 64 bytes or base64)
+pub fn verify_manifest_signature(
+    manifest: &[u8],
+    signature: &[u8],
+    public_key: &[u8; 32],
+) -> Result<()> {
+    let key = VerifyingKey::from_bytes(public_key)
+        .map_err(|e| CsCliError::UpdateError(format!("Invalid release signing key: {e}")))?;
+
+    let raw = if signature.len() == 64 {
+        signature.to_vec()
+    } else {
+        let text = String::from_utf8_lossy(signature);
+        base64_simd::STANDARD
+            .decode_to_vec(text.trim().as_bytes())
+            .map_err(|e| CsCliError::UpdateError(format!("Unreadable release signature: {e}")))?
+    };
+    let signature = Signature::from_slice(&raw)
+        .map_err(|e| CsCliError::UpdateError(format!("Unreadable release signature: {e}")))?;
+
+    key.verify(manifest, &signature).map_err(|_| {
+        CsCliError::UpdateError(
+            "Release signature does not match the release signing key".to_string(),
+        )
+    })
+}
+
+/// Check a downloaded asset against the manifest
+pub fn verify_asset(manifest: &str, asset_name: &str, data: &[u8]) -> Result<()> {
+    let expected = parse_manifest(manifest).remove(asset_name).ok_or_else(|| {
+        CsCliError::UpdateError(format!(
+            "{asset_name} is not listed in the release checksums"
+        ))
+    })?;
+
+    let actual = sha256_hex(data);
+    if actual != expected {
+        return Err(CsCliError::UpdateError(format!(
+            "Checksum mismatch for {asset_name}: expected {expected}, got {actual}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    #[test]
+    fn test_manifest_and_asset_checks() {
+        let binary = b"new binary";
+        let manifest = format!(
+            "{}  cs-cli-x86_64-apple-darwin\n{} *cs-cli-aarch64-apple-darwin\n",
+            sha256_hex(binary),
+            sha256_hex(b"other")
+        );
+
+        assert_eq!(parse_manifest(&manifest).len(), 2);
+        assert!(verify_asset(&manifest, "cs-cli-x86_64-apple-darwin", binary).is_ok());
+        assert!(verify_asset(&manifest, "cs-cli-aarch64-apple-darwin", binary).is_err());
+        assert!(verify_asset(&manifest, "cs-cli-missing", binary).is_err());
+    }
+
+    #[test]
+    fn test_signature_checks() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = signing_key.verifying_key().to_bytes();
+        let manifest = b"abc  cs-cli\n";
+        let signature = signing_key.sign(manifest).to_bytes();
+
+        assert!(verify_manifest_signature(manifest, &signature, &public_key).is_ok());
+
+        let encoded = base64_simd::STANDARD.encode_to_string(signature);
+        assert!(verify_manifest_signature(manifest, encoded.as_bytes(), &public_key).is_ok());
+
+        assert!(verify_manifest_signature(b"tampered", &signature, &public_key).is_err());
+        let other_key = SigningKey::from_bytes(&[8u8; 32])
+            .verifying_key()
+            .to_bytes();
+        assert!(verify_manifest_signature(manifest, &signature, &other_key).is_err());
+    }
+}